@@ -4,7 +4,7 @@
 //! message received from any channel (Telegram, web, REPL, WASM, …). It is
 //! pure data with no transport-trait coupling; the channel layer wraps it
 //! into an `IncomingMessage`, while `ironclaw_llm::transcription` operates
-//! directly on `&mut [IncomingAttachment]` to fill `extracted_text` for
+//! directly on a `Vec<IncomingAttachment>` to fill `extracted_text` for
 //! audio inputs.
 
 /// Normalize a MIME type to its canonical comparison form: drop any
@@ -396,6 +396,29 @@ impl ExtensionIngressRouter {
                 self.admit_messages(binding.extension_id(), &verified.installation_id, messages)
                     .await
             }
+            InboundOutcome::MessagesWithResponse { messages, response } => {
+                if response.validate().is_err() || !(200..=299).contains(&response.status) {
+                    tracing::warn!(
+                        extension_id = %binding.extension_id(),
+                        "channel adapter immediate response violated host bounds"
+                    );
+                    return IngressResponse::error(500, "adapter");
+                }
+                let admitted = self
+                    .admit_messages(binding.extension_id(), &verified.installation_id, messages)
+                    .await;
+                // The adapter's response replaces the plain 2xx only once
+                // every message is durably admitted; failures keep their
+                // error so the vendor retries.
+                if !(200..=299).contains(&admitted.status) {
+                    return admitted;
+                }
+                IngressResponse {
+                    status: response.status,
+                    content_type: response.content_type,
+                    body: response.body,
+                }
+            }
         }
     }
 
@@ -106,6 +106,8 @@ enum AdapterMode {
     Message,
     /// Message with a `reply_context` payload attached.
     MessageWithReplyContext,
+    /// Message plus an acknowledgement body returned after admission.
+    MessageWithResponse,
     Respond,
     OversizedRespond,
     Ignore,
@@ -141,7 +143,9 @@ impl ChannelAdapter for ScriptedChannelAdapter {
                 content_type: None,
                 body: vec![0u8; 64 * 1024 + 1],
             })),
-            AdapterMode::Message | AdapterMode::MessageWithReplyContext => {
+            AdapterMode::Message
+            | AdapterMode::MessageWithReplyContext
+            | AdapterMode::MessageWithResponse => {
                 let value: serde_json::Value =
                     serde_json::from_slice(request.body).map_err(|error| ChannelError::Parse {
                         reason: error.to_string(),
@@ -149,7 +153,7 @@ impl ChannelAdapter for ScriptedChannelAdapter {
                 let text = value["text"].as_str().unwrap_or_default().to_string();
                 let event = value["event"].as_str().unwrap_or("event-1");
                 let conversation = value["conversation"].as_str().unwrap_or("conv-1");
-                Ok(InboundOutcome::Messages(vec![NormalizedInboundMessage {
+                let messages = vec![NormalizedInboundMessage {
                     actor: ExternalActorRef::new("acme_user", "U-1", None::<&str>).expect("actor"),
                     conversation: ExternalConversationRef::new(None, conversation, None, None)
                         .expect("conversation"),
@@ -159,7 +163,18 @@ impl ChannelAdapter for ScriptedChannelAdapter {
                     attachments: Vec::new(),
                     reply_context: matches!(self.mode, AdapterMode::MessageWithReplyContext)
                         .then(|| b"opaque-reply-route".to_vec()),
-                }]))
+                }];
+                if self.mode == AdapterMode::MessageWithResponse {
+                    return Ok(InboundOutcome::MessagesWithResponse {
+                        messages,
+                        response: ImmediateResponse {
+                            status: 200,
+                            content_type: Some("application/json".to_string()),
+                            body: br#"{"method":"ack"}"#.to_vec(),
+                        },
+                    });
+                }
+                Ok(InboundOutcome::Messages(messages))
             }
         }
     }
@@ -830,6 +845,33 @@ async fn respond_outcome_answers_without_enqueue_within_bounds() {
     );
 }
 
+/// `MessagesWithResponse` admits the messages first and only then returns
+/// the adapter's acknowledgement body; a failed admission keeps its error so
+/// the vendor retries.
+#[tokio::test]
+async fn messages_with_response_acknowledge_only_after_admission() {
+    let body = br#"{"text":"approve","event":"ev-ack","conversation":"C-1"}"#;
+    let harness = harness_with_activation(HarnessOptions {
+        adapter_mode: AdapterMode::MessageWithResponse,
+        ..HarnessOptions::default()
+    })
+    .await;
+    let response = harness.router.handle(signed_request(body)).await;
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, br#"{"method":"ack"}"#);
+    assert_eq!(harness.admitted.lock().expect("admitted").len(), 1);
+
+    let retryable = harness_with_activation(HarnessOptions {
+        adapter_mode: AdapterMode::MessageWithResponse,
+        sink_mode: SinkMode::FailRetryable,
+        ..HarnessOptions::default()
+    })
+    .await;
+    let response = retryable.router.handle(signed_request(body)).await;
+    assert_eq!(response.status, 503);
+    assert_ne!(response.body, br#"{"method":"ack"}"#);
+}
+
 /// Ignore outcome: authenticated no-op acks 200 without admission.
 #[tokio::test]
 async fn ignore_outcome_acks_without_admission() {
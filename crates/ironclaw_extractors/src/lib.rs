@@ -246,11 +246,41 @@ fn extract_pdf(data: &[u8]) -> Result<String, String> {
         .map_err(|e| format!("PDF extraction failed: {e}"))
 }
 
+/// Compound File Binary (OLE2) signature. Password-protected OOXML files are
+/// not ZIP archives: Office wraps the encrypted package in a CFB container.
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Reject a password-protected OOXML file with a clear error before the ZIP
+/// reader reports it as a corrupt archive.
+///
+/// Encrypted documents are CFB containers holding an `EncryptedPackage`
+/// stream; stream names are stored UTF-16LE in the CFB directory.
+fn reject_encrypted_office_document(data: &[u8], format: &str) -> Result<(), String> {
+    if !data.starts_with(&CFB_SIGNATURE) {
+        return Ok(());
+    }
+    let stream_name: Vec<u8> = "EncryptedPackage"
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    if data
+        .windows(stream_name.len())
+        .any(|window| window == stream_name.as_slice())
+    {
+        return Err(format!(
+            "{format} document is password-protected and cannot be read"
+        ));
+    }
+    Ok(())
+}
+
 fn extract_docx(data: &[u8]) -> Result<String, String> {
+    reject_encrypted_office_document(data, "DOCX")?;
     extract_office_xml(data, "word/document.xml")
 }
 
 fn extract_pptx(data: &[u8]) -> Result<String, String> {
+    reject_encrypted_office_document(data, "PPTX")?;
     let cursor = std::io::Cursor::new(data);
     let mut archive =
         zip::ZipArchive::new(cursor).map_err(|e| format!("invalid PPTX archive: {e}"))?;
@@ -291,6 +321,7 @@ fn extract_pptx(data: &[u8]) -> Result<String, String> {
 }
 
 fn extract_xlsx(data: &[u8]) -> Result<String, String> {
+    reject_encrypted_office_document(data, "XLSX")?;
     let cursor = std::io::Cursor::new(data);
     let mut archive =
         zip::ZipArchive::new(cursor).map_err(|e| format!("invalid XLSX archive: {e}"))?;
@@ -1032,6 +1063,48 @@ mod tests {
         );
     }
 
+    fn encrypted_office_container() -> Vec<u8> {
+        // Enough of a CFB file for the signature check: the header magic and
+        // a directory entry naming the EncryptedPackage stream.
+        let mut data = CFB_SIGNATURE.to_vec();
+        data.resize(512, 0);
+        data.extend("EncryptedPackage".encode_utf16().flat_map(u16::to_le_bytes));
+        data.resize(1024, 0);
+        data
+    }
+
+    #[test]
+    fn extract_docx_reports_password_protected_document() {
+        let error = extract_text(
+            &encrypted_office_container(),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            Some("secret.docx"),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "DOCX document is password-protected and cannot be read"
+        );
+
+        let error =
+            extract_document_text_by_filename(&encrypted_office_container(), Some("budget.xlsx"))
+                .unwrap_err();
+        assert_eq!(
+            error,
+            "XLSX document is password-protected and cannot be read"
+        );
+    }
+
+    #[test]
+    fn extract_docx_keeps_archive_error_for_unencrypted_cfb() {
+        // A legacy .doc mislabelled as .docx is CFB but not encrypted OOXML;
+        // it must keep the generic archive error rather than claim a password.
+        let mut data = CFB_SIGNATURE.to_vec();
+        data.resize(1024, 0);
+        let error = extract_docx(&data).unwrap_err();
+        assert!(error.starts_with("invalid Office XML archive"), "{error}");
+    }
+
     /// Caller-level: extract_office_xml (DOCX path) must reject an oversized entry.
     #[test]
     fn extract_docx_rejects_oversized_entry() {
@@ -17,8 +17,9 @@ use ironclaw_loop_host::{
     SkillSourceKind, sort_skill_bundle_descriptors,
 };
 use ironclaw_skills::{
-    LoadedSkill, SkillSelectionOptions, SkillSource, SkillTrust, extract_skill_mentions,
-    parse_skill_md, prefilter_skills_with_options, skill_token_cost, validate_skill_name,
+    FuzzyKeywordMatch, LoadedSkill, SkillSelectionOptions, SkillSource, SkillTrust,
+    extract_skill_mentions, parse_skill_md, prefilter_skills_with_options, skill_token_cost,
+    validate_skill_name,
 };
 use ironclaw_turns::run_profile::{LoopRunContext, SkillVisibility};
 use ironclaw_turns::{AcceptedMessageRef, TurnRunId, TurnScope};
@@ -98,6 +99,9 @@ pub struct SkillActivationSelectorConfig {
     pub max_context_tokens: usize,
     pub selection_mode: SkillActivationSelectionMode,
     pub regex_activation_enabled: bool,
+    /// Typo-tolerant keyword matching for criteria selection. `None` keeps
+    /// keyword activation to exact substring hits.
+    pub fuzzy_keywords: Option<FuzzyKeywordMatch>,
     pub injection_mode: SkillInjectionMode,
 }
 
@@ -115,6 +119,7 @@ impl Default for SkillActivationSelectorConfig {
             max_context_tokens: DEFAULT_MAX_SKILL_CONTEXT_TOKENS,
             selection_mode: SkillActivationSelectionMode::ExplicitAndCriteria,
             regex_activation_enabled: true,
+            fuzzy_keywords: None,
             // Library default stays the legacy full-body contract; the Reborn
             // composition seam opts into `Listing` (see
             // `ironclaw_reborn_composition::runtime::local_dev_selector_config`
@@ -145,6 +150,11 @@ impl SkillActivationSelectorConfig {
         self
     }
 
+    pub fn set_fuzzy_keywords(mut self, fuzzy_keywords: Option<FuzzyKeywordMatch>) -> Self {
+        self.fuzzy_keywords = fuzzy_keywords;
+        self
+    }
+
     pub fn set_injection_mode(mut self, injection_mode: SkillInjectionMode) -> Self {
         self.injection_mode = injection_mode;
         self
@@ -263,6 +273,7 @@ where
     // at runtime without a restart. Defaults to `true` (auto-activation on).
     auto_activate_learned: Arc<AtomicBool>,
     setup_marker_source: Option<Arc<dyn SetupMarkerSource>>,
+    skill_state_source: Option<Arc<dyn SkillStateSource>>,
     activation_observer: Mutex<Option<Arc<dyn SkillActivationObserver>>>,
     messages_by_run: Mutex<HashMap<SkillActivationMessageKey, SkillActivationMessage>>,
     activation_cache: Mutex<HashMap<ActivationCandidateCacheKey, CachedActivationCandidate>>,
@@ -282,6 +293,21 @@ pub(crate) trait SetupMarkerSource: std::fmt::Debug + Send + Sync {
     ) -> Result<HashSet<String>, SkillActivationSelectionError>;
 }
 
+/// The operator's persisted skill state (the
+/// [`ironclaw_skills::REGISTRY_STATE_FILE_NAME`] file): read on every turn so
+/// `SkillRegistry::set_enabled` takes effect without a restart, and updated
+/// with the usage counters `SkillRegistry::usage_stats` reports.
+#[async_trait]
+pub(crate) trait SkillStateSource: std::fmt::Debug + Send + Sync {
+    /// Names of skills the operator disabled. Unreadable state counts as
+    /// nothing disabled.
+    async fn disabled_skills(&self, run_context: &LoopRunContext) -> HashSet<String>;
+
+    /// Count one activation of each of `names`. Writes may be batched, and
+    /// failures are logged rather than failing the turn.
+    async fn record_activations(&self, run_context: &LoopRunContext, names: &[String]);
+}
+
 impl<S> SelectableSkillContextSource<S>
 where
     S: SkillBundleSource + ?Sized,
@@ -292,6 +318,7 @@ where
             config,
             auto_activate_learned: Arc::new(AtomicBool::new(true)),
             setup_marker_source: None,
+            skill_state_source: None,
             activation_observer: Mutex::new(None),
             messages_by_run: Mutex::new(HashMap::new()),
             activation_cache: Mutex::new(HashMap::new()),
@@ -318,6 +345,14 @@ where
         self
     }
 
+    pub(crate) fn with_skill_state_source<T>(mut self, source: Arc<T>) -> Self
+    where
+        T: SkillStateSource + 'static,
+    {
+        self.skill_state_source = Some(source);
+        self
+    }
+
     pub fn record_user_message(
         &self,
         scope: TurnScope,
@@ -420,6 +455,7 @@ where
             &effective_config,
             &candidate_set.satisfied_setup_markers,
         )?;
+        self.record_activations(run_context, &selection).await;
         let plan =
             self.merge_active_plan(run_context, activation_plan_for_candidates(selection))?;
         // Refresh the captured execution plan so take_activation_plan_for_run reflects
@@ -476,6 +512,7 @@ where
         let (plan, candidates) = self
             .resolve_activation_plan_with_candidates(run_context, message)
             .await?;
+        self.record_activations(run_context, &plan.selection).await;
         let plan = self.merge_active_plan(run_context, plan)?;
         if capture_plan {
             self.plans_by_run
@@ -603,9 +640,15 @@ where
         let mut satisfied_setup_markers = self
             .satisfied_setup_markers_for_marker_set(run_context, &checked_setup_markers)
             .await?;
+        let channel = run_context
+            .product_context
+            .as_ref()
+            .and_then(|context| context.source_channel.as_ref())
+            .map(|channel| channel.as_str());
         let selection = loop {
             let selection = select_skill_activations(
                 message,
+                channel,
                 &candidates,
                 &self.config,
                 self.auto_activate_learned.load(Ordering::Relaxed),
@@ -635,6 +678,31 @@ where
         Ok((plan, candidates))
     }
 
+    /// Count the skills `selection` loads toward the operator's usage stats.
+    /// Under [`SkillInjectionMode::Listing`] criteria matches only rank the
+    /// listing, so they do not count as used.
+    async fn record_activations(
+        &self,
+        run_context: &LoopRunContext,
+        selection: &SkillActivationSelection,
+    ) {
+        let Some(source) = self.skill_state_source.as_deref() else {
+            return;
+        };
+        let names = selection
+            .activations
+            .iter()
+            .filter(|activation| {
+                self.config.injection_mode == SkillInjectionMode::Full
+                    || activation.mode != SkillActivationMode::ActivationCriteria
+            })
+            .map(|activation| activation.name.clone())
+            .collect::<Vec<_>>();
+        if !names.is_empty() {
+            source.record_activations(run_context, &names).await;
+        }
+    }
+
     async fn load_named_activation_candidate_set(
         &self,
         run_context: &LoopRunContext,
@@ -697,6 +765,16 @@ where
             .list_skill_bundles(run_context)
             .await
             .map_err(skill_bundle_source_error_to_selection_error)?;
+        // Disabled skills never activate: dropping them here keeps them out
+        // of criteria selection, explicit mentions, `skill_activate`, and the
+        // listing alike. The bundle source guarantees the bundle name is the
+        // manifest name the registry disables by.
+        if let Some(source) = self.skill_state_source.as_deref() {
+            let disabled = source.disabled_skills(run_context).await;
+            if !disabled.is_empty() {
+                descriptors.retain(|descriptor| !disabled.contains(descriptor.id().name()));
+            }
+        }
         sort_skill_bundle_descriptors(&mut descriptors);
         validate_descriptor_policy_metadata(&descriptors)?;
         Ok(descriptors)
@@ -1264,6 +1342,8 @@ fn loaded_skill_from_candidate(
     let parsed =
         parse_skill_md(skill_md).map_err(|_| SkillActivationSelectionError::ParseFailed)?;
     let compiled_patterns = LoadedSkill::compile_patterns(&parsed.manifest.activation.patterns);
+    let compiled_exclude_patterns =
+        LoadedSkill::compile_patterns(&parsed.manifest.activation.exclude_patterns);
     let lowercased_keywords = lowercased(&parsed.manifest.activation.keywords);
     let lowercased_exclude_keywords = lowercased(&parsed.manifest.activation.exclude_keywords);
     let lowercased_tags = lowercased(&parsed.manifest.activation.tags);
@@ -1282,6 +1362,7 @@ fn loaded_skill_from_candidate(
         source,
         content_hash: descriptor_context_ordering_key(descriptor),
         compiled_patterns,
+        compiled_exclude_patterns,
         lowercased_keywords,
         lowercased_exclude_keywords,
         lowercased_tags,
@@ -1290,6 +1371,7 @@ fn loaded_skill_from_candidate(
 
 fn select_skill_activations(
     message: &str,
+    channel: Option<&str>,
     candidates: &[ActivationCandidate],
     config: &SkillActivationSelectorConfig,
     auto_activate_learned: bool,
@@ -1351,8 +1433,13 @@ fn select_skill_activations(
             remaining_slots,
             remaining_tokens,
             satisfied_setup_markers,
+            // Disabled skills never reach this point; they are dropped with
+            // their descriptors in `load_activation_descriptors`.
             SkillSelectionOptions {
                 regex_activation_enabled: config.regex_activation_enabled,
+                fuzzy_keywords: config.fuzzy_keywords,
+                channel,
+                ..Default::default()
             },
         );
         feedback.extend(outcome.notes);
@@ -1772,6 +1859,18 @@ mod tests {
         satisfied_markers: HashSet<String>,
     }
 
+    #[derive(Debug, Default)]
+    struct StaticSkillStateSource {
+        disabled: HashSet<String>,
+        recorded: Mutex<Vec<String>>,
+    }
+
+    impl StaticSkillStateSource {
+        fn recorded(&self) -> Vec<String> {
+            self.recorded.lock().unwrap().clone()
+        }
+    }
+
     #[derive(Debug)]
     struct CountingSetupMarkerSource {
         inner: StaticSetupMarkerSource,
@@ -1968,6 +2067,17 @@ mod tests {
         }
     }
 
+    #[async_trait]
+    impl SkillStateSource for StaticSkillStateSource {
+        async fn disabled_skills(&self, _run_context: &LoopRunContext) -> HashSet<String> {
+            self.disabled.clone()
+        }
+
+        async fn record_activations(&self, _run_context: &LoopRunContext, names: &[String]) {
+            self.recorded.lock().unwrap().extend_from_slice(names);
+        }
+    }
+
     #[async_trait]
     impl SetupMarkerSource for CountingSetupMarkerSource {
         async fn satisfied_setup_markers(
@@ -2151,6 +2261,52 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn selector_scopes_criteria_activation_to_source_channel() {
+        let source = Arc::new(StaticSkillBundleSource::new(vec![(
+            SkillSourceKind::User,
+            "slack-summary",
+            &skill_md_with_activation(
+                "slack-summary",
+                "  keywords: [\"summary\"]\n  channels: [\"Slack\"]",
+                "SLACK_SUMMARY_SENTINEL",
+            ),
+        )]));
+        let selectable =
+            SelectableSkillContextSource::new(source, SkillActivationSelectorConfig::default());
+
+        for (channel, expected) in [("slack", 1), ("telegram", 0)] {
+            let context = run_context_for(&format!("thread-{channel}"), "msg:channel")
+                .await
+                .with_product_context(ironclaw_turns::ProductTurnContext::new(
+                    ironclaw_turns::TurnOriginKind::Inbound,
+                    None,
+                    Some(ironclaw_turns::RunOriginAdapter::new(channel).unwrap()),
+                    ironclaw_turns::TurnOwner::Personal {
+                        user: ironclaw_host_api::UserId::new("user-a").unwrap(),
+                    },
+                ));
+            selectable
+                .record_user_message(
+                    context.scope.clone(),
+                    accepted_message_ref(&context),
+                    "give me a summary",
+                )
+                .expect("record message");
+
+            let selected = selectable
+                .load_skill_context_candidates(&context)
+                .await
+                .expect("selection succeeds");
+
+            assert_eq!(
+                selected.len(),
+                expected,
+                "slack-scoped skill activation on {channel}"
+            );
+        }
+    }
+
     fn listing_config() -> SkillActivationSelectorConfig {
         SkillActivationSelectorConfig::default().set_injection_mode(SkillInjectionMode::Listing)
     }
@@ -2189,6 +2345,108 @@ mod tests {
             .expect("available-skills listing candidate")
     }
 
+    #[tokio::test]
+    async fn disabled_skills_never_activate_or_list() {
+        let disabled = Arc::new(StaticSkillStateSource {
+            disabled: HashSet::from(["code-review".to_string()]),
+            ..Default::default()
+        });
+        let full = SelectableSkillContextSource::new(
+            two_skill_source(),
+            SkillActivationSelectorConfig::default(),
+        )
+        .with_skill_state_source(Arc::clone(&disabled));
+        let context = run_context().await;
+        full.record_user_message(
+            context.scope.clone(),
+            accepted_message_ref(&context),
+            "$code-review please review this sheet",
+        )
+        .expect("record message");
+        let selected = full
+            .load_skill_context_candidates(&context)
+            .await
+            .expect("selection succeeds");
+        assert_eq!(selected.len(), 1);
+        assert!(
+            selected[0]
+                .loaded_skill_md()
+                .is_some_and(|skill_md| skill_md.contains("SHEET_SENTINEL")),
+            "neither the mention nor the keyword may activate a disabled skill"
+        );
+
+        let listing = SelectableSkillContextSource::new(two_skill_source(), listing_config())
+            .with_skill_state_source(disabled);
+        let context = run_context().await;
+        let plan = listing
+            .activate_skills_for_run(&context, &["code-review".to_string()])
+            .await
+            .expect("model-selected activation resolves");
+        assert!(plan.selection.activations.is_empty());
+        assert_eq!(
+            plan.selection.feedback,
+            vec!["code-review: requested skill is not available".to_string()]
+        );
+        let text = listing_text(
+            &listing
+                .load_skill_context_candidates(&context)
+                .await
+                .expect("listing load succeeds"),
+        );
+        assert!(text.contains("- spreadsheet:"));
+        assert!(!text.contains("- code-review:"), "{text}");
+    }
+
+    #[tokio::test]
+    async fn selected_skills_are_recorded_once_as_used() {
+        let state = Arc::new(StaticSkillStateSource::default());
+        let full = SelectableSkillContextSource::new(
+            two_skill_source(),
+            SkillActivationSelectorConfig::default(),
+        )
+        .with_skill_state_source(Arc::clone(&state));
+        let context = run_context().await;
+        full.record_user_message(
+            context.scope.clone(),
+            accepted_message_ref(&context),
+            "please review this PR",
+        )
+        .expect("record message");
+        full.load_skill_context_candidates(&context)
+            .await
+            .expect("selection succeeds");
+        // Later prompt builds of the same run reuse the active plan and do
+        // not count again.
+        full.load_skill_context_candidates(&context)
+            .await
+            .expect("active plan load succeeds");
+        assert_eq!(state.recorded(), vec!["code-review".to_string()]);
+
+        // Listing mode: a criteria match only ranks the listing; loading
+        // the body via skill_activate is what counts.
+        let state = Arc::new(StaticSkillStateSource::default());
+        let listing = SelectableSkillContextSource::new(two_skill_source(), listing_config())
+            .with_skill_state_source(Arc::clone(&state));
+        let context = run_context().await;
+        listing
+            .record_user_message(
+                context.scope.clone(),
+                accepted_message_ref(&context),
+                "please review this sheet",
+            )
+            .expect("record message");
+        listing
+            .load_skill_context_candidates(&context)
+            .await
+            .expect("selection succeeds");
+        assert!(state.recorded().is_empty());
+        listing
+            .activate_skills_for_run(&context, &["spreadsheet".to_string()])
+            .await
+            .expect("model-selected activation succeeds");
+        assert_eq!(state.recorded(), vec!["spreadsheet".to_string()]);
+    }
+
     #[tokio::test]
     async fn listing_mode_lists_criteria_matched_skill_without_injecting_body() {
         let selectable = SelectableSkillContextSource::new(two_skill_source(), listing_config());
@@ -2511,6 +2769,46 @@ mod tests {
         assert!(!combined.contains("REGEX_REVIEW_SENTINEL"));
     }
 
+    #[tokio::test]
+    async fn selector_applies_configured_fuzzy_keyword_matching() {
+        let source = Arc::new(StaticSkillBundleSource::new(vec![(
+            SkillSourceKind::User,
+            "deploy-helper",
+            &skill_md(
+                "deploy-helper",
+                "Ship releases",
+                &["deployment"],
+                "DEPLOY_HELPER_SENTINEL",
+            ),
+        )]));
+        let context = run_context().await;
+        let mut selected_by_config = Vec::new();
+        for fuzzy_keywords in [None, Some(FuzzyKeywordMatch::default())] {
+            let selectable = SelectableSkillContextSource::new(
+                Arc::clone(&source),
+                SkillActivationSelectorConfig::default().set_fuzzy_keywords(fuzzy_keywords),
+            );
+            selectable
+                .record_user_message(
+                    context.scope.clone(),
+                    accepted_message_ref(&context),
+                    "help with the deploymnet please",
+                )
+                .expect("record message");
+            let selected = selectable
+                .load_skill_context_candidates(&context)
+                .await
+                .expect("selection succeeds");
+            selected_by_config.push(selected.len());
+        }
+
+        assert_eq!(
+            selected_by_config,
+            vec![0, 1],
+            "a misspelled keyword activates the skill only when fuzzy matching is configured"
+        );
+    }
+
     #[tokio::test]
     async fn selector_keeps_explicit_activation_when_regex_activation_is_disabled() {
         let source = Arc::new(StaticSkillBundleSource::new(vec![(
@@ -10,6 +10,7 @@ mod assets;
 mod error;
 mod execution;
 mod setup_markers;
+mod skill_state;
 mod skills;
 
 pub use activation::{
@@ -0,0 +1,421 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use ironclaw_filesystem::{FilesystemError, RootFilesystem, ScopedFilesystem};
+use ironclaw_host_api::{ResourceScope, ScopedPath, TenantId, UserId};
+use ironclaw_skills::{REGISTRY_STATE_FILE_NAME, SKILL_USAGE_WRITE_INTERVAL, SkillRegistryState};
+use ironclaw_turns::run_profile::LoopRunContext;
+
+use crate::activation::SkillStateSource;
+
+/// Upper bound on the registry state file read per turn.
+const MAX_SKILL_STATE_BYTES: usize = 256 * 1024;
+
+/// Reads the operator's [`REGISTRY_STATE_FILE_NAME`] from the user skills
+/// root, the same file `SkillRegistry::set_enabled` persists, and folds
+/// activation counts back into it at most once per
+/// [`SKILL_USAGE_WRITE_INTERVAL`] per user. Counts batched in between are
+/// flushed when the interval ends, even if the user goes quiet.
+pub(crate) struct FilesystemSkillStateSource<F>
+where
+    F: RootFilesystem + 'static,
+{
+    state_file: Arc<SkillStateFile<F>>,
+}
+
+/// The state file and the per-user counts not yet written to it, shared
+/// with scheduled flushes.
+struct SkillStateFile<F>
+where
+    F: RootFilesystem + 'static,
+{
+    filesystem: Arc<ScopedFilesystem<F>>,
+    state_path: ScopedPath,
+    usage_batches: Mutex<HashMap<(TenantId, UserId), UsageBatch>>,
+}
+
+/// Activations counted for one user since their state file was last written.
+#[derive(Default)]
+struct UsageBatch {
+    pending: SkillRegistryState,
+    written_at: Option<Instant>,
+    /// Whether a flush of `pending` is already scheduled.
+    flush_scheduled: bool,
+}
+
+impl<F> std::fmt::Debug for FilesystemSkillStateSource<F>
+where
+    F: RootFilesystem + 'static,
+{
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("FilesystemSkillStateSource")
+            .field("filesystem", &self.state_file.filesystem)
+            .field("state_path", &self.state_file.state_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> FilesystemSkillStateSource<F>
+where
+    F: RootFilesystem + 'static,
+{
+    /// `None` when `user_skills_root` cannot hold the state file.
+    pub(crate) fn new(
+        filesystem: Arc<ScopedFilesystem<F>>,
+        user_skills_root: &ScopedPath,
+    ) -> Option<Self> {
+        let state_path = ScopedPath::new(format!(
+            "{}/{}",
+            user_skills_root.as_str().trim_end_matches('/'),
+            REGISTRY_STATE_FILE_NAME
+        ))
+        .ok()?;
+        Some(Self {
+            state_file: Arc::new(SkillStateFile {
+                filesystem,
+                state_path,
+                usage_batches: Mutex::new(HashMap::new()),
+            }),
+        })
+    }
+}
+
+impl<F> SkillStateFile<F>
+where
+    F: RootFilesystem + 'static,
+{
+    /// The persisted state, empty when the file does not exist yet. `None`
+    /// when it exists but cannot be used, so callers never overwrite an
+    /// operator's `disabled` list they could not read.
+    async fn read_state(&self, scope: &ResourceScope) -> Option<SkillRegistryState> {
+        let bytes = match self
+            .filesystem
+            .read_bytes_bounded(scope, &self.state_path, MAX_SKILL_STATE_BYTES)
+            .await
+        {
+            Ok(Some(bytes)) => bytes,
+            Ok(None) => {
+                tracing::warn!(
+                    path = %self.state_path.as_str(),
+                    "ignoring oversized skill registry state"
+                );
+                return None;
+            }
+            Err(FilesystemError::NotFound { .. }) => return Some(SkillRegistryState::default()),
+            Err(error) => {
+                tracing::debug!(
+                    path = %self.state_path.as_str(),
+                    %error,
+                    "skill registry state is unavailable"
+                );
+                return None;
+            }
+        };
+        match SkillRegistryState::from_json(&bytes) {
+            Ok(state) => Some(state),
+            Err(error) => {
+                tracing::warn!(
+                    path = %self.state_path.as_str(),
+                    %error,
+                    "ignoring malformed skill registry state"
+                );
+                None
+            }
+        }
+    }
+
+    /// Merge `pending` into the state file. Returns `false` when nothing was
+    /// written, so the caller keeps the counts for the next attempt.
+    async fn write_usage(&self, scope: &ResourceScope, pending: &SkillRegistryState) -> bool {
+        let Some(mut state) = self.read_state(scope).await else {
+            return false;
+        };
+        state.merge_usage(pending.usage.clone());
+        let bytes = match state.to_json() {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                tracing::debug!(%error, "failed to serialize skill usage");
+                return false;
+            }
+        };
+        match self
+            .filesystem
+            .write_bytes(scope, &self.state_path, bytes)
+            .await
+        {
+            Ok(()) => true,
+            Err(error) => {
+                tracing::debug!(
+                    path = %self.state_path.as_str(),
+                    %error,
+                    "failed to persist skill usage"
+                );
+                false
+            }
+        }
+    }
+
+    /// Write `pending`, handing the counts back to the user's batch when the
+    /// write fails.
+    async fn write_pending(
+        &self,
+        scope: &ResourceScope,
+        key: (TenantId, UserId),
+        pending: SkillRegistryState,
+    ) {
+        if !self.write_usage(scope, &pending).await {
+            self.usage_batches()
+                .entry(key)
+                .or_default()
+                .pending
+                .merge_usage(pending.usage);
+        }
+    }
+
+    /// After `delay`, write whatever the user's batch still holds. This is
+    /// what persists the counts of a user who stops activating skills before
+    /// the interval ends.
+    async fn flush_after(self: Arc<Self>, scope: ResourceScope, delay: Duration) {
+        tokio::time::sleep(delay).await;
+        let key = (scope.tenant_id.clone(), scope.user_id.clone());
+        let pending = {
+            let mut batches = self.usage_batches();
+            let Some(batch) = batches.get_mut(&key) else {
+                return;
+            };
+            batch.flush_scheduled = false;
+            if batch.pending.usage.is_empty() {
+                return;
+            }
+            batch.written_at = Some(Instant::now());
+            std::mem::take(&mut batch.pending)
+        };
+        self.write_pending(&scope, key, pending).await;
+    }
+
+    fn usage_batches(&self) -> std::sync::MutexGuard<'_, HashMap<(TenantId, UserId), UsageBatch>> {
+        self.usage_batches
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl<F> SkillStateSource for FilesystemSkillStateSource<F>
+where
+    F: RootFilesystem + 'static,
+{
+    async fn disabled_skills(&self, run_context: &LoopRunContext) -> HashSet<String> {
+        self.state_file
+            .read_state(&state_scope_for_run(run_context))
+            .await
+            .unwrap_or_default()
+            .disabled
+            .into_iter()
+            .collect()
+    }
+
+    async fn record_activations(&self, run_context: &LoopRunContext, names: &[String]) {
+        let scope = state_scope_for_run(run_context);
+        let key = (scope.tenant_id.clone(), scope.user_id.clone());
+        let pending = {
+            let mut batches = self.state_file.usage_batches();
+            let batch = batches.entry(key.clone()).or_default();
+            batch
+                .pending
+                .record_activations(names.iter().map(String::as_str));
+            if let Some(elapsed) = batch
+                .written_at
+                .map(|written_at| written_at.elapsed())
+                .filter(|elapsed| *elapsed < SKILL_USAGE_WRITE_INTERVAL)
+            {
+                if !batch.flush_scheduled {
+                    batch.flush_scheduled = true;
+                    tokio::spawn(
+                        Arc::clone(&self.state_file)
+                            .flush_after(scope, SKILL_USAGE_WRITE_INTERVAL - elapsed),
+                    );
+                }
+                return;
+            }
+            // Claim the batch before the write so concurrent turns wait for
+            // the next interval instead of writing the same counts again.
+            batch.written_at = Some(Instant::now());
+            std::mem::take(&mut batch.pending)
+        };
+        self.state_file.write_pending(&scope, key, pending).await;
+    }
+}
+
+/// The scope user skill bundles are listed under, so the state file is
+/// read from the same per-user root.
+fn state_scope_for_run(run_context: &LoopRunContext) -> ResourceScope {
+    let mut scope = run_context.scope.to_resource_scope();
+    if let Some(actor) = run_context.actor() {
+        scope.user_id = actor.user_id.clone();
+    }
+    scope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ironclaw_filesystem::{CasExpectation, Entry, InMemoryBackend};
+    use ironclaw_host_api::{
+        AgentId, MountAlias, MountGrant, MountPermissions, MountView, ProjectId, TenantId, UserId,
+        VirtualPath,
+    };
+    use ironclaw_turns::{
+        TurnActor, TurnId, TurnRunId, TurnScope,
+        run_profile::{
+            InMemoryRunProfileResolver, RunProfileResolutionRequest, RunProfileResolver,
+        },
+    };
+
+    const STATE_PATH: &str = "/tenants/tenant-a/users/user-a/skills/.ironclaw-skill-state.json";
+
+    async fn run_context() -> LoopRunContext {
+        let resolved = InMemoryRunProfileResolver::default()
+            .resolve_run_profile(RunProfileResolutionRequest::interactive_default())
+            .await
+            .expect("run profile resolves");
+        LoopRunContext::new(
+            TurnScope::new(
+                TenantId::new("tenant-a").expect("valid tenant"),
+                Some(AgentId::new("agent-a").expect("valid agent")),
+                Some(ProjectId::new("project-a").expect("valid project")),
+                ironclaw_host_api::ThreadId::new("thread-a").expect("valid thread"),
+            ),
+            TurnId::new(),
+            TurnRunId::new(),
+            resolved,
+        )
+        .with_actor(TurnActor::new(UserId::new("user-a").expect("valid user")))
+    }
+
+    async fn source_with_state(
+        state: Option<&[u8]>,
+    ) -> FilesystemSkillStateSource<InMemoryBackend> {
+        let root = Arc::new(InMemoryBackend::default());
+        if let Some(state) = state {
+            root.put(
+                &VirtualPath::new(STATE_PATH).expect("valid path"),
+                Entry::bytes(state.to_vec()),
+                CasExpectation::Any,
+            )
+            .await
+            .expect("write state");
+        }
+        let view = MountView::new(vec![MountGrant::new(
+            MountAlias::new("/skills").expect("valid alias"),
+            VirtualPath::new("/tenants/tenant-a/users/user-a/skills").expect("valid path"),
+            MountPermissions::read_write(),
+        )])
+        .expect("mount view");
+        FilesystemSkillStateSource::new(
+            Arc::new(ScopedFilesystem::with_fixed_view(root, view)),
+            &ScopedPath::new("/skills").expect("valid root"),
+        )
+        .expect("state path")
+    }
+
+    #[tokio::test]
+    async fn reads_disabled_skills_from_the_user_root() {
+        let source = source_with_state(Some(br#"{"disabled":["noisy"],"usage":{}}"#)).await;
+
+        assert_eq!(
+            source.disabled_skills(&run_context().await).await,
+            HashSet::from(["noisy".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_or_malformed_state_disables_nothing() {
+        for state in [None, Some(&b"not json"[..])] {
+            let source = source_with_state(state).await;
+            assert!(
+                source
+                    .disabled_skills(&run_context().await)
+                    .await
+                    .is_empty()
+            );
+        }
+    }
+
+    async fn persisted_state(
+        source: &FilesystemSkillStateSource<InMemoryBackend>,
+    ) -> SkillRegistryState {
+        source
+            .state_file
+            .read_state(&state_scope_for_run(&run_context().await))
+            .await
+            .expect("state readable")
+    }
+
+    #[tokio::test]
+    async fn activations_are_written_at_most_once_per_interval() {
+        let source = source_with_state(Some(br#"{"disabled":["noisy"],"usage":{}}"#)).await;
+        let context = run_context().await;
+        let names = ["code-review".to_string()];
+
+        source.record_activations(&context, &names).await;
+        source.record_activations(&context, &names).await;
+
+        let state = persisted_state(&source).await;
+        assert_eq!(state.disabled, vec!["noisy".to_string()]);
+        assert_eq!(state.usage["code-review"].activation_count, 1);
+        assert_eq!(
+            source.state_file.usage_batches()[&(
+                TenantId::new("tenant-a").expect("valid tenant"),
+                UserId::new("user-a").expect("valid user")
+            )]
+                .pending
+                .usage["code-review"]
+                .activation_count,
+            1
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn quiet_batch_is_flushed_when_the_interval_ends() {
+        let source = source_with_state(None).await;
+        let context = run_context().await;
+        let names = ["code-review".to_string()];
+
+        source.record_activations(&context, &names).await;
+        source.record_activations(&context, &names).await;
+        source.record_activations(&context, &names).await;
+        assert_eq!(
+            persisted_state(&source).await.usage["code-review"].activation_count,
+            1
+        );
+
+        tokio::time::sleep(SKILL_USAGE_WRITE_INTERVAL).await;
+        tokio::task::yield_now().await;
+
+        assert_eq!(
+            persisted_state(&source).await.usage["code-review"].activation_count,
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn malformed_state_is_never_overwritten_by_usage() {
+        let source = source_with_state(Some(b"not json")).await;
+
+        source
+            .record_activations(&run_context().await, &["code-review".to_string()])
+            .await;
+
+        assert!(
+            source
+                .state_file
+                .read_state(&state_scope_for_run(&run_context().await))
+                .await
+                .is_none()
+        );
+    }
+}
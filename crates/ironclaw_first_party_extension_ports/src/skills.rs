@@ -10,6 +10,7 @@ use ironclaw_loop_host::{
 use crate::{
     SelectableSkillContextSource, SkillActivationSelectorConfig, SkillExecutionAdapter,
     error::FirstPartySkillsExtensionError, setup_markers::FilesystemSetupMarkerSource,
+    skill_state::FilesystemSkillStateSource,
 };
 
 const SYSTEM_SKILLS_ROOT: &str = "/system/skills";
@@ -103,6 +104,7 @@ where
     F: RootFilesystem + 'static,
 {
     bundle_source: Arc<FilesystemSkillBundleSource<F>>,
+    skill_state_source: Option<Arc<FilesystemSkillStateSource<F>>>,
     default_selectable_runtime: FirstPartySelectableSkillsRuntime<F>,
 }
 
@@ -189,14 +191,18 @@ where
         handles: FirstPartySkillsExtensionHandles,
         tenant_id: TenantId,
     ) -> Result<Self, FirstPartySkillsExtensionError> {
+        let skill_state_source = handles.user_skills().and_then(|root| {
+            FilesystemSkillStateSource::new(Arc::clone(&filesystem), root).map(Arc::new)
+        });
         let bundle_source = Arc::new(
             FilesystemSkillBundleSource::new(filesystem, handles.bundle_roots(&tenant_id))
                 .map_err(|error| {
                     FirstPartySkillsExtensionError::InvalidBundleSource(error.to_string())
                 })?,
         );
-        let default_selectable_context_source = Arc::new(SelectableSkillContextSource::new(
-            Arc::clone(&bundle_source),
+        let default_selectable_context_source = Arc::new(selectable_source(
+            &bundle_source,
+            skill_state_source.as_ref(),
             SkillActivationSelectorConfig::default(),
         ));
         let execution_adapter = Arc::new(SkillExecutionAdapter::new(Arc::clone(
@@ -208,6 +214,7 @@ where
         );
         Ok(Self {
             bundle_source,
+            skill_state_source,
             default_selectable_runtime,
         })
     }
@@ -227,8 +234,9 @@ where
         if config == SkillActivationSelectorConfig::default() {
             return self.default_selectable_runtime.activation_source();
         }
-        Arc::new(SelectableSkillContextSource::new(
-            Arc::clone(&self.bundle_source),
+        Arc::new(selectable_source(
+            &self.bundle_source,
+            self.skill_state_source.as_ref(),
             config,
         ))
     }
@@ -257,9 +265,13 @@ where
     {
         let setup_marker_source = Arc::new(FilesystemSetupMarkerSource::new(workspace_filesystem));
         let activation_source = Arc::new(
-            SelectableSkillContextSource::new(Arc::clone(&self.bundle_source), config)
-                .with_auto_activate_flag(auto_activate_flag)
-                .with_setup_marker_source(setup_marker_source),
+            selectable_source(
+                &self.bundle_source,
+                self.skill_state_source.as_ref(),
+                config,
+            )
+            .with_auto_activate_flag(auto_activate_flag)
+            .with_setup_marker_source(setup_marker_source),
         );
         let execution_adapter =
             Arc::new(SkillExecutionAdapter::new(Arc::clone(&activation_source)));
@@ -273,6 +285,23 @@ where
     }
 }
 
+/// A selector over `bundle_source` that honors the operator's persisted
+/// disabled skills when the user skills root is granted.
+fn selectable_source<F>(
+    bundle_source: &Arc<FilesystemSkillBundleSource<F>>,
+    skill_state_source: Option<&Arc<FilesystemSkillStateSource<F>>>,
+    config: SkillActivationSelectorConfig,
+) -> SelectableSkillContextSource<FilesystemSkillBundleSource<F>>
+where
+    F: RootFilesystem + 'static,
+{
+    let source = SelectableSkillContextSource::new(Arc::clone(bundle_source), config);
+    match skill_state_source {
+        Some(state) => source.with_skill_state_source(Arc::clone(state)),
+        None => source,
+    }
+}
+
 fn scoped_root(path: &'static str) -> Result<ScopedPath, FirstPartySkillsExtensionError> {
     ScopedPath::new(path)
         .map_err(|reason| FirstPartySkillsExtensionError::InvalidRootPath(reason.to_string()))
@@ -537,6 +566,59 @@ mod tests {
         assert!(!entries.iter().any(|entry| entry.name == "user-helper"));
     }
 
+    #[tokio::test]
+    async fn extension_selector_honors_persisted_disabled_skills() {
+        let root = Arc::new(InMemoryBackend::default());
+        for name in ["user-helper", "other-helper"] {
+            write_file(
+                &root,
+                &format!("/tenants/tenant-a/users/user-a/skills/{name}/SKILL.md"),
+                skill_md(name, &format!("{name} description"), &name.to_uppercase()),
+            )
+            .await;
+        }
+        write_file(
+            &root,
+            &format!(
+                "/tenants/tenant-a/users/user-a/skills/{}",
+                ironclaw_skills::REGISTRY_STATE_FILE_NAME
+            ),
+            br#"{"disabled":["user-helper"]}"#.to_vec(),
+        )
+        .await;
+        let extension = FirstPartySkillsExtension::new(
+            scoped_filesystem(root),
+            FirstPartySkillsExtensionHandles::without_tenant_shared().unwrap(),
+            TenantId::new("tenant-a").unwrap(),
+        )
+        .unwrap();
+        let context = run_context()
+            .await
+            .with_accepted_message_ref(AcceptedMessageRef::new("accepted-disabled").unwrap());
+        extension
+            .selectable_skill_context_source(SkillActivationSelectorConfig::default())
+            .record_user_message(
+                context.scope.clone(),
+                context.accepted_message_ref.clone().unwrap(),
+                "$user-helper $other-helper",
+            )
+            .unwrap();
+
+        let candidates = extension
+            .host_skill_context_source()
+            .load_skill_context_candidates(&context)
+            .await
+            .unwrap();
+        let snapshot = build_skill_run_snapshot(candidates).unwrap();
+        let names: Vec<_> = snapshot
+            .entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["other-helper"]);
+    }
+
     #[tokio::test]
     async fn extension_bundle_source_reads_only_skill_handles() {
         let root = Arc::new(InMemoryBackend::default());
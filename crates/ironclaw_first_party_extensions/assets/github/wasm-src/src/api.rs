@@ -1,5 +1,6 @@
 mod actions;
 mod contents;
+mod graphql;
 mod issues;
 mod pulls;
 mod repos;
@@ -8,6 +9,7 @@ mod search;
 
 pub(crate) use actions::*;
 pub(crate) use contents::*;
+pub(crate) use graphql::*;
 pub(crate) use issues::*;
 pub(crate) use pulls::*;
 pub(crate) use repos::*;
@@ -39,6 +39,56 @@ pub(crate) fn trigger_workflow(
     github_request("POST", &path, Some(req_body.to_string()))
 }
 
+/// GitHub's limit on a `repository_dispatch` `event_type`.
+const MAX_EVENT_TYPE_LENGTH: usize = 100;
+/// GitHub's limit on top-level `client_payload` properties.
+const MAX_CLIENT_PAYLOAD_PROPERTIES: usize = 10;
+
+/// Send a `repository_dispatch` event for workflows listening on `event_type`.
+///
+/// GitHub answers with an empty 204, so the result spells out what was sent
+/// instead of echoing a bare status.
+pub(crate) fn repository_dispatch(
+    owner: &str,
+    repo: &str,
+    event_type: &str,
+    client_payload: Option<serde_json::Value>,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    if event_type.trim().is_empty() || event_type.len() > MAX_EVENT_TYPE_LENGTH {
+        return Err("invalid_event_type".into());
+    }
+    let mut req_body = serde_json::json!({
+        "event_type": event_type,
+    });
+    if let Some(client_payload) = client_payload {
+        match client_payload.as_object() {
+            Some(fields) if fields.len() <= MAX_CLIENT_PAYLOAD_PROPERTIES => {}
+            _ => return Err("invalid_client_payload".into()),
+        }
+        validate_input_length(&client_payload.to_string(), "client_payload")?;
+        req_body["client_payload"] = client_payload;
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let path = format!("/repos/{encoded_owner}/{encoded_repo}/dispatches");
+    let response = github_request("POST", &path, Some(req_body.to_string()))?;
+    let status = serde_json::from_str::<serde_json::Value>(&response)
+        .ok()
+        .and_then(|response| response["status"].as_u64())
+        .unwrap_or(204);
+    Ok(serde_json::json!({
+        "status": status,
+        "event_type": event_type,
+        "message": format!(
+            "repository_dispatch event '{event_type}' sent to {owner}/{repo}; workflows listening for it will start shortly"
+        ),
+    })
+    .to_string())
+}
+
 // arch-exempt: too_many_args, action-run query fans out across many optional filters, plan #5171
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn get_workflow_runs(
@@ -160,6 +210,108 @@ pub(crate) fn get_job_logs(owner: &str, repo: &str, job_id: u64) -> Result<Strin
     serde_json::to_string(&body).map_err(|err| format!("github_job_logs_encode_failed_{err}"))
 }
 
+/// Failed jobs whose logs `get_workflow_run_logs` fetches per call.
+const MAX_RUN_LOG_JOBS: usize = 5;
+/// Tail of each failed job's log kept by `get_workflow_run_logs`; failures are
+/// reported at the end of a job log.
+const MAX_RUN_JOB_LOG_TAIL_BYTES: usize = 16 * 1024;
+
+/// Collect the failure output of one workflow run.
+///
+/// GitHub's run-level `/logs` endpoint returns a zip archive, which the tool
+/// cannot decode, so this lists the run's latest-attempt jobs and fetches the
+/// plain-text log of each failed one (the same endpoint as `get_job_logs`),
+/// keeping the tail of each. A failed log fetch is reported on that job
+/// instead of failing the whole call.
+pub(crate) fn get_workflow_run_logs(
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let jobs_path = format!(
+        "/repos/{encoded_owner}/{encoded_repo}/actions/runs/{run_id}/jobs?per_page=100&filter=latest"
+    );
+    let jobs: serde_json::Value = serde_json::from_str(&github_request("GET", &jobs_path, None)?)
+        .map_err(|_| "github_api_invalid_json".to_string())?;
+    let failed: Vec<&serde_json::Value> = jobs["jobs"]
+        .as_array()
+        .map(|jobs| {
+            jobs.iter()
+                .filter(|job| is_failed_conclusion(&job["conclusion"]))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut failed_jobs = Vec::new();
+    for job in failed.iter().take(MAX_RUN_LOG_JOBS) {
+        let job_id = job["id"].as_u64().ok_or("github_api_invalid_json")?;
+        let failed_steps: Vec<&serde_json::Value> = job["steps"]
+            .as_array()
+            .map(|steps| {
+                steps
+                    .iter()
+                    .filter(|step| is_failed_conclusion(&step["conclusion"]))
+                    .map(|step| &step["name"])
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut entry = serde_json::json!({
+            "id": job_id,
+            "name": job["name"],
+            "conclusion": job["conclusion"],
+            "failed_steps": failed_steps,
+        });
+        let log_path = format!("/repos/{encoded_owner}/{encoded_repo}/actions/jobs/{job_id}/logs");
+        match github_request("GET", &log_path, None) {
+            Ok(log) => {
+                let (tail, truncated) = log_tail(&log, MAX_RUN_JOB_LOG_TAIL_BYTES);
+                entry["log"] = serde_json::json!(tail);
+                entry["log_truncated"] = serde_json::json!(truncated);
+            }
+            Err(error) => entry["log_error"] = serde_json::json!(error),
+        }
+        failed_jobs.push(entry);
+    }
+
+    Ok(serde_json::json!({
+        "run_id": run_id,
+        "failed_jobs": failed_jobs,
+        "omitted_failed_jobs": failed.len().saturating_sub(MAX_RUN_LOG_JOBS),
+    })
+    .to_string())
+}
+
+fn is_failed_conclusion(conclusion: &serde_json::Value) -> bool {
+    matches!(
+        conclusion.as_str(),
+        Some("failure" | "timed_out" | "startup_failure")
+    )
+}
+
+/// Keep the last `max_bytes` of `log` (on a char boundary), prefixed with a
+/// marker when anything was dropped.
+fn log_tail(log: &str, max_bytes: usize) -> (String, bool) {
+    if log.len() <= max_bytes {
+        return (log.to_string(), false);
+    }
+    let mut start = log.len() - max_bytes;
+    while !log.is_char_boundary(start) {
+        start += 1;
+    }
+    (
+        format!(
+            "[... {start} earlier bytes truncated ...]\n{}",
+            &log[start..]
+        ),
+        true,
+    )
+}
+
 pub(crate) fn get_workflow_run_artifacts(
     owner: &str,
     repo: &str,
@@ -222,6 +374,46 @@ pub(crate) fn rerun_workflow_job(
     )
 }
 
+/// Cancel a queued or in-progress workflow run.
+///
+/// GitHub refuses to cancel a run that has already completed (409, or 403 for
+/// some run states); both surface as `github_workflow_run_not_cancellable`
+/// rather than a bare status code.
+pub(crate) fn cancel_workflow_run(owner: &str, repo: &str, run_id: u64) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let path = format!("/repos/{encoded_owner}/{encoded_repo}/actions/runs/{run_id}/cancel");
+    github_request("POST", &path, None).map_err(|error| match error.as_str() {
+        "github_api_error_status_403" | "github_api_error_status_409" => {
+            "github_workflow_run_not_cancellable".to_string()
+        }
+        _ => error,
+    })
+}
+
+/// Rerun a whole workflow run, or only its failed jobs when `failed_only`.
+pub(crate) fn rerun_workflow(
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+    failed_only: bool,
+    enable_debug_logging: Option<bool>,
+) -> Result<String, String> {
+    if failed_only {
+        return rerun_failed_workflow_run_jobs(owner, repo, run_id, enable_debug_logging);
+    }
+    workflow_rerun_request(
+        owner,
+        repo,
+        &format!("/actions/runs/{run_id}/rerun"),
+        enable_debug_logging,
+        None,
+    )
+}
+
 fn workflow_rerun_request(
     owner: &str,
     repo: &str,
@@ -38,6 +38,81 @@ pub(crate) fn get_file_content(
     github_request("GET", &url_path, None)
 }
 
+/// List the paths in a repository tree, with blob SHAs for files.
+///
+/// Without `ref` the repository's default branch is listed. GitHub caps
+/// recursive listings and sets `truncated` on very large repositories; the
+/// flag is passed through so the caller can narrow the walk by listing a
+/// subtree's SHA instead of assuming the listing is complete.
+pub(crate) fn list_tree(
+    owner: &str,
+    repo: &str,
+    r#ref: Option<&str>,
+    recursive: bool,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    if let Some(r#ref) = r#ref {
+        validate_git_ref(r#ref, "ref")?;
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+
+    let tree_ref = match r#ref {
+        Some(r#ref) => r#ref.to_string(),
+        None => {
+            let response = github_request(
+                "GET",
+                &format!("/repos/{encoded_owner}/{encoded_repo}"),
+                None,
+            )?;
+            let repository: serde_json::Value = serde_json::from_str(&response)
+                .map_err(|_| "github_api_invalid_json".to_string())?;
+            let default_branch = repository["default_branch"]
+                .as_str()
+                .ok_or_else(|| "GitHub repository has no default branch".to_string())?;
+            validate_git_ref(default_branch, "default_branch")?;
+            default_branch.to_string()
+        }
+    };
+
+    let mut path = format!(
+        "/repos/{encoded_owner}/{encoded_repo}/git/trees/{}",
+        encode_repo_path(&tree_ref)
+    );
+    if recursive {
+        path.push_str("?recursive=1");
+    }
+    let response = github_request("GET", &path, None)?;
+    let tree: serde_json::Value =
+        serde_json::from_str(&response).map_err(|_| "github_api_invalid_json".to_string())?;
+    let entries: Vec<serde_json::Value> = tree["tree"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| {
+            let mut summary = serde_json::json!({
+                "path": entry["path"],
+                "type": entry["type"],
+                "sha": entry["sha"],
+            });
+            if let Some(size) = entry["size"].as_u64() {
+                summary["size"] = serde_json::json!(size);
+            }
+            summary
+        })
+        .collect();
+    Ok(serde_json::json!({
+        "ref": tree_ref,
+        "sha": tree["sha"],
+        "truncated": tree["truncated"].as_bool().unwrap_or(false),
+        "entries": entries,
+    })
+    .to_string())
+}
+
 // arch-exempt: too_many_args, file write inputs stay split to mirror GitHub payload shape, plan #5171
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn create_or_update_file(
@@ -0,0 +1,37 @@
+use crate::request::github_request;
+use crate::validation::*;
+
+/// Cap on the serialized `variables` object sent with a passthrough query.
+const MAX_GRAPHQL_VARIABLES_BYTES: usize = 16 * 1024;
+
+/// Send a caller-supplied query to GitHub's GraphQL API.
+///
+/// Unlike the typed review-thread helpers, GraphQL `errors` are returned with
+/// any partial `data` instead of failing the call, so callers can see which
+/// field of their own query GitHub rejected.
+pub(crate) fn graphql(query: &str, variables: Option<serde_json::Value>) -> Result<String, String> {
+    if query.trim().is_empty() {
+        return Err("invalid_query_empty".to_string());
+    }
+    validate_input_length(query, "query")?;
+    let variables = variables.unwrap_or_else(|| serde_json::json!({}));
+    if !variables.is_object() {
+        return Err("invalid_variables".to_string());
+    }
+    if variables.to_string().len() > MAX_GRAPHQL_VARIABLES_BYTES {
+        return Err("invalid_variables_too_large".to_string());
+    }
+
+    let req_body = serde_json::json!({
+        "query": query,
+        "variables": variables,
+    });
+    let response = github_request("POST", "/graphql", Some(req_body.to_string()))?;
+    let response: serde_json::Value = serde_json::from_str(&response)
+        .map_err(|err| format!("github_api_invalid_json: graphql response parse failed: {err}"))?;
+    Ok(serde_json::json!({
+        "data": response.get("data").cloned().unwrap_or(serde_json::Value::Null),
+        "errors": response.get("errors").cloned().unwrap_or(serde_json::Value::Null),
+    })
+    .to_string())
+}
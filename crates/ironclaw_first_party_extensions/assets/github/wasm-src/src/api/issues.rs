@@ -1,10 +1,15 @@
 use crate::request::github_request;
-use crate::types::IssueState;
+use crate::types::{IssueLockReason, IssueState};
 use crate::validation::*;
 
 const MAX_ASSIGNEES_PER_REQUEST: usize = 10;
 const MAX_LIST_ISSUES_LOGICAL_PAGE: u32 = 10;
 const MAX_LIST_ISSUES_RAW_PAGES: u32 = 10;
+/// Comments `get_issue` inlines with `include_comments`; longer threads are
+/// truncated and flagged so the caller can page the rest with
+/// `list_issue_comments`.
+const MAX_GET_ISSUE_COMMENTS: usize = 200;
+const GET_ISSUE_COMMENTS_PAGE_SIZE: usize = 100;
 
 // arch-exempt: too_many_args, issue listing keeps GitHub filter args explicit, plan #5171
 #[allow(clippy::too_many_arguments)]
@@ -337,6 +342,36 @@ pub(crate) fn remove_issue_assignees(
     )
 }
 
+pub(crate) fn lock_issue(
+    owner: &str,
+    repo: &str,
+    issue_number: u32,
+    lock_reason: Option<IssueLockReason>,
+) -> Result<String, String> {
+    let path = issue_lock_path(owner, repo, issue_number)?;
+    let req_body =
+        lock_reason.map(|reason| serde_json::json!({ "lock_reason": reason.as_str() }).to_string());
+    github_request("PUT", &path, req_body)
+}
+
+pub(crate) fn unlock_issue(owner: &str, repo: &str, issue_number: u32) -> Result<String, String> {
+    let path = issue_lock_path(owner, repo, issue_number)?;
+    github_request("DELETE", &path, None)
+}
+
+fn issue_lock_path(owner: &str, repo: &str, issue_number: u32) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    validate_positive_number(issue_number, "issue_number")?;
+    Ok(format!(
+        "/repos/{}/{}/issues/{}/lock",
+        url_encode_path(owner),
+        url_encode_path(repo),
+        issue_number
+    ))
+}
+
 fn issue_name_list_request(
     method: &str,
     owner: &str,
@@ -406,20 +441,63 @@ fn validate_name_list(values: Option<&[String]>, field_name: &str) -> Result<(),
     Ok(())
 }
 
-pub(crate) fn get_issue(owner: &str, repo: &str, issue_number: u32) -> Result<String, String> {
+pub(crate) fn get_issue(
+    owner: &str,
+    repo: &str,
+    issue_number: u32,
+    include_comments: bool,
+) -> Result<String, String> {
     if !validate_path_segment(owner) || !validate_path_segment(repo) {
         return Err("Invalid owner or repo name".into());
     }
     let encoded_owner = url_encode_path(owner);
     let encoded_repo = url_encode_path(repo);
-    github_request(
+    let issue = github_request(
         "GET",
         &format!(
             "/repos/{}/{}/issues/{}",
             encoded_owner, encoded_repo, issue_number
         ),
         None,
-    )
+    )?;
+    if !include_comments {
+        return Ok(issue);
+    }
+    let issue: serde_json::Value =
+        serde_json::from_str(&issue).map_err(|_| "github_api_invalid_json".to_string())?;
+    let mut comments = Vec::new();
+    let mut page = 1_usize;
+    let mut exhausted = false;
+    while comments.len() < MAX_GET_ISSUE_COMMENTS {
+        let path = format!(
+            "/repos/{}/{}/issues/{}/comments?per_page={}&page={}",
+            encoded_owner, encoded_repo, issue_number, GET_ISSUE_COMMENTS_PAGE_SIZE, page
+        );
+        let response: serde_json::Value =
+            serde_json::from_str(&github_request("GET", &path, None)?)
+                .map_err(|_| "github_api_invalid_json".to_string())?;
+        let batch = response
+            .as_array()
+            .ok_or_else(|| "github_api_invalid_json".to_string())?;
+        comments.extend(batch.iter().cloned());
+        if batch.len() < GET_ISSUE_COMMENTS_PAGE_SIZE {
+            exhausted = true;
+            break;
+        }
+        page += 1;
+    }
+    let truncated = comments.len() > MAX_GET_ISSUE_COMMENTS
+        || (!exhausted
+            && issue["comments"]
+                .as_u64()
+                .is_none_or(|total| total > comments.len() as u64));
+    comments.truncate(MAX_GET_ISSUE_COMMENTS);
+    Ok(serde_json::json!({
+        "issue": issue,
+        "comments": comments,
+        "comments_truncated": truncated,
+    })
+    .to_string())
 }
 
 pub(crate) fn list_issue_comments(
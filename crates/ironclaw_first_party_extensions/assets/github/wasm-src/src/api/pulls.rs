@@ -384,6 +384,126 @@ pub(crate) fn get_pull_request_reviews(
     github_request("GET", &path, None)
 }
 
+/// Summarize whether a pull request is ready to merge in one call: the pull
+/// request's mergeability, each reviewer's current review state, and the
+/// check runs on its head commit.
+///
+/// A reviewer's state is their latest review, except that a plain
+/// `COMMENTED` review does not replace an earlier approval or change request
+/// (GitHub's own merge gating treats it the same way).
+pub(crate) fn get_pull_request_status(
+    owner: &str,
+    repo: &str,
+    pr_number: u32,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let base = format!("/repos/{encoded_owner}/{encoded_repo}");
+
+    let pull: serde_json::Value = serde_json::from_str(&github_request(
+        "GET",
+        &format!("{base}/pulls/{pr_number}"),
+        None,
+    )?)
+    .map_err(|_| "github_api_invalid_json".to_string())?;
+    let head_sha = pull["head"]["sha"]
+        .as_str()
+        .ok_or("github_api_invalid_json")?
+        .to_string();
+    validate_git_ref(&head_sha, "head_sha")?;
+
+    let reviews: serde_json::Value = serde_json::from_str(&github_request(
+        "GET",
+        &format!("{base}/pulls/{pr_number}/reviews?per_page=100"),
+        None,
+    )?)
+    .map_err(|_| "github_api_invalid_json".to_string())?;
+
+    let checks: serde_json::Value = serde_json::from_str(&github_request(
+        "GET",
+        &format!(
+            "{base}/commits/{}/check-runs?per_page=100",
+            url_encode_path(&head_sha)
+        ),
+        None,
+    )?)
+    .map_err(|_| "github_api_invalid_json".to_string())?;
+
+    Ok(serde_json::json!({
+        "number": pr_number,
+        "state": pull["state"],
+        "draft": pull["draft"],
+        "head_sha": head_sha,
+        "mergeable": pull["mergeable"],
+        "mergeable_state": pull["mergeable_state"],
+        "reviews": latest_reviews(&reviews),
+        "checks": check_run_summary(&checks),
+    })
+    .to_string())
+}
+
+/// One entry per reviewer, in order of their first review.
+fn latest_reviews(reviews: &serde_json::Value) -> Vec<serde_json::Value> {
+    let mut latest: Vec<serde_json::Value> = Vec::new();
+    for review in reviews.as_array().into_iter().flatten() {
+        let Some(login) = review["user"]["login"].as_str() else {
+            continue;
+        };
+        let state = review["state"].as_str().unwrap_or_default();
+        if state == "PENDING" {
+            continue;
+        }
+        let entry = serde_json::json!({
+            "reviewer": login,
+            "state": state,
+            "submitted_at": review["submitted_at"],
+        });
+        match latest
+            .iter_mut()
+            .find(|existing| existing["reviewer"] == login)
+        {
+            Some(existing) if state == "COMMENTED" && existing["state"] != "COMMENTED" => {}
+            Some(existing) => *existing = entry,
+            None => latest.push(entry),
+        }
+    }
+    latest
+}
+
+/// Check runs reduced to their outcome, with pass/fail/pending counts.
+fn check_run_summary(checks: &serde_json::Value) -> serde_json::Value {
+    let runs: Vec<serde_json::Value> = checks["check_runs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|run| {
+            serde_json::json!({
+                "name": run["name"],
+                "status": run["status"],
+                "conclusion": run["conclusion"],
+            })
+        })
+        .collect();
+    let (mut passed, mut failed, mut pending) = (0, 0, 0);
+    for run in &runs {
+        match run["conclusion"].as_str() {
+            None => pending += 1,
+            Some("success" | "neutral" | "skipped") => passed += 1,
+            Some(_) => failed += 1,
+        }
+    }
+    serde_json::json!({
+        "total_count": checks["total_count"].as_u64().unwrap_or(runs.len() as u64),
+        "passed": passed,
+        "failed": failed,
+        "pending": pending,
+        "runs": runs,
+    })
+}
+
 pub(crate) fn get_combined_status(owner: &str, repo: &str, r#ref: &str) -> Result<String, String> {
     if !validate_path_segment(owner) || !validate_path_segment(repo) {
         return Err("Invalid owner or repo name".into());
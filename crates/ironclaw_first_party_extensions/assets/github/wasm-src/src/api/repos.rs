@@ -15,6 +15,74 @@ pub(crate) fn get_repo(owner: &str, repo: &str) -> Result<String, String> {
     )
 }
 
+pub(crate) fn get_repo_languages(owner: &str, repo: &str) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    github_request(
+        "GET",
+        &format!("/repos/{encoded_owner}/{encoded_repo}/languages"),
+        None,
+    )
+}
+
+/// Summarize per-contributor commit, addition, and deletion totals.
+///
+/// GitHub computes these statistics lazily and answers 202 with an empty
+/// object until they are cached; that surfaces as `"status": "computing"` so
+/// the caller can retry instead of treating it as "no contributors". The
+/// weekly breakdown is dropped to keep long-lived repositories small.
+pub(crate) fn get_contributor_stats(owner: &str, repo: &str) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let response = github_request(
+        "GET",
+        &format!("/repos/{encoded_owner}/{encoded_repo}/stats/contributors"),
+        None,
+    )?;
+    let stats: serde_json::Value =
+        serde_json::from_str(&response).map_err(|_| "github_api_invalid_json".to_string())?;
+    let Some(stats) = stats.as_array() else {
+        return Ok(serde_json::json!({
+            "status": "computing",
+            "message": "GitHub is still computing contributor statistics; retry later.",
+        })
+        .to_string());
+    };
+    let mut contributors: Vec<serde_json::Value> = stats
+        .iter()
+        .map(|contributor| {
+            let weeks = contributor["weeks"]
+                .as_array()
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let sum = |key: &str| {
+                weeks
+                    .iter()
+                    .filter_map(|week| week[key].as_u64())
+                    .sum::<u64>()
+            };
+            serde_json::json!({
+                "login": contributor["author"]["login"],
+                "commits": contributor["total"],
+                "additions": sum("a"),
+                "deletions": sum("d"),
+            })
+        })
+        .collect();
+    contributors.sort_by_key(|contributor| std::cmp::Reverse(contributor["commits"].as_u64()));
+    Ok(serde_json::json!({
+        "status": "ok",
+        "contributors": contributors,
+    })
+    .to_string())
+}
+
 pub(crate) fn create_repo(
     name: &str,
     description: Option<&str>,
@@ -110,6 +178,115 @@ pub(crate) fn fork_repo(
     github_request("POST", &path, Some(req_body.to_string()))
 }
 
+/// Repository webhook events an agent may subscribe to; `*` means all.
+const GITHUB_WEBHOOK_EVENTS: &[&str] = &[
+    "*",
+    "branch_protection_rule",
+    "check_run",
+    "check_suite",
+    "commit_comment",
+    "create",
+    "delete",
+    "deployment",
+    "deployment_status",
+    "discussion",
+    "discussion_comment",
+    "fork",
+    "gollum",
+    "issue_comment",
+    "issues",
+    "label",
+    "member",
+    "merge_group",
+    "meta",
+    "milestone",
+    "package",
+    "page_build",
+    "public",
+    "pull_request",
+    "pull_request_review",
+    "pull_request_review_comment",
+    "pull_request_review_thread",
+    "push",
+    "registry_package",
+    "release",
+    "repository",
+    "repository_dispatch",
+    "security_advisory",
+    "star",
+    "status",
+    "watch",
+    "workflow_dispatch",
+    "workflow_job",
+    "workflow_run",
+];
+
+/// Register a repository webhook delivering JSON payloads to `url`.
+///
+/// Only the hook id and its non-secret settings are returned; GitHub masks
+/// the secret in its response, but it is never echoed back regardless.
+pub(crate) fn create_webhook(
+    owner: &str,
+    repo: &str,
+    url: &str,
+    events: &[String],
+    secret: Option<&str>,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    validate_input_length(url, "url")?;
+    let host = url
+        .strip_prefix("https://")
+        .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or_default())
+        .unwrap_or_default();
+    if host.is_empty() || url.chars().any(|c| c.is_control() || c.is_whitespace()) {
+        return Err("Invalid url: webhook url must be an absolute https URL".into());
+    }
+    if events.is_empty() {
+        return Err("Invalid events: at least one event is required".into());
+    }
+    if let Some(event) = events
+        .iter()
+        .find(|event| !GITHUB_WEBHOOK_EVENTS.contains(&event.as_str()))
+    {
+        return Err(format!("Invalid events: unknown webhook event '{event}'"));
+    }
+    if let Some(secret) = secret {
+        validate_input_length(secret, "secret")?;
+    }
+
+    let mut config = serde_json::json!({
+        "url": url,
+        "content_type": "json",
+    });
+    if let Some(secret) = secret {
+        config["secret"] = serde_json::json!(secret);
+    }
+    let req_body = serde_json::json!({
+        "name": "web",
+        "active": true,
+        "events": events,
+        "config": config,
+    });
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let response = github_request(
+        "POST",
+        &format!("/repos/{encoded_owner}/{encoded_repo}/hooks"),
+        Some(req_body.to_string()),
+    )?;
+    let hook: serde_json::Value =
+        serde_json::from_str(&response).map_err(|_| "github_api_invalid_json".to_string())?;
+    Ok(serde_json::json!({
+        "id": hook["id"],
+        "active": hook["active"],
+        "events": hook["events"],
+        "url": hook["config"]["url"],
+    })
+    .to_string())
+}
+
 pub(crate) fn get_authenticated_user() -> Result<String, String> {
     github_request("GET", "/user", None)
 }
@@ -199,6 +376,67 @@ pub(crate) fn create_branch(
     github_request("POST", &path, Some(req_body.to_string()))
 }
 
+pub(crate) fn list_commits(
+    owner: &str,
+    repo: &str,
+    sha: Option<&str>,
+    path: Option<&str>,
+    page: Option<u32>,
+    limit: Option<u32>,
+) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    if let Some(sha) = sha {
+        validate_git_ref(sha, "sha")?;
+    }
+    if let Some(path) = path {
+        validate_repo_path(path)?;
+    }
+    validate_page(page)?;
+    validate_limit(limit)?;
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    let limit = limit.unwrap_or(30).min(100);
+    let mut url_path = format!(
+        "/repos/{}/{}/commits?per_page={}",
+        encoded_owner, encoded_repo, limit
+    );
+    if let Some(sha) = sha {
+        url_path.push_str(&format!("&sha={}", url_encode_query(sha)));
+    }
+    if let Some(path) = path {
+        url_path.push_str(&format!("&path={}", url_encode_query(path)));
+    }
+    if let Some(page) = page {
+        url_path.push_str(&format!("&page={page}"));
+    }
+    github_request("GET", &url_path, None)
+}
+
+/// Fetch a single commit, including its changed `files` and `stats`.
+///
+/// `ref` may be a SHA, branch, or tag; branch names keep their `/`
+/// separators so `feature/x` resolves the same way it does on GitHub.
+pub(crate) fn get_commit(owner: &str, repo: &str, r#ref: &str) -> Result<String, String> {
+    if !validate_path_segment(owner) || !validate_path_segment(repo) {
+        return Err("Invalid owner or repo name".into());
+    }
+    validate_git_ref(r#ref, "ref")?;
+    let encoded_owner = url_encode_path(owner);
+    let encoded_repo = url_encode_path(repo);
+    github_request(
+        "GET",
+        &format!(
+            "/repos/{}/{}/commits/{}",
+            encoded_owner,
+            encoded_repo,
+            encode_repo_path(r#ref)
+        ),
+        None,
+    )
+}
+
 pub(crate) fn list_releases(
     owner: &str,
     repo: &str,
@@ -11,6 +11,8 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
 
     match action {
         GitHubAction::GetRepo { owner, repo } => get_repo(&owner, &repo),
+        GitHubAction::GetRepoLanguages { owner, repo } => get_repo_languages(&owner, &repo),
+        GitHubAction::GetContributorStats { owner, repo } => get_contributor_stats(&owner, &repo),
         GitHubAction::CreateRepo {
             name,
             description,
@@ -109,11 +111,28 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             issue_number,
             assignees,
         } => remove_issue_assignees(&owner, &repo, issue_number, assignees),
+        GitHubAction::LockIssue {
+            owner,
+            repo,
+            issue_number,
+            lock_reason,
+        } => lock_issue(&owner, &repo, issue_number, lock_reason),
+        GitHubAction::UnlockIssue {
+            owner,
+            repo,
+            issue_number,
+        } => unlock_issue(&owner, &repo, issue_number),
         GitHubAction::GetIssue {
             owner,
             repo,
             issue_number,
-        } => get_issue(&owner, &repo, issue_number),
+            include_comments,
+        } => get_issue(
+            &owner,
+            &repo,
+            issue_number,
+            include_comments.unwrap_or(false),
+        ),
         GitHubAction::ListIssueComments {
             owner,
             repo,
@@ -261,6 +280,11 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
         } => list_pull_request_review_threads(&owner, &repo, pr_number, first, after.as_deref()),
         GitHubAction::ResolveReviewThread { thread_id } => resolve_review_thread(&thread_id),
         GitHubAction::UnresolveReviewThread { thread_id } => unresolve_review_thread(&thread_id),
+        GitHubAction::GetPullRequestStatus {
+            owner,
+            repo,
+            pr_number,
+        } => get_pull_request_status(&owner, &repo, pr_number),
         GitHubAction::GetCombinedStatus { owner, repo, r#ref } => {
             get_combined_status(&owner, &repo, &r#ref)
         }
@@ -343,12 +367,27 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             branch,
             from_ref,
         } => create_branch(&owner, &repo, &branch, &from_ref),
+        GitHubAction::ListCommits {
+            owner,
+            repo,
+            sha,
+            path,
+            page,
+            limit,
+        } => list_commits(&owner, &repo, sha.as_deref(), path.as_deref(), page, limit),
+        GitHubAction::GetCommit { owner, repo, r#ref } => get_commit(&owner, &repo, &r#ref),
         GitHubAction::GetFileContent {
             owner,
             repo,
             path,
             r#ref,
         } => get_file_content(&owner, &repo, &path, r#ref.as_deref()),
+        GitHubAction::ListTree {
+            owner,
+            repo,
+            r#ref,
+            recursive,
+        } => list_tree(&owner, &repo, r#ref.as_deref(), recursive.unwrap_or(false)),
         GitHubAction::CreateOrUpdateFile {
             owner,
             repo,
@@ -423,6 +462,12 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             r#ref,
             inputs,
         } => trigger_workflow(&owner, &repo, &workflow_id, &r#ref, inputs),
+        GitHubAction::RepositoryDispatch {
+            owner,
+            repo,
+            event_type,
+            client_payload,
+        } => repository_dispatch(&owner, &repo, &event_type, client_payload),
         GitHubAction::GetWorkflowRuns {
             owner,
             repo,
@@ -465,6 +510,11 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             repo,
             job_id,
         } => get_job_logs(&owner, &repo, job_id),
+        GitHubAction::GetWorkflowRunLogs {
+            owner,
+            repo,
+            run_id,
+        } => get_workflow_run_logs(&owner, &repo, run_id),
         GitHubAction::GetWorkflowRunArtifacts {
             owner,
             repo,
@@ -495,6 +545,25 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             enable_debug_logging,
             enable_debugger,
         } => rerun_workflow_job(&owner, &repo, job_id, enable_debug_logging, enable_debugger),
+        GitHubAction::CancelWorkflowRun {
+            owner,
+            repo,
+            run_id,
+        } => cancel_workflow_run(&owner, &repo, run_id),
+        GitHubAction::RerunWorkflow {
+            owner,
+            repo,
+            run_id,
+            failed_only,
+            enable_debug_logging,
+        } => rerun_workflow(
+            &owner,
+            &repo,
+            run_id,
+            failed_only.unwrap_or(false),
+            enable_debug_logging,
+        ),
+        GitHubAction::Graphql { query, variables } => graphql(&query, variables),
         GitHubAction::ForkRepo {
             owner,
             repo,
@@ -508,6 +577,13 @@ pub(crate) fn execute_inner(params: &str, context: Option<&str>) -> Result<Strin
             name.as_deref(),
             default_branch_only,
         ),
+        GitHubAction::CreateWebhook {
+            owner,
+            repo,
+            url,
+            events,
+            secret,
+        } => create_webhook(&owner, &repo, &url, &events, secret.as_deref()),
         GitHubAction::HandleWebhook { webhook } => handle_webhook(webhook),
     }
 }
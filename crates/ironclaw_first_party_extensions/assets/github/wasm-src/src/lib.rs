@@ -77,6 +77,10 @@ fn guest_error_kind(code: &str) -> &'static str {
         | "invalid_label"
         | "invalid_comments"
         | "invalid_thread_id"
+        | "invalid_event_type"
+        | "invalid_client_payload"
+        | "invalid_variables"
+        | "invalid_variables_too_large"
         | "Invalid owner or repo name"
         | "Invalid repository name"
         | "Invalid org name"
@@ -92,7 +96,9 @@ fn guest_error_kind(code: &str) -> &'static str {
         "github_api_egress_denied" | "github_api_redirect_denied" => "network_denied",
         "github_api_error_status_401" => "auth_required",
         "github_api_error_status_422_validation" => "input",
-        "github_api_error_status_403" | "github_api_error_status_429" => "client",
+        "github_api_error_status_403"
+        | "github_api_error_status_429"
+        | "github_workflow_run_not_cancellable" => "client",
         _ => "operation_failed",
     }
 }
@@ -101,6 +107,8 @@ fn is_string_validation_input_error(code: &str) -> bool {
     code.starts_with("Invalid labels:")
         || code.starts_with("Invalid assignees:")
         || code.starts_with("invalid_comments:")
+        || code.starts_with("Invalid url:")
+        || code.starts_with("Invalid events:")
 }
 
 export!(GitHubTool);
@@ -737,6 +745,47 @@ mod tests {
         assert_eq!(body, json!({"milestone": null}));
     }
 
+    #[test]
+    fn lock_and_unlock_issue_use_the_lock_endpoint() {
+        test_support::set_response(Ok(json!({"status": 204}).to_string()));
+        execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","issue_number":42,"lock_reason":"too heated"}"#,
+            Some(r#"{"capability_id":"github.lock_issue"}"#),
+        )
+        .expect("lock issue should dispatch");
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[0].path, "/repos/nearai/ironclaw/issues/42/lock");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, json!({"lock_reason": "too heated"}));
+
+        test_support::set_response(Ok(json!({"status": 204}).to_string()));
+        execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","issue_number":42}"#,
+            Some(r#"{"capability_id":"github.unlock_issue"}"#),
+        )
+        .expect("unlock issue should dispatch");
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(requests[0].path, "/repos/nearai/ironclaw/issues/42/lock");
+        assert!(requests[0].body.is_none());
+    }
+
+    #[test]
+    fn lock_issue_rejects_unknown_lock_reason_before_egress() {
+        test_support::set_response(Ok(json!({}).to_string()));
+        assert_eq!(
+            execute_inner(
+                r#"{"owner":"nearai","repo":"ironclaw","issue_number":42,"lock_reason":"boring"}"#,
+                Some(r#"{"capability_id":"github.lock_issue"}"#),
+            )
+            .unwrap_err(),
+            "invalid_parameters"
+        );
+        assert!(test_support::requests().is_empty());
+    }
+
     #[test]
     fn add_issue_assignees_rejects_more_than_ten_assignees_before_egress() {
         let assignees = (0..11)
@@ -1178,6 +1227,301 @@ mod tests {
         assert_eq!(body, json!({}));
     }
 
+    #[test]
+    fn graphql_passthrough_returns_data_and_errors_without_failing() {
+        test_support::set_response(Ok(json!({
+            "data": {"viewer": {"login": "octocat"}, "node": null},
+            "errors": [{"message": "Could not resolve to a node", "path": ["node"]}]
+        })
+        .to_string()));
+        let output = execute_inner(
+            r#"{"query":"query($id: ID!) { viewer { login } node(id: $id) { id } }","variables":{"id":"missing"}}"#,
+            Some(r#"{"capability_id":"github.graphql"}"#),
+        )
+        .expect("graphql errors should be returned, not raised");
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/graphql");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["variables"], json!({"id": "missing"}));
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["data"]["viewer"]["login"], json!("octocat"));
+        assert_eq!(output["errors"][0]["path"], json!(["node"]));
+
+        test_support::set_response(Ok(
+            json!({"data": {"viewer": {"login": "octocat"}}}).to_string()
+        ));
+        execute_inner(
+            r#"{"query":"{ viewer { login } }"}"#,
+            Some(r#"{"capability_id":"github.graphql"}"#),
+        )
+        .expect("variables default to an empty object");
+        let body: serde_json::Value =
+            serde_json::from_str(test_support::requests()[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["variables"], json!({}));
+
+        let oversized =
+            json!({"query": "{ viewer { login } }", "variables": {"blob": "x".repeat(17 * 1024)}});
+        for (input, expected_error) in [
+            (json!({"query": "  "}), "invalid_query_empty"),
+            (
+                json!({"query": "{ viewer { login } }", "variables": [1]}),
+                "invalid_variables",
+            ),
+            (oversized, "invalid_variables_too_large"),
+        ] {
+            test_support::set_responses([]);
+            let error = execute_inner(
+                &input.to_string(),
+                Some(r#"{"capability_id":"github.graphql"}"#),
+            )
+            .expect_err("invalid graphql input should fail before egress");
+            assert_eq!(error, expected_error);
+            assert_eq!(guest_error_kind(&error), "input");
+            assert!(test_support::requests().is_empty());
+        }
+    }
+
+    #[test]
+    fn get_repo_languages_passes_through_byte_counts() {
+        let languages = json!({"Rust": 120_000, "Python": 4_000});
+        test_support::set_response(Ok(languages.to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw"}"#,
+            Some(r#"{"capability_id":"github.get_repo_languages"}"#),
+        )
+        .expect("get_repo_languages should dispatch");
+        assert_eq!(
+            test_support::requests()[0].path,
+            "/repos/nearai/ironclaw/languages"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&output).unwrap(),
+            languages
+        );
+    }
+
+    #[test]
+    fn get_contributor_stats_summarizes_weeks_and_reports_computing() {
+        test_support::set_response(Ok(json!([
+            {"author": {"login": "occasional"}, "total": 2, "weeks": [
+                {"w": 1, "a": 5, "d": 1, "c": 2}
+            ]},
+            {"author": {"login": "maintainer"}, "total": 40, "weeks": [
+                {"w": 1, "a": 100, "d": 20, "c": 30},
+                {"w": 2, "a": 50, "d": 10, "c": 10}
+            ]}
+        ])
+        .to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw"}"#,
+            Some(r#"{"capability_id":"github.get_contributor_stats"}"#),
+        )
+        .expect("get_contributor_stats should dispatch");
+        assert_eq!(
+            test_support::requests()[0].path,
+            "/repos/nearai/ironclaw/stats/contributors"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&output).unwrap(),
+            json!({"status": "ok", "contributors": [
+                {"login": "maintainer", "commits": 40, "additions": 150, "deletions": 30},
+                {"login": "occasional", "commits": 2, "additions": 5, "deletions": 1}
+            ]})
+        );
+
+        // GitHub answers 202 with an empty object while statistics are cached.
+        test_support::set_response(Ok(json!({}).to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw"}"#,
+            Some(r#"{"capability_id":"github.get_contributor_stats"}"#),
+        )
+        .expect("computing contributor stats should not fail");
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["status"], json!("computing"));
+    }
+
+    #[test]
+    fn create_webhook_posts_json_hook_and_returns_its_id() {
+        test_support::set_response(Ok(json!({
+            "id": 12345,
+            "name": "web",
+            "active": true,
+            "events": ["push", "pull_request"],
+            "config": {
+                "url": "https://ironclaw.example/webhooks/github",
+                "content_type": "json",
+                "secret": "********"
+            }
+        })
+        .to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","url":"https://ironclaw.example/webhooks/github","events":["push","pull_request"],"secret":"s3cret"}"#,
+            Some(r#"{"capability_id":"github.create_webhook"}"#),
+        )
+        .expect("create_webhook should dispatch");
+
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/repos/nearai/ironclaw/hooks");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "name": "web",
+                "active": true,
+                "events": ["push", "pull_request"],
+                "config": {
+                    "url": "https://ironclaw.example/webhooks/github",
+                    "content_type": "json",
+                    "secret": "s3cret"
+                }
+            })
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["id"], json!(12345));
+        assert_eq!(output["events"], json!(["push", "pull_request"]));
+        assert!(!output.to_string().contains("secret"));
+
+        for (input, expected_prefix) in [
+            (
+                json!({"url": "http://ironclaw.example/hook", "events": ["push"]}),
+                "Invalid url:",
+            ),
+            (
+                json!({"url": "https:///hook", "events": ["push"]}),
+                "Invalid url:",
+            ),
+            (
+                json!({"url": "https://ironclaw.example/hook", "events": []}),
+                "Invalid events:",
+            ),
+            (
+                json!({"url": "https://ironclaw.example/hook", "events": ["pushes"]}),
+                "Invalid events:",
+            ),
+        ] {
+            test_support::set_responses([]);
+            let mut params = json!({"owner": "nearai", "repo": "ironclaw"});
+            params
+                .as_object_mut()
+                .unwrap()
+                .extend(input.as_object().unwrap().clone());
+            let error = execute_inner(
+                &params.to_string(),
+                Some(r#"{"capability_id":"github.create_webhook"}"#),
+            )
+            .expect_err("invalid webhook input should fail");
+            assert!(error.starts_with(expected_prefix), "{error}");
+            assert_eq!(guest_error_kind(&error), "input");
+            assert!(test_support::requests().is_empty());
+        }
+    }
+
+    #[test]
+    fn repository_dispatch_posts_event_and_reports_empty_response() {
+        test_support::set_response(Ok(json!({"status": 204}).to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","event_type":"deploy","client_payload":{"env":"staging"}}"#,
+            Some(r#"{"capability_id":"github.repository_dispatch"}"#),
+        )
+        .expect("repository dispatch should dispatch");
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/repos/nearai/ironclaw/dispatches");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({"event_type": "deploy", "client_payload": {"env": "staging"}})
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["status"], json!(204));
+        assert_eq!(output["event_type"], json!("deploy"));
+        assert!(output["message"]
+            .as_str()
+            .unwrap()
+            .contains("nearai/ironclaw"));
+
+        let long_event_type = "e".repeat(101);
+        let too_many_keys: serde_json::Map<String, serde_json::Value> =
+            (0..11).map(|i| (format!("k{i}"), json!(i))).collect();
+        for (input, expected_error) in [
+            (json!({"event_type": " "}), "invalid_event_type"),
+            (json!({"event_type": long_event_type}), "invalid_event_type"),
+            (
+                json!({"event_type": "deploy", "client_payload": ["staging"]}),
+                "invalid_client_payload",
+            ),
+            (
+                json!({"event_type": "deploy", "client_payload": too_many_keys}),
+                "invalid_client_payload",
+            ),
+        ] {
+            test_support::set_responses([]);
+            let mut params = json!({"owner": "nearai", "repo": "ironclaw"});
+            params
+                .as_object_mut()
+                .unwrap()
+                .extend(input.as_object().unwrap().clone());
+            let error = execute_inner(
+                &params.to_string(),
+                Some(r#"{"capability_id":"github.repository_dispatch"}"#),
+            )
+            .expect_err("invalid repository dispatch input should fail");
+            assert_eq!(error, expected_error);
+            assert_eq!(guest_error_kind(&error), "input");
+            assert!(test_support::requests().is_empty());
+        }
+    }
+
+    #[test]
+    fn rerun_workflow_and_cancel_workflow_run_use_run_endpoints() {
+        for (input, expected_path) in [
+            (
+                r#"{"owner":"nearai","repo":"ironclaw","run_id":123}"#,
+                "/repos/nearai/ironclaw/actions/runs/123/rerun",
+            ),
+            (
+                r#"{"owner":"nearai","repo":"ironclaw","run_id":123,"failed_only":true}"#,
+                "/repos/nearai/ironclaw/actions/runs/123/rerun-failed-jobs",
+            ),
+        ] {
+            test_support::set_response(Ok(json!({"status": 201}).to_string()));
+            execute_inner(input, Some(r#"{"capability_id":"github.rerun_workflow"}"#))
+                .expect("rerun workflow should dispatch");
+            let requests = test_support::requests();
+            assert_eq!(requests[0].method, "POST");
+            assert_eq!(requests[0].path, expected_path);
+        }
+
+        test_support::set_response(Ok(json!({}).to_string()));
+        execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","run_id":123}"#,
+            Some(r#"{"capability_id":"github.cancel_workflow_run"}"#),
+        )
+        .expect("cancel workflow run should dispatch");
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            requests[0].path,
+            "/repos/nearai/ironclaw/actions/runs/123/cancel"
+        );
+
+        for status in ["github_api_error_status_403", "github_api_error_status_409"] {
+            test_support::set_response(Err(status.to_string()));
+            let error = execute_inner(
+                r#"{"owner":"nearai","repo":"ironclaw","run_id":123}"#,
+                Some(r#"{"capability_id":"github.cancel_workflow_run"}"#),
+            )
+            .expect_err("completed runs cannot be cancelled");
+            assert_eq!(error, "github_workflow_run_not_cancellable");
+            assert_eq!(guest_error_kind(&error), "client");
+        }
+    }
+
     #[test]
     fn get_job_logs_json_encodes_plain_text_body() {
         // GitHub's job-logs endpoint returns raw plain-text log content, not
@@ -1209,6 +1553,126 @@ mod tests {
         assert_eq!(decoded, serde_json::Value::String(raw_log.to_string()));
     }
 
+    #[test]
+    fn get_pull_request_status_aggregates_reviews_and_checks() {
+        test_support::set_responses([
+            Ok(json!({
+                "state": "open",
+                "draft": false,
+                "mergeable": true,
+                "mergeable_state": "blocked",
+                "head": {"sha": "abc123"}
+            })
+            .to_string()),
+            Ok(json!([
+                {"user": {"login": "alice"}, "state": "CHANGES_REQUESTED", "submitted_at": "t1"},
+                {"user": {"login": "bob"}, "state": "APPROVED", "submitted_at": "t2"},
+                {"user": {"login": "alice"}, "state": "APPROVED", "submitted_at": "t3"},
+                {"user": {"login": "bob"}, "state": "COMMENTED", "submitted_at": "t4"},
+                {"user": {"login": "carol"}, "state": "COMMENTED", "submitted_at": "t5"}
+            ])
+            .to_string()),
+            Ok(json!({"total_count": 3, "check_runs": [
+                {"name": "test", "status": "completed", "conclusion": "success"},
+                {"name": "clippy", "status": "completed", "conclusion": "failure"},
+                {"name": "e2e", "status": "in_progress", "conclusion": null}
+            ]})
+            .to_string()),
+        ]);
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","pr_number":7}"#,
+            Some(r#"{"capability_id":"github.get_pull_request_status"}"#),
+        )
+        .expect("get_pull_request_status should dispatch");
+
+        let paths: Vec<String> = test_support::requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/repos/nearai/ironclaw/pulls/7",
+                "/repos/nearai/ironclaw/pulls/7/reviews?per_page=100",
+                "/repos/nearai/ironclaw/commits/abc123/check-runs?per_page=100",
+            ]
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["mergeable"], json!(true));
+        assert_eq!(output["head_sha"], json!("abc123"));
+        let reviews: Vec<(&str, &str)> = output["reviews"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|review| {
+                (
+                    review["reviewer"].as_str().unwrap(),
+                    review["state"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            reviews,
+            vec![
+                ("alice", "APPROVED"),
+                ("bob", "APPROVED"),
+                ("carol", "COMMENTED")
+            ]
+        );
+        assert_eq!(output["checks"]["passed"], json!(1));
+        assert_eq!(output["checks"]["failed"], json!(1));
+        assert_eq!(output["checks"]["pending"], json!(1));
+        assert_eq!(output["checks"]["runs"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn get_workflow_run_logs_returns_failed_job_log_tails() {
+        let long_log = format!("{}\nerror[E0308]: mismatched types", "x".repeat(20_000));
+        test_support::set_responses([
+            Ok(json!({"jobs": [
+                {"id": 1, "name": "fmt", "conclusion": "success", "steps": []},
+                {"id": 2, "name": "test", "conclusion": "failure", "steps": [
+                    {"name": "Checkout", "conclusion": "success"},
+                    {"name": "cargo test", "conclusion": "failure"}
+                ]},
+                {"id": 3, "name": "clippy", "conclusion": "timed_out", "steps": []}
+            ]})
+            .to_string()),
+            Ok(long_log),
+            Err("github_api_error_status_410".to_string()),
+        ]);
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","run_id":123}"#,
+            Some(r#"{"capability_id":"github.get_workflow_run_logs"}"#),
+        )
+        .expect("get_workflow_run_logs should dispatch");
+
+        let paths: Vec<String> = test_support::requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/repos/nearai/ironclaw/actions/runs/123/jobs?per_page=100&filter=latest",
+                "/repos/nearai/ironclaw/actions/jobs/2/logs",
+                "/repos/nearai/ironclaw/actions/jobs/3/logs",
+            ]
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["omitted_failed_jobs"], json!(0));
+        let jobs = output["failed_jobs"].as_array().unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0]["name"], json!("test"));
+        assert_eq!(jobs[0]["failed_steps"], json!(["cargo test"]));
+        assert_eq!(jobs[0]["log_truncated"], json!(true));
+        let log = jobs[0]["log"].as_str().unwrap();
+        assert!(log.starts_with("[... "), "{log:.40}");
+        assert!(log.ends_with("error[E0308]: mismatched types"));
+        assert!(log.len() < 17 * 1024);
+        assert_eq!(jobs[1]["log_error"], json!("github_api_error_status_410"));
+    }
+
     #[test]
     fn workflow_run_jobs_and_artifacts_reject_invalid_page_or_limit_before_egress() {
         for (capability, input, expected_error) in [
@@ -1525,6 +1989,185 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_tree_defaults_to_default_branch_and_surfaces_truncation() {
+        test_support::set_responses([
+            Ok(json!({"default_branch": "trunk"}).to_string()),
+            Ok(json!({
+                "sha": "tree-sha",
+                "truncated": true,
+                "tree": [
+                    {"path": "src", "mode": "040000", "type": "tree", "sha": "dir-sha", "url": "u"},
+                    {"path": "src/lib.rs", "mode": "100644", "type": "blob", "sha": "blob-sha", "size": 42, "url": "u"}
+                ]
+            })
+            .to_string()),
+        ]);
+
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","recursive":true}"#,
+            Some(r#"{"capability_id":"github.list_tree"}"#),
+        )
+        .expect("github.list_tree should list the default branch");
+
+        let paths: Vec<_> = test_support::requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/repos/nearai/ironclaw",
+                "/repos/nearai/ironclaw/git/trees/trunk?recursive=1",
+            ]
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["ref"], "trunk");
+        assert_eq!(output["truncated"], true);
+        assert_eq!(
+            output["entries"][1],
+            json!({"path": "src/lib.rs", "type": "blob", "sha": "blob-sha", "size": 42})
+        );
+        assert!(output["entries"][0].get("size").is_none());
+    }
+
+    #[test]
+    fn list_tree_uses_ref_and_rejects_invalid_refs() {
+        test_support::set_response(Ok(json!({"sha": "t", "tree": []}).to_string()));
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","ref":"feature/x"}"#,
+            Some(r#"{"capability_id":"github.list_tree"}"#),
+        )
+        .expect("github.list_tree should list the ref");
+        assert_eq!(
+            test_support::requests()[0].path,
+            "/repos/nearai/ironclaw/git/trees/feature/x"
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["truncated"], false);
+
+        test_support::set_response(Ok("{}".to_string()));
+        let err = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","ref":"main..evil"}"#,
+            Some(r#"{"capability_id":"github.list_tree"}"#),
+        )
+        .expect_err("invalid ref should be rejected");
+        assert!(err.contains("Invalid ref"), "{err}");
+        assert!(test_support::requests().is_empty());
+    }
+
+    #[test]
+    fn get_issue_with_comments_combines_issue_and_paged_comments() {
+        let full_page: Vec<_> = (0..100).map(|id| json!({"id": id})).collect();
+        let second_page: Vec<_> = (100..130).map(|id| json!({"id": id})).collect();
+        test_support::set_responses([
+            Ok(json!({"number": 7, "title": "Crash", "comments": 130}).to_string()),
+            Ok(json!(full_page).to_string()),
+            Ok(json!(second_page).to_string()),
+        ]);
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","issue_number":7,"include_comments":true}"#,
+            Some(r#"{"capability_id":"github.get_issue"}"#),
+        )
+        .expect("get_issue with comments should dispatch");
+
+        let paths: Vec<_> = test_support::requests()
+            .into_iter()
+            .map(|request| request.path)
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/repos/nearai/ironclaw/issues/7",
+                "/repos/nearai/ironclaw/issues/7/comments?per_page=100&page=1",
+                "/repos/nearai/ironclaw/issues/7/comments?per_page=100&page=2",
+            ]
+        );
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["issue"]["title"], "Crash");
+        assert_eq!(output["comments"].as_array().map(Vec::len), Some(130));
+        assert_eq!(output["comments_truncated"], false);
+    }
+
+    #[test]
+    fn get_issue_with_comments_caps_long_threads() {
+        let page = |start: usize| {
+            Ok(json!((start..start + 100)
+                .map(|id| json!({"id": id}))
+                .collect::<Vec<_>>())
+            .to_string())
+        };
+        test_support::set_responses([
+            Ok(json!({"number": 7, "comments": 450}).to_string()),
+            page(0),
+            page(100),
+        ]);
+        let output = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","issue_number":7,"include_comments":true}"#,
+            Some(r#"{"capability_id":"github.get_issue"}"#),
+        )
+        .expect("capped get_issue should dispatch");
+
+        assert_eq!(test_support::requests().len(), 3, "stops at the cap");
+        let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(output["comments"].as_array().map(Vec::len), Some(200));
+        assert_eq!(output["comments_truncated"], true);
+    }
+
+    #[test]
+    fn list_commits_filters_by_ref_and_path() {
+        test_support::set_response(Ok(json!([]).to_string()));
+
+        execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","sha":"feature/x","path":"src/lib.rs","page":2,"limit":10}"#,
+            Some(r#"{"capability_id":"github.list_commits"}"#),
+        )
+        .expect("github.list_commits should dispatch");
+
+        let requests = test_support::requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            "/repos/nearai/ironclaw/commits?per_page=10&sha=feature%2Fx&path=src%2Flib.rs&page=2"
+        );
+    }
+
+    #[test]
+    fn get_commit_fetches_ref_and_rejects_injection() {
+        test_support::set_response(Ok(json!({
+            "sha": "abc123",
+            "stats": {"additions": 1, "deletions": 0, "total": 1},
+            "files": [{"filename": "src/lib.rs"}]
+        })
+        .to_string()));
+
+        execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","ref":"feature/x"}"#,
+            Some(r#"{"capability_id":"github.get_commit"}"#),
+        )
+        .expect("github.get_commit should dispatch");
+        assert_eq!(
+            test_support::requests()[0].path,
+            "/repos/nearai/ironclaw/commits/feature/x"
+        );
+
+        for bad_ref in ["../../user", "main:secrets", "/main"] {
+            let params = json!({"owner": "nearai", "repo": "ironclaw", "ref": bad_ref});
+            let err = execute_inner(
+                &params.to_string(),
+                Some(r#"{"capability_id":"github.get_commit"}"#),
+            )
+            .unwrap_err();
+            assert!(err.contains("Invalid ref"), "{bad_ref}: {err}");
+        }
+        let err = execute_inner(
+            r#"{"owner":"nearai","repo":"ironclaw","sha":"main:secrets"}"#,
+            Some(r#"{"capability_id":"github.list_commits"}"#),
+        )
+        .unwrap_err();
+        assert!(err.contains("Invalid sha"));
+    }
+
     #[test]
     fn search_issues_pull_requests_accepts_wider_sort() {
         test_support::set_response(Ok(json!({"items": []}).to_string()));
@@ -46,6 +46,8 @@ fn schema_action_name(schema: &str) -> Option<String> {
 
 const GITHUB_SCHEMAS: &[&str] = &[
     include_str!("../../schemas/github/get_repo.input.v1.json"),
+    include_str!("../../schemas/github/get_repo_languages.input.v1.json"),
+    include_str!("../../schemas/github/get_contributor_stats.input.v1.json"),
     include_str!("../../schemas/github/create_repo.input.v1.json"),
     include_str!("../../schemas/github/list_issues.input.v1.json"),
     include_str!("../../schemas/github/create_issue.input.v1.json"),
@@ -54,6 +56,8 @@ const GITHUB_SCHEMAS: &[&str] = &[
     include_str!("../../schemas/github/remove_issue_label.input.v1.json"),
     include_str!("../../schemas/github/add_issue_assignees.input.v1.json"),
     include_str!("../../schemas/github/remove_issue_assignees.input.v1.json"),
+    include_str!("../../schemas/github/lock_issue.input.v1.json"),
+    include_str!("../../schemas/github/unlock_issue.input.v1.json"),
     include_str!("../../schemas/github/get_issue.input.v1.json"),
     include_str!("../../schemas/github/list_issue_comments.input.v1.json"),
     include_str!("../../schemas/github/create_issue_comment.input.v1.json"),
@@ -70,6 +74,7 @@ const GITHUB_SCHEMAS: &[&str] = &[
     include_str!("../../schemas/github/list_pull_request_review_threads.input.v1.json"),
     include_str!("../../schemas/github/resolve_review_thread.input.v1.json"),
     include_str!("../../schemas/github/unresolve_review_thread.input.v1.json"),
+    include_str!("../../schemas/github/get_pull_request_status.input.v1.json"),
     include_str!("../../schemas/github/get_combined_status.input.v1.json"),
     include_str!("../../schemas/github/merge_pull_request.input.v1.json"),
     include_str!("../../schemas/github/get_authenticated_user.input.v1.json"),
@@ -80,18 +85,27 @@ const GITHUB_SCHEMAS: &[&str] = &[
     include_str!("../../schemas/github/search_issues_pull_requests.input.v1.json"),
     include_str!("../../schemas/github/list_branches.input.v1.json"),
     include_str!("../../schemas/github/create_branch.input.v1.json"),
+    include_str!("../../schemas/github/list_commits.input.v1.json"),
+    include_str!("../../schemas/github/get_commit.input.v1.json"),
     include_str!("../../schemas/github/get_file_content.input.v1.json"),
+    include_str!("../../schemas/github/list_tree.input.v1.json"),
     include_str!("../../schemas/github/create_or_update_file.input.v1.json"),
     include_str!("../../schemas/github/delete_file.input.v1.json"),
     include_str!("../../schemas/github/list_releases.input.v1.json"),
     include_str!("../../schemas/github/create_release.input.v1.json"),
     include_str!("../../schemas/github/trigger_workflow.input.v1.json"),
+    include_str!("../../schemas/github/repository_dispatch.input.v1.json"),
     include_str!("../../schemas/github/get_workflow_runs.input.v1.json"),
     include_str!("../../schemas/github/get_workflow_run_jobs.input.v1.json"),
     include_str!("../../schemas/github/get_job_logs.input.v1.json"),
+    include_str!("../../schemas/github/get_workflow_run_logs.input.v1.json"),
     include_str!("../../schemas/github/get_workflow_run_artifacts.input.v1.json"),
     include_str!("../../schemas/github/rerun_failed_workflow_run_jobs.input.v1.json"),
     include_str!("../../schemas/github/rerun_workflow_job.input.v1.json"),
+    include_str!("../../schemas/github/cancel_workflow_run.input.v1.json"),
+    include_str!("../../schemas/github/rerun_workflow.input.v1.json"),
     include_str!("../../schemas/github/fork_repo.input.v1.json"),
+    include_str!("../../schemas/github/create_webhook.input.v1.json"),
+    include_str!("../../schemas/github/graphql.input.v1.json"),
     include_str!("../../schemas/github/handle_webhook.input.v1.json"),
 ];
@@ -27,6 +27,10 @@ pub(crate) struct GitCommitIdentity {
 pub(crate) enum GitHubAction {
     #[serde(rename = "get_repo")]
     GetRepo { owner: String, repo: String },
+    #[serde(rename = "get_repo_languages")]
+    GetRepoLanguages { owner: String, repo: String },
+    #[serde(rename = "get_contributor_stats")]
+    GetContributorStats { owner: String, repo: String },
     #[serde(rename = "create_repo")]
     CreateRepo {
         name: String,
@@ -100,11 +104,25 @@ pub(crate) enum GitHubAction {
         issue_number: u32,
         assignees: Vec<String>,
     },
+    #[serde(rename = "lock_issue")]
+    LockIssue {
+        owner: String,
+        repo: String,
+        issue_number: u32,
+        lock_reason: Option<IssueLockReason>,
+    },
+    #[serde(rename = "unlock_issue")]
+    UnlockIssue {
+        owner: String,
+        repo: String,
+        issue_number: u32,
+    },
     #[serde(rename = "get_issue")]
     GetIssue {
         owner: String,
         repo: String,
         issue_number: u32,
+        include_comments: Option<bool>,
     },
     #[serde(rename = "list_issue_comments")]
     ListIssueComments {
@@ -228,6 +246,13 @@ pub(crate) enum GitHubAction {
     ResolveReviewThread { thread_id: String },
     #[serde(rename = "unresolve_review_thread")]
     UnresolveReviewThread { thread_id: String },
+    #[serde(rename = "get_pull_request_status")]
+    GetPullRequestStatus {
+        owner: String,
+        repo: String,
+        #[serde(alias = "number", alias = "pull_number")]
+        pr_number: u32,
+    },
     #[serde(rename = "get_combined_status")]
     GetCombinedStatus {
         owner: String,
@@ -302,6 +327,21 @@ pub(crate) enum GitHubAction {
         branch: String,
         from_ref: String,
     },
+    #[serde(rename = "list_commits")]
+    ListCommits {
+        owner: String,
+        repo: String,
+        sha: Option<String>,
+        path: Option<String>,
+        page: Option<u32>,
+        limit: Option<u32>,
+    },
+    #[serde(rename = "get_commit")]
+    GetCommit {
+        owner: String,
+        repo: String,
+        r#ref: String,
+    },
     #[serde(rename = "get_file_content")]
     GetFileContent {
         owner: String,
@@ -309,6 +349,13 @@ pub(crate) enum GitHubAction {
         path: String,
         r#ref: Option<String>,
     },
+    #[serde(rename = "list_tree")]
+    ListTree {
+        owner: String,
+        repo: String,
+        r#ref: Option<String>,
+        recursive: Option<bool>,
+    },
     #[serde(rename = "create_or_update_file")]
     CreateOrUpdateFile {
         owner: String,
@@ -359,6 +406,13 @@ pub(crate) enum GitHubAction {
         r#ref: String,
         inputs: Option<serde_json::Value>,
     },
+    #[serde(rename = "repository_dispatch")]
+    RepositoryDispatch {
+        owner: String,
+        repo: String,
+        event_type: String,
+        client_payload: Option<serde_json::Value>,
+    },
     #[serde(rename = "get_workflow_runs")]
     GetWorkflowRuns {
         owner: String,
@@ -390,6 +444,12 @@ pub(crate) enum GitHubAction {
         repo: String,
         job_id: u64,
     },
+    #[serde(rename = "get_workflow_run_logs")]
+    GetWorkflowRunLogs {
+        owner: String,
+        repo: String,
+        run_id: u64,
+    },
     #[serde(rename = "get_workflow_run_artifacts")]
     GetWorkflowRunArtifacts {
         owner: String,
@@ -415,6 +475,25 @@ pub(crate) enum GitHubAction {
         enable_debug_logging: Option<bool>,
         enable_debugger: Option<bool>,
     },
+    #[serde(rename = "cancel_workflow_run")]
+    CancelWorkflowRun {
+        owner: String,
+        repo: String,
+        run_id: u64,
+    },
+    #[serde(rename = "rerun_workflow")]
+    RerunWorkflow {
+        owner: String,
+        repo: String,
+        run_id: u64,
+        failed_only: Option<bool>,
+        enable_debug_logging: Option<bool>,
+    },
+    #[serde(rename = "graphql")]
+    Graphql {
+        query: String,
+        variables: Option<serde_json::Value>,
+    },
     #[serde(rename = "fork_repo")]
     ForkRepo {
         owner: String,
@@ -423,6 +502,14 @@ pub(crate) enum GitHubAction {
         name: Option<String>,
         default_branch_only: Option<bool>,
     },
+    #[serde(rename = "create_webhook")]
+    CreateWebhook {
+        owner: String,
+        repo: String,
+        url: String,
+        events: Vec<String>,
+        secret: Option<String>,
+    },
     #[serde(rename = "handle_webhook")]
     HandleWebhook { webhook: GitHubWebhookRequest },
 }
@@ -464,6 +551,29 @@ impl IssueState {
     }
 }
 
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub(crate) enum IssueLockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+}
+
+impl IssueLockReason {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::OffTopic => "off-topic",
+            Self::TooHeated => "too heated",
+            Self::Resolved => "resolved",
+            Self::Spam => "spam",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize)]
 pub(crate) enum PullRequestState {
     #[serde(rename = "open")]
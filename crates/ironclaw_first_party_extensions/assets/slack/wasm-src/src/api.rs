@@ -9,6 +9,9 @@ use crate::types::*;
 
 const SLACK_API_BASE: &str = "https://slack.com/api";
 
+/// How far ahead `chat.scheduleMessage` accepts a `post_at`.
+const MAX_SCHEDULE_AHEAD_SECS: i64 = 120 * 24 * 60 * 60;
+
 /// Emit the host runtime's structured guest-error contract
 /// (`StructuredWasmGuestError { code, kind }`, parsed in
 /// `crates/ironclaw_host_runtime/src/services/wasm_execution.rs`) so a Slack
@@ -41,8 +44,9 @@ fn structured_error(code: &str, kind: &'static str) -> String {
 /// `invalid_auth` gates on re-authentication rather than reading as bad input.
 fn slack_error_kind(code: &str) -> &'static str {
     match code {
-        "missing_scope" | "not_authed" | "invalid_auth" | "account_inactive"
-        | "token_revoked" => "auth_required",
+        "missing_scope" | "not_authed" | "invalid_auth" | "account_inactive" | "token_revoked" => {
+            "auth_required"
+        }
         "ratelimited" | "rate_limited" => "client",
         "channel_not_found" | "user_not_found" => "input",
         _ if code.starts_with("invalid_") => "input",
@@ -434,26 +438,81 @@ pub fn get_conversation_history(
     Ok(enriched_history_result(&parsed))
 }
 
-/// Read the replies of one thread (`conversations.replies`), with the same
-/// enrichment contract as history: resolved display names and
-/// connected-account marking.
+/// Replies fetched per `conversations.replies` page while walking a thread to
+/// its tail.
+const THREAD_PAGE_SIZE: u32 = 200;
+
+/// Upper bound on pages walked per thread read; longer threads are read from
+/// their first `THREAD_PAGE_SIZE * MAX_THREAD_PAGES` messages.
+const MAX_THREAD_PAGES: usize = 5;
+
+/// Read the tail of one thread (`conversations.replies`): the parent plus the
+/// newest replies, `limit` messages in all, with the same enrichment contract
+/// as history: resolved display names and connected-account marking.
+/// `conversations.replies` pages oldest-first, so the thread is walked by
+/// cursor and only the tail is kept. With `exclude_bot_id`, that bot's
+/// messages are dropped before enrichment so they cost no `users.info`
+/// lookups.
 pub fn get_thread_replies(
     channel: &str,
     thread_ts: &str,
     limit: u32,
+    exclude_bot_id: Option<&str>,
 ) -> Result<ConversationHistoryResult, String> {
     // Slack rejects limit=1000; 999 is the real maximum.
-    let limit = limit.clamp(1, 999);
-    let url = format!(
-        "conversations.replies?channel={}&ts={}&limit={}",
-        url_encode(channel),
-        url_encode(thread_ts),
-        limit
-    );
+    let limit = limit.clamp(1, 999) as usize;
+    let mut messages = Vec::new();
+    let mut cursor: Option<String> = None;
+    let mut truncated = false;
+    for page in 0..MAX_THREAD_PAGES {
+        let mut url = format!(
+            "conversations.replies?channel={}&ts={}&limit={}",
+            url_encode(channel),
+            url_encode(thread_ts),
+            THREAD_PAGE_SIZE
+        );
+        if let Some(cursor) = &cursor {
+            url.push_str(&format!("&cursor={}", url_encode(cursor)));
+        }
+        let mut parsed = slack_api_call("GET", &url, None)?;
+        if let Some(page_messages) = parsed["messages"].as_array_mut() {
+            messages.append(page_messages);
+        }
+        cursor = parsed["response_metadata"]["next_cursor"]
+            .as_str()
+            .filter(|next| !next.is_empty())
+            .map(str::to_string);
+        if cursor.is_none() {
+            break;
+        }
+        truncated = page + 1 == MAX_THREAD_PAGES;
+    }
+    if let Some(bot_id) = exclude_bot_id {
+        strip_bot_messages(&mut messages, bot_id);
+    }
+    let omitted = thread_tail(&mut messages, limit);
 
-    let parsed = slack_api_call("GET", &url, None)?;
+    Ok(enriched_history_result(&serde_json::json!({
+        "messages": messages,
+        "has_more": omitted || truncated,
+    })))
+}
 
-    Ok(enriched_history_result(&parsed))
+/// Remove the messages `bot_id` posted from a `conversations.*` message list.
+/// Only that bot's messages go; other bots and apps in the thread stay.
+fn strip_bot_messages(messages: &mut Vec<serde_json::Value>, bot_id: &str) {
+    messages.retain(|message| message["bot_id"].as_str() != Some(bot_id));
+}
+
+/// Keep the thread parent (the first message) and the newest replies, `limit`
+/// messages in all. Returns whether any reply was dropped.
+fn thread_tail(messages: &mut Vec<serde_json::Value>, limit: usize) -> bool {
+    if messages.len() <= limit {
+        return false;
+    }
+    let keep_replies = limit.saturating_sub(1);
+    messages.drain(1..messages.len() - keep_replies);
+    true
 }
 
 /// Shared post-processing for `conversations.history` / `conversations.replies`
@@ -535,10 +594,7 @@ fn is_slack_user_id(id: &str) -> bool {
 /// pass through untouched, and Slack's HTML entities (&lt; &gt; &amp;) are
 /// decoded AFTER token rewriting so literal `&lt;@U…&gt;` text never turns
 /// into a live token.
-fn humanize_message_text(
-    text: &str,
-    names: &std::collections::HashMap<String, String>,
-) -> String {
+fn humanize_message_text(text: &str, names: &std::collections::HashMap<String, String>) -> String {
     let mut out = String::with_capacity(text.len());
     let mut rest = text;
     while let Some(start) = rest.find('<') {
@@ -726,12 +782,98 @@ pub fn send_message(
     })
 }
 
+/// Schedule a message as the user for `post_at` (Unix seconds) via
+/// `chat.scheduleMessage`, with the same `as_user` handling as
+/// [`send_message`].
+pub fn schedule_message(
+    channel: &str,
+    text: &str,
+    thread_ts: Option<&str>,
+    post_at: i64,
+) -> Result<ScheduleMessageResult, String> {
+    let now = (host::now_millis() / 1000) as i64;
+    validate_post_at(post_at, now)?;
+
+    let payload = schedule_message_payload(channel, text, thread_ts, post_at, true)?;
+    let parsed = match slack_api_call("POST", "chat.scheduleMessage", Some(&payload)) {
+        Ok(parsed) => parsed,
+        Err(error) if error.contains("as_user_not_supported") => {
+            let payload = schedule_message_payload(channel, text, thread_ts, post_at, false)?;
+            slack_api_call("POST", "chat.scheduleMessage", Some(&payload))?
+        }
+        Err(error) => return Err(error),
+    };
+
+    Ok(ScheduleMessageResult {
+        ok: true,
+        channel: parsed["channel"].as_str().unwrap_or(channel).to_string(),
+        scheduled_message_id: parsed["scheduled_message_id"]
+            .as_str()
+            .unwrap_or("")
+            .to_string(),
+        post_at: parsed["post_at"].as_i64().unwrap_or(post_at),
+    })
+}
+
+/// Cancel a scheduled message via `chat.deleteScheduledMessage`.
+pub fn delete_scheduled_message(
+    channel: &str,
+    scheduled_message_id: &str,
+) -> Result<DeleteScheduledMessageResult, String> {
+    let payload = serde_json::json!({
+        "channel": channel,
+        "scheduled_message_id": scheduled_message_id,
+    });
+    let payload = serde_json::to_string(&payload).map_err(|e| e.to_string())?;
+    slack_api_call("POST", "chat.deleteScheduledMessage", Some(&payload))?;
+
+    Ok(DeleteScheduledMessageResult {
+        ok: true,
+        channel: channel.to_string(),
+        scheduled_message_id: scheduled_message_id.to_string(),
+    })
+}
+
+/// Slack rejects a `post_at` in the past or more than 120 days out; check
+/// locally so the model gets an input error instead of a vendor round trip.
+fn validate_post_at(post_at: i64, now: i64) -> Result<(), String> {
+    if post_at <= now {
+        return Err(structured_error("time_in_past", "input"));
+    }
+    if post_at - now > MAX_SCHEDULE_AHEAD_SECS {
+        return Err(structured_error("time_too_far", "input"));
+    }
+    Ok(())
+}
+
+fn schedule_message_payload(
+    channel: &str,
+    text: &str,
+    thread_ts: Option<&str>,
+    post_at: i64,
+    as_user: bool,
+) -> Result<String, String> {
+    let mut payload = message_body(channel, text, thread_ts, as_user);
+    payload["post_at"] = serde_json::Value::from(post_at);
+    serde_json::to_string(&payload).map_err(|e| e.to_string())
+}
+
 fn send_message_payload(
     channel: &str,
     text: &str,
     thread_ts: Option<&str>,
     as_user: bool,
 ) -> Result<String, String> {
+    serde_json::to_string(&message_body(channel, text, thread_ts, as_user))
+        .map_err(|e| e.to_string())
+}
+
+fn message_body(
+    channel: &str,
+    text: &str,
+    thread_ts: Option<&str>,
+    as_user: bool,
+) -> serde_json::Value {
     let mut payload = serde_json::json!({
         "channel": channel,
         "text": text,
@@ -742,5 +884,53 @@ fn send_message_payload(
     if let Some(ts) = thread_ts {
         payload["thread_ts"] = serde_json::Value::String(ts.to_string());
     }
-    serde_json::to_string(&payload).map_err(|e| e.to_string())
+    payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(ts: &str, bot_id: Option<&str>) -> serde_json::Value {
+        let mut message = serde_json::json!({ "ts": ts, "text": "hi" });
+        if let Some(bot_id) = bot_id {
+            message["bot_id"] = serde_json::Value::from(bot_id);
+        }
+        message
+    }
+
+    fn timestamps(messages: &[serde_json::Value]) -> Vec<&str> {
+        messages
+            .iter()
+            .map(|message| message["ts"].as_str().unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn strip_bot_messages_drops_only_the_given_bot() {
+        let mut messages = vec![
+            message("1", None),
+            message("2", Some("B-SELF")),
+            message("3", Some("B-OTHER")),
+            message("4", None),
+        ];
+
+        strip_bot_messages(&mut messages, "B-SELF");
+
+        assert_eq!(timestamps(&messages), vec!["1", "3", "4"]);
+    }
+
+    #[test]
+    fn thread_tail_keeps_the_parent_and_the_newest_replies() {
+        let mut messages: Vec<_> = (1..=6).map(|ts| message(&ts.to_string(), None)).collect();
+
+        assert!(thread_tail(&mut messages, 3));
+        assert_eq!(timestamps(&messages), vec!["1", "5", "6"]);
+
+        assert!(!thread_tail(&mut messages, 3));
+        assert_eq!(timestamps(&messages), vec!["1", "5", "6"]);
+
+        assert!(thread_tail(&mut messages, 1));
+        assert_eq!(timestamps(&messages), vec!["1"]);
+    }
 }
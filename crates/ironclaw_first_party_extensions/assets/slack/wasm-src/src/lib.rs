@@ -19,7 +19,8 @@
 //! - `get_thread_replies`: Read one thread's replies (not part of history)
 //! - `get_user_info`: Get information about a Slack user
 //! - `whoami`: Resolve who the connected account is (auth.test)
-//! - `send_message`: Post a message as the user
+//! - `send_message`: Post (or, with `post_at`, schedule) a message as the user
+//! - `delete_scheduled_message`: Cancel a scheduled message before it posts
 //!
 //! # Example Usage
 //!
@@ -98,12 +99,8 @@ fn execute_inner(params: &str, context: Option<&str>) -> Result<String, String>
             sort,
             page,
         } => {
-            let result = api::search_messages(
-                &query,
-                count,
-                sort.as_ref().map(|sort| sort.as_str()),
-                page,
-            )?;
+            let result =
+                api::search_messages(&query, count, sort.as_ref().map(|sort| sort.as_str()), page)?;
             serde_json::to_string(&result).map_err(|e| e.to_string())?
         }
 
@@ -140,8 +137,10 @@ fn execute_inner(params: &str, context: Option<&str>) -> Result<String, String>
             channel,
             thread_ts,
             limit,
+            exclude_bot_id,
         } => {
-            let result = api::get_thread_replies(&channel, &thread_ts, limit)?;
+            let result =
+                api::get_thread_replies(&channel, &thread_ts, limit, exclude_bot_id.as_deref())?;
             serde_json::to_string(&result).map_err(|e| e.to_string())?
         }
 
@@ -159,10 +158,29 @@ fn execute_inner(params: &str, context: Option<&str>) -> Result<String, String>
             channel,
             text,
             thread_ts,
+            post_at: None,
         } => {
             let result = api::send_message(&channel, &text, thread_ts.as_deref())?;
             serde_json::to_string(&result).map_err(|e| e.to_string())?
         }
+
+        SlackUserAction::SendMessage {
+            channel,
+            text,
+            thread_ts,
+            post_at: Some(post_at),
+        } => {
+            let result = api::schedule_message(&channel, &text, thread_ts.as_deref(), post_at)?;
+            serde_json::to_string(&result).map_err(|e| e.to_string())?
+        }
+
+        SlackUserAction::DeleteScheduledMessage {
+            channel,
+            scheduled_message_id,
+        } => {
+            let result = api::delete_scheduled_message(&channel, &scheduled_message_id)?;
+            serde_json::to_string(&result).map_err(|e| e.to_string())?
+        }
     };
 
     Ok(result)
@@ -183,6 +201,7 @@ fn action_from_context(context: Option<&str>) -> Result<&'static str, String> {
         "slack.get_user_info" => Ok("get_user_info"),
         "slack.whoami" => Ok("whoami"),
         "slack.send_message" => Ok("send_message"),
+        "slack.delete_scheduled_message" => Ok("delete_scheduled_message"),
         _ => Err("unsupported_slack_user_capability".to_string()),
     }
 }
@@ -86,9 +86,15 @@ pub enum SlackUserAction {
         channel: String,
         /// The thread parent's `ts` (also exposed as `thread_ts` on replies).
         thread_ts: String,
-        /// Maximum number of messages to return (default: 50, max: 999).
+        /// Maximum number of messages to return: the parent plus the newest
+        /// replies (default: 50, max: 999).
         #[serde(default = "default_history_limit")]
         limit: u32,
+        /// Drop messages posted by this bot id — the agent's own bot, named
+        /// in the thread context of a mid-thread mention — so its earlier
+        /// replies are skipped. Other bots' messages stay. Default: none.
+        #[serde(default)]
+        exclude_bot_id: Option<String>,
     },
 
     /// Get information about a user (name, real name).
@@ -118,6 +124,20 @@ pub enum SlackUserAction {
         /// Optional thread timestamp to reply in a thread.
         #[serde(default)]
         thread_ts: Option<String>,
+        /// Optional Unix time (seconds) to post at instead of now. Must be
+        /// in the future and at most 120 days ahead; the message is then
+        /// scheduled via `chat.scheduleMessage`.
+        #[serde(default)]
+        post_at: Option<i64>,
+    },
+
+    /// Cancel a message scheduled with `send_message`'s `post_at` before it
+    /// posts. Requires the `chat:write` user scope.
+    DeleteScheduledMessage {
+        /// Channel ID the message was scheduled in.
+        channel: String,
+        /// `scheduled_message_id` returned when the message was scheduled.
+        scheduled_message_id: String,
     },
 }
 
@@ -316,6 +336,23 @@ pub struct SendMessageResult {
     pub ts: String,
 }
 
+/// Result from send_message with `post_at`.
+#[derive(Debug, Serialize)]
+pub struct ScheduleMessageResult {
+    pub ok: bool,
+    pub channel: String,
+    pub scheduled_message_id: String,
+    pub post_at: i64,
+}
+
+/// Result from delete_scheduled_message.
+#[derive(Debug, Serialize)]
+pub struct DeleteScheduledMessageResult {
+    pub ok: bool,
+    pub channel: String,
+    pub scheduled_message_id: String,
+}
+
 /// Result from whoami: the CONNECTED account's identity.
 #[derive(Debug, Serialize)]
 pub struct WhoamiResult {
@@ -59,6 +59,7 @@ fn assets() -> Vec<super::PackageAsset> {
         bytes_asset("manifest.toml", MANIFEST.as_bytes()),
         github_schema_asset!("add_issue_assignees.input.v1.json"),
         github_schema_asset!("add_issue_labels.input.v1.json"),
+        github_schema_asset!("cancel_workflow_run.input.v1.json"),
         github_schema_asset!("comment_issue.input.v1.json"),
         github_schema_asset!("comment_issue.output.v1.json"),
         github_schema_asset!("create_branch.input.v1.json"),
@@ -71,21 +72,30 @@ fn assets() -> Vec<super::PackageAsset> {
         github_schema_asset!("create_repo.input.v1.json"),
         github_schema_asset!("delete_file.input.v1.json"),
         github_schema_asset!("fork_repo.input.v1.json"),
+        github_schema_asset!("create_webhook.input.v1.json"),
         github_schema_asset!("get_combined_status.input.v1.json"),
+        github_schema_asset!("get_commit.input.v1.json"),
+        github_schema_asset!("get_contributor_stats.input.v1.json"),
         github_schema_asset!("get_file_content.input.v1.json"),
+        github_schema_asset!("list_tree.input.v1.json"),
         github_schema_asset!("get_issue.input.v1.json"),
         github_schema_asset!("get_job_logs.input.v1.json"),
         github_schema_asset!("get_issue.output.v1.json"),
         github_schema_asset!("get_pull_request.input.v1.json"),
         github_schema_asset!("get_pull_request_files.input.v1.json"),
         github_schema_asset!("get_pull_request_reviews.input.v1.json"),
+        github_schema_asset!("get_pull_request_status.input.v1.json"),
         github_schema_asset!("get_repo.input.v1.json"),
         github_schema_asset!("get_authenticated_user.input.v1.json"),
+        github_schema_asset!("get_repo_languages.input.v1.json"),
         github_schema_asset!("get_workflow_run_artifacts.input.v1.json"),
         github_schema_asset!("get_workflow_run_jobs.input.v1.json"),
+        github_schema_asset!("get_workflow_run_logs.input.v1.json"),
         github_schema_asset!("get_workflow_runs.input.v1.json"),
+        github_schema_asset!("graphql.input.v1.json"),
         github_schema_asset!("handle_webhook.input.v1.json"),
         github_schema_asset!("list_branches.input.v1.json"),
+        github_schema_asset!("list_commits.input.v1.json"),
         github_schema_asset!("list_issue_comments.input.v1.json"),
         github_schema_asset!("list_issues.input.v1.json"),
         github_schema_asset!("list_pull_request_comments.input.v1.json"),
@@ -93,12 +103,15 @@ fn assets() -> Vec<super::PackageAsset> {
         github_schema_asset!("list_pull_requests.input.v1.json"),
         github_schema_asset!("list_releases.input.v1.json"),
         github_schema_asset!("list_repos.input.v1.json"),
+        github_schema_asset!("lock_issue.input.v1.json"),
         github_schema_asset!("merge_pull_request.input.v1.json"),
         github_schema_asset!("raw_output.v1.json"),
         github_schema_asset!("remove_issue_assignees.input.v1.json"),
         github_schema_asset!("remove_issue_label.input.v1.json"),
         github_schema_asset!("reply_pull_request_comment.input.v1.json"),
+        github_schema_asset!("repository_dispatch.input.v1.json"),
         github_schema_asset!("rerun_failed_workflow_run_jobs.input.v1.json"),
+        github_schema_asset!("rerun_workflow.input.v1.json"),
         github_schema_asset!("rerun_workflow_job.input.v1.json"),
         github_schema_asset!("resolve_review_thread.input.v1.json"),
         github_schema_asset!("search_code.input.v1.json"),
@@ -107,11 +120,13 @@ fn assets() -> Vec<super::PackageAsset> {
         github_schema_asset!("search_issues_pull_requests.input.v1.json"),
         github_schema_asset!("search_repositories.input.v1.json"),
         github_schema_asset!("trigger_workflow.input.v1.json"),
+        github_schema_asset!("unlock_issue.input.v1.json"),
         github_schema_asset!("unresolve_review_thread.input.v1.json"),
         github_schema_asset!("update_issue.input.v1.json"),
         github_schema_asset!("update_pull_request.input.v1.json"),
         github_prompt_asset!("add_issue_assignees.md"),
         github_prompt_asset!("add_issue_labels.md"),
+        github_prompt_asset!("cancel_workflow_run.md"),
         github_prompt_asset!("comment_issue.md"),
         github_prompt_asset!("create_branch.md"),
         github_prompt_asset!("create_issue.md"),
@@ -123,20 +138,29 @@ fn assets() -> Vec<super::PackageAsset> {
         github_prompt_asset!("create_repo.md"),
         github_prompt_asset!("delete_file.md"),
         github_prompt_asset!("fork_repo.md"),
+        github_prompt_asset!("create_webhook.md"),
         github_prompt_asset!("get_combined_status.md"),
+        github_prompt_asset!("get_commit.md"),
+        github_prompt_asset!("get_contributor_stats.md"),
         github_prompt_asset!("get_file_content.md"),
+        github_prompt_asset!("list_tree.md"),
         github_prompt_asset!("get_issue.md"),
         github_prompt_asset!("get_job_logs.md"),
         github_prompt_asset!("get_pull_request.md"),
         github_prompt_asset!("get_pull_request_files.md"),
         github_prompt_asset!("get_pull_request_reviews.md"),
+        github_prompt_asset!("get_pull_request_status.md"),
         github_prompt_asset!("get_repo.md"),
         github_prompt_asset!("get_authenticated_user.md"),
+        github_prompt_asset!("get_repo_languages.md"),
         github_prompt_asset!("get_workflow_run_artifacts.md"),
         github_prompt_asset!("get_workflow_run_jobs.md"),
+        github_prompt_asset!("get_workflow_run_logs.md"),
         github_prompt_asset!("get_workflow_runs.md"),
+        github_prompt_asset!("graphql.md"),
         github_prompt_asset!("handle_webhook.md"),
         github_prompt_asset!("list_branches.md"),
+        github_prompt_asset!("list_commits.md"),
         github_prompt_asset!("list_issue_comments.md"),
         github_prompt_asset!("list_issues.md"),
         github_prompt_asset!("list_pull_request_comments.md"),
@@ -144,11 +168,14 @@ fn assets() -> Vec<super::PackageAsset> {
         github_prompt_asset!("list_pull_requests.md"),
         github_prompt_asset!("list_releases.md"),
         github_prompt_asset!("list_repos.md"),
+        github_prompt_asset!("lock_issue.md"),
         github_prompt_asset!("merge_pull_request.md"),
         github_prompt_asset!("remove_issue_assignees.md"),
         github_prompt_asset!("remove_issue_label.md"),
         github_prompt_asset!("reply_pull_request_comment.md"),
+        github_prompt_asset!("repository_dispatch.md"),
         github_prompt_asset!("rerun_failed_workflow_run_jobs.md"),
+        github_prompt_asset!("rerun_workflow.md"),
         github_prompt_asset!("rerun_workflow_job.md"),
         github_prompt_asset!("resolve_review_thread.md"),
         github_prompt_asset!("search_code.md"),
@@ -156,6 +183,7 @@ fn assets() -> Vec<super::PackageAsset> {
         github_prompt_asset!("search_issues_pull_requests.md"),
         github_prompt_asset!("search_repositories.md"),
         github_prompt_asset!("trigger_workflow.md"),
+        github_prompt_asset!("unlock_issue.md"),
         github_prompt_asset!("unresolve_review_thread.md"),
         github_prompt_asset!("update_issue.md"),
         github_prompt_asset!("update_pull_request.md"),
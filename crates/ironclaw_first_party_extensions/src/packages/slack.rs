@@ -95,6 +95,8 @@ fn assets() -> Vec<super::PackageAsset> {
         slack_prompt_asset!("whoami"),
         slack_schema_asset!("send_message.input.v1.json"),
         slack_prompt_asset!("send_message"),
+        slack_schema_asset!("delete_scheduled_message.input.v1.json"),
+        slack_prompt_asset!("delete_scheduled_message"),
         bytes_asset("wasm/slack_user_tool.wasm", WASM),
     ]
 }
@@ -10,10 +10,10 @@ use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use ironclaw_filesystem::RootFilesystem;
 use ironclaw_host_api::{MountView, ResourceScope, RuntimeDispatchErrorKind};
 use ironclaw_skills::{
-    InstalledSkillMetadataSource, SkillContentRequest, SkillInstallFile, SkillInstallRequest,
-    SkillInstallSource, SkillManagementContext, SkillManagementError, SkillManagementErrorKind,
-    SkillRemoveRequest, SkillUpdateRequest, install_skill, list_skills, read_skill_content,
-    remove_skill, skill_summary_json, update_skill,
+    InstalledSkillMetadataSource, SkillContentRequest, SkillEnabledRequest, SkillInstallFile,
+    SkillInstallRequest, SkillInstallSource, SkillManagementContext, SkillManagementError,
+    SkillManagementErrorKind, SkillRemoveRequest, SkillUpdateRequest, install_skill, list_skills,
+    read_skill_content, remove_skill, set_skill_enabled, skill_summary_json, update_skill,
 };
 use serde_json::{Value, json};
 
@@ -23,6 +23,7 @@ pub enum SkillManagementCapabilityKind {
     Install,
     Update,
     SetAutoActivate,
+    SetEnabled,
     Remove,
 }
 
@@ -88,6 +89,7 @@ pub async fn dispatch(
         SkillManagementCapabilityKind::Install => dispatch_install(request).await,
         SkillManagementCapabilityKind::Update => dispatch_update(request).await,
         SkillManagementCapabilityKind::SetAutoActivate => dispatch_set_auto_activate(request).await,
+        SkillManagementCapabilityKind::SetEnabled => dispatch_set_enabled(request).await,
         SkillManagementCapabilityKind::Remove => dispatch_remove(request).await,
     }
 }
@@ -272,6 +274,51 @@ async fn dispatch_set_auto_activate(
     }))
 }
 
+#[tracing::instrument(
+    level = "debug",
+    skip(request),
+    fields(
+        has_name = request.input.get("name").is_some(),
+        has_enabled = request.input.get("enabled").is_some(),
+    )
+)]
+async fn dispatch_set_enabled(
+    request: &SkillManagementCapabilityRequest<'_>,
+) -> Result<Value, SkillManagementCapabilityError> {
+    let name = request
+        .input
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            tracing::debug!("skill management set-enabled missing string name input");
+            input_error()
+        })?;
+    let enabled = request
+        .input
+        .get("enabled")
+        .and_then(Value::as_bool)
+        .ok_or_else(|| {
+            tracing::debug!("skill management set-enabled missing boolean enabled input");
+            input_error()
+        })?;
+    reject_extra_fields(request.input, &["name", "enabled"])?;
+    let context = management_context(request)?;
+    let updated = set_skill_enabled(&context, SkillEnabledRequest { name, enabled })
+        .await
+        .map_err(capability_error)?;
+    tracing::debug!(
+        skill_name = %updated.name,
+        enabled = updated.enabled,
+        "skill management set-enabled completed"
+    );
+
+    Ok(json!({
+        "updated": true,
+        "name": updated.name,
+        "enabled": updated.enabled,
+    }))
+}
+
 #[tracing::instrument(
     level = "debug",
     skip(request),
@@ -413,7 +460,11 @@ mod tests {
     use std::sync::Arc;
 
     use ironclaw_filesystem::InMemoryBackend;
-    use ironclaw_host_api::{InvocationId, MountView, ResourceScope, UserId};
+    use ironclaw_filesystem::RootFilesystem;
+    use ironclaw_host_api::{
+        InvocationId, MountAlias, MountGrant, MountPermissions, MountView, ResourceScope, UserId,
+        VirtualPath,
+    };
     use serde_json::json;
 
     use super::*;
@@ -437,4 +488,56 @@ mod tests {
 
         assert_eq!(error.kind(), RuntimeDispatchErrorKind::InputEncode);
     }
+
+    #[tokio::test]
+    async fn set_enabled_disables_skill_in_listing() {
+        let scope =
+            ResourceScope::local_default(UserId::new("alice").unwrap(), InvocationId::new())
+                .unwrap();
+        let mounts = MountView::new(vec![MountGrant::new(
+            MountAlias::new("/skills").unwrap(),
+            VirtualPath::new("/projects/skills").unwrap(),
+            MountPermissions::read_write_list_delete(),
+        )])
+        .unwrap();
+        let filesystem = Arc::new(InMemoryBackend::new());
+        filesystem
+            .write_file(
+                &VirtualPath::new("/projects/skills/helper/SKILL.md").unwrap(),
+                b"---\nname: helper\ndescription: helps\n---\nPROMPT\n",
+            )
+            .await
+            .unwrap();
+        let dispatch_kind = async |kind, input: Value| {
+            let request = SkillManagementCapabilityRequest::new(
+                kind,
+                &scope,
+                Some(&mounts),
+                filesystem.clone(),
+                &input,
+            );
+            dispatch(&request).await
+        };
+
+        let output = dispatch_kind(
+            SkillManagementCapabilityKind::SetEnabled,
+            json!({"name": "helper", "enabled": false}),
+        )
+        .await
+        .unwrap();
+        assert_eq!(output["enabled"], false);
+        let listed = dispatch_kind(SkillManagementCapabilityKind::List, json!({}))
+            .await
+            .unwrap();
+        assert_eq!(listed["skills"][0]["name"], "helper");
+        assert_eq!(listed["skills"][0]["enabled"], false);
+
+        let error = dispatch_kind(
+            SkillManagementCapabilityKind::SetEnabled,
+            json!({"name": "helper"}),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(error.kind(), RuntimeDispatchErrorKind::InputEncode);
+    }
 }
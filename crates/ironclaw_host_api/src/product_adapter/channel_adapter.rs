@@ -69,6 +69,19 @@ pub trait ChannelAdapter: Send + Sync {
     ) -> Result<Vec<TargetCandidate>, ChannelError> {
         Err(ChannelError::Unsupported)
     }
+
+    /// Optional: earlier conversation context for one inbound message (e.g.
+    /// the thread messages above a mid-thread mention), fetched through
+    /// restricted egress before admission. The host prepends it to the
+    /// message text; `None` — the default, and any vendor failure — admits
+    /// the message as parsed. Bounded by [`MAX_INBOUND_CONTEXT_BYTES`].
+    async fn inbound_context(
+        &self,
+        _message: &NormalizedInboundMessage,
+        _egress: &dyn RestrictedEgress,
+    ) -> Option<String> {
+        None
+    }
 }
 
 /// Activation/cleanup context: installation identity, the extension's
@@ -99,6 +112,13 @@ pub enum InboundOutcome {
     Messages(Vec<NormalizedInboundMessage>),
     /// Bounded immediate response (e.g. a URL-verification challenge).
     Respond(ImmediateResponse),
+    /// Normalized message(s) plus the bounded response the host returns once
+    /// they are admitted (e.g. a Telegram webhook-reply `answerCallbackQuery`
+    /// acknowledging a button tap).
+    MessagesWithResponse {
+        messages: Vec<NormalizedInboundMessage>,
+        response: ImmediateResponse,
+    },
     /// Authenticated no-op (ignored event types).
     Ignore,
 }
@@ -124,6 +144,9 @@ pub struct NormalizedInboundMessage {
 /// Maximum size of an inbound message's opaque `reply_context`.
 pub const MAX_REPLY_CONTEXT_BYTES: usize = 4 * 1024;
 
+/// Maximum size of the context [`ChannelAdapter::inbound_context`] returns.
+pub const MAX_INBOUND_CONTEXT_BYTES: usize = 8 * 1024;
+
 /// An attachment reference — the vendor URL/id plus a mime hint. Bytes are
 /// fetched host-side through restricted egress with the channel credential
 /// only when a consumer needs them, keeping `inbound` pure.
@@ -189,6 +212,25 @@ pub enum OutboundPart {
     Retract {
         vendor_message_ref: String,
     },
+    /// Message text offered with quick-reply choices. Channels with native
+    /// buttons render them (e.g. Telegram inline keyboards) and a tap comes
+    /// back as an ordinary inbound message carrying the choice's `reply`;
+    /// text-only channels fall back to [`render_channel_choices`].
+    ///
+    /// [`render_channel_choices`]: crate::product_adapter::render_channel_choices
+    Choices {
+        text: String,
+        choices: Vec<OutboundChoice>,
+    },
+}
+
+/// One quick-reply choice on an [`OutboundPart::Choices`] part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboundChoice {
+    /// Button label shown to the user.
+    pub label: String,
+    /// Text the choice sends back as the user's reply.
+    pub reply: String,
 }
 
 /// Structured per-attempt delivery report. The adapter cannot mark anything
@@ -28,9 +28,10 @@ pub use auth::{
 pub use capabilities::{ProductAdapterCapabilities, ProductCapabilityFlag};
 pub use channel_adapter::{
     AttachmentRef, ChannelAdapter, ChannelContext, ChannelError, DeliveryReport, ImmediateResponse,
-    InboundOutcome, MAX_IMMEDIATE_RESPONSE_BYTES, MAX_REPLY_CONTEXT_BYTES,
-    NormalizedInboundMessage, OutboundEnvelope, OutboundPart, OutboundTarget, PartDeliveryOutcome,
-    TargetCandidate, TargetQuery, VerifiedInbound,
+    InboundOutcome, MAX_IMMEDIATE_RESPONSE_BYTES, MAX_INBOUND_CONTEXT_BYTES,
+    MAX_REPLY_CONTEXT_BYTES, NormalizedInboundMessage, OutboundChoice, OutboundEnvelope,
+    OutboundPart, OutboundTarget, PartDeliveryOutcome, TargetCandidate, TargetQuery,
+    VerifiedInbound,
 };
 pub use egress::{
     DeclaredEgressHost, DeclaredEgressTarget, DeliveryAttemptId, DeliveryStatus,
@@ -67,7 +68,7 @@ pub use outbound::{
     PreferenceTargetEncodeRequest, ProductGateKind, ProductOutboundEnvelope,
     ProductOutboundPayload, ProductOutboundTarget, ProductProjectionItem, ProductProjectionState,
     ProductRenderOutcome, ProductSynchronousResponse, ProductWorkSummaryPhase, ProgressKind,
-    ProgressUpdateView, ProjectionCursor, render_channel_auth_prompt,
+    ProgressUpdateView, ProjectionCursor, render_channel_auth_prompt, render_channel_choices,
 };
 pub use projection::{
     ProductProjectionReadInput, ProductProjectionSubject, ProductProjectionSubscribeInput,
@@ -9,6 +9,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+use crate::product_adapter::channel_adapter::OutboundChoice;
 use crate::product_adapter::error::ProductAdapterError;
 use crate::product_adapter::external::{ExternalActorRef, ExternalConversationRef};
 use crate::product_adapter::identity::{AdapterInstallationId, ProductAdapterId};
@@ -1163,6 +1164,20 @@ pub fn render_channel_auth_prompt(view: &AuthPromptView, direct_message: bool) -
     text
 }
 
+/// Render quick-reply choices for channels without native buttons: the
+/// message text followed by the replies the user can send back.
+pub fn render_channel_choices(text: &str, choices: &[OutboundChoice]) -> String {
+    if choices.is_empty() {
+        return text.to_string();
+    }
+    let replies = choices
+        .iter()
+        .map(|choice| format!("`{}`", choice.reply))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{text}\n\nReply with one of: {replies}")
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AuthPromptContextView {
     pub challenge_kind: AuthPromptChallengeKind,
@@ -1816,6 +1831,25 @@ impl ProductOutboundEnvelope {
 mod tests {
     use super::*;
 
+    #[test]
+    fn render_channel_choices_lists_replies_after_text() {
+        let choices = [
+            OutboundChoice {
+                label: "Approve".to_string(),
+                reply: "approve".to_string(),
+            },
+            OutboundChoice {
+                label: "Deny".to_string(),
+                reply: "deny".to_string(),
+            },
+        ];
+        assert_eq!(
+            render_channel_choices("Approval needed", &choices),
+            "Approval needed\n\nReply with one of: `approve`, `deny`"
+        );
+        assert_eq!(render_channel_choices("Plain", &[]), "Plain");
+    }
+
     #[test]
     fn auth_prompt_challenge_kind_all_variants_roundtrip() {
         // Stable wire values: browser OAuth, pasted credentials, and
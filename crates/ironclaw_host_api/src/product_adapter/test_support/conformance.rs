@@ -169,6 +169,17 @@ pub async fn run_channel_adapter_conformance(conformance: ChannelAdapterConforma
                     );
                 }
             }
+            Ok(InboundOutcome::MessagesWithResponse { messages, response }) => {
+                response
+                    .validate()
+                    .expect("conformance: immediate responses must stay within host bounds"); // safety: test-support conformance failure should fail the caller's test.
+                for message in &messages {
+                    conformance_value(
+                        message.validate(),
+                        "conformance: messages normalized from odd input must satisfy bounds",
+                    );
+                }
+            }
             Ok(InboundOutcome::Ignore) | Err(_) => {}
         }
     }
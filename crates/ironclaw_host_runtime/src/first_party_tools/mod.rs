@@ -58,8 +58,9 @@ pub use outbound_delivery::OUTBOUND_DELIVERY_TARGET_ROUTE_CURRENT_CAPABILITY_ID;
 pub use profile_set::PROFILE_SET_CAPABILITY_ID;
 pub use shell::SHELL_CAPABILITY_ID;
 pub use skill_management::{
-    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID,
-    SKILL_REMOVE_CAPABILITY_ID, SKILL_UPDATE_CAPABILITY_ID,
+    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_ENABLED_SET_CAPABILITY_ID,
+    SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID,
+    SKILL_UPDATE_CAPABILITY_ID,
 };
 pub use spawn_subagent::SPAWN_SUBAGENT_CAPABILITY_ID;
 pub use time::TIME_CAPABILITY_ID;
@@ -515,6 +516,7 @@ fn first_party_origin_gate_matrix(id: &str) -> OriginGateMatrix {
         SKILL_INSTALL_CAPABILITY_ID
             | SKILL_UPDATE_CAPABILITY_ID
             | SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID
+            | SKILL_ENABLED_SET_CAPABILITY_ID
             | SKILL_REMOVE_CAPABILITY_ID
     ) {
         matrix.product = OriginGatePolicy::ConsentSufficient;
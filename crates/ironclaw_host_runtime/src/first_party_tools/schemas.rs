@@ -679,6 +679,31 @@ pub(crate) fn resolve_builtin_input_schema_ref(reference: &str) -> Option<Value>
             "required": ["updated", "name", "auto_activate"],
             "additionalProperties": false
         }),
+        "schemas/builtin/skill_enabled_set.input.v1.json" => json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Name of the system or user skill to enable or disable"
+                },
+                "enabled": {
+                    "type": "boolean",
+                    "description": "Whether the skill may activate at all; disabled skills ignore explicit mentions too"
+                }
+            },
+            "required": ["name", "enabled"],
+            "additionalProperties": false
+        }),
+        "schemas/builtin/skill_enabled_set.output.v1.json" => json!({
+            "type": "object",
+            "properties": {
+                "updated": { "type": "boolean" },
+                "name": { "type": "string" },
+                "enabled": { "type": "boolean" }
+            },
+            "required": ["updated", "name", "enabled"],
+            "additionalProperties": false
+        }),
         "schemas/builtin/skill_remove.input.v1.json" => json!({
             "type": "object",
             "properties": {
@@ -988,6 +1013,8 @@ mod tests {
             "schemas/builtin/skill_update.output.v1.json",
             "schemas/builtin/skill_auto_activate_set.input.v1.json",
             "schemas/builtin/skill_auto_activate_set.output.v1.json",
+            "schemas/builtin/skill_enabled_set.input.v1.json",
+            "schemas/builtin/skill_enabled_set.output.v1.json",
             "schemas/builtin/skill_auto_activate_learned_set.input.v1.json",
             "schemas/builtin/skill_auto_activate_learned_set.output.v1.json",
         ] {
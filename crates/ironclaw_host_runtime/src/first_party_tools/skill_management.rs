@@ -26,6 +26,7 @@ pub const SKILL_LIST_CAPABILITY_ID: &str = "builtin.skill_list";
 pub const SKILL_INSTALL_CAPABILITY_ID: &str = "builtin.skill_install";
 pub const SKILL_UPDATE_CAPABILITY_ID: &str = "builtin.skill_update";
 pub const SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID: &str = "builtin.skill_auto_activate_set";
+pub const SKILL_ENABLED_SET_CAPABILITY_ID: &str = "builtin.skill_enabled_set";
 pub const SKILL_REMOVE_CAPABILITY_ID: &str = "builtin.skill_remove";
 
 pub(super) fn manifests() -> Result<Vec<CapabilityManifest>, ExtensionError> {
@@ -63,6 +64,13 @@ pub(super) fn manifests() -> Result<Vec<CapabilityManifest>, ExtensionError> {
             PermissionMode::Ask,
             resource_profile(),
         )?,
+        first_party_capability_manifest(
+            SKILL_ENABLED_SET_CAPABILITY_ID,
+            "Enable or disable a Reborn filesystem skill without changing its files; disabled skills never activate",
+            vec![EffectKind::ReadFilesystem, EffectKind::WriteFilesystem],
+            PermissionMode::Ask,
+            resource_profile(),
+        )?,
         first_party_capability_manifest(
             SKILL_REMOVE_CAPABILITY_ID,
             "Remove a user-installed Reborn filesystem skill",
@@ -97,6 +105,10 @@ pub(super) fn insert_handlers(
         CapabilityId::new(SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID)?,
         handler.clone(),
     );
+    registry.insert_handler(
+        CapabilityId::new(SKILL_ENABLED_SET_CAPABILITY_ID)?,
+        handler.clone(),
+    );
     registry.insert_handler(CapabilityId::new(SKILL_REMOVE_CAPABILITY_ID)?, handler);
     Ok(())
 }
@@ -115,6 +127,7 @@ impl FirstPartyCapabilityHandler for SkillManagementToolHandler {
             SKILL_INSTALL_CAPABILITY_ID => SkillManagementCapabilityKind::Install,
             SKILL_UPDATE_CAPABILITY_ID => SkillManagementCapabilityKind::Update,
             SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID => SkillManagementCapabilityKind::SetAutoActivate,
+            SKILL_ENABLED_SET_CAPABILITY_ID => SkillManagementCapabilityKind::SetEnabled,
             SKILL_REMOVE_CAPABILITY_ID => SkillManagementCapabilityKind::Remove,
             _ => {
                 return Err(FirstPartyCapabilityError::new(
@@ -187,6 +187,8 @@ async fn github_v2_package_discovers_and_publishes_issue_hot_catalog() {
     ));
     let expected_github_capability_ids = [
         "github.get_repo",
+        "github.get_repo_languages",
+        "github.get_contributor_stats",
         "github.create_repo",
         "github.list_issues",
         "github.create_issue",
@@ -195,6 +197,8 @@ async fn github_v2_package_discovers_and_publishes_issue_hot_catalog() {
         "github.remove_issue_label",
         "github.add_issue_assignees",
         "github.remove_issue_assignees",
+        "github.lock_issue",
+        "github.unlock_issue",
         "github.get_issue",
         "github.list_issue_comments",
         "github.create_issue_comment",
@@ -208,6 +212,7 @@ async fn github_v2_package_discovers_and_publishes_issue_hot_catalog() {
         "github.list_pull_request_comments",
         "github.reply_pull_request_comment",
         "github.get_pull_request_reviews",
+        "github.get_pull_request_status",
         "github.list_pull_request_review_threads",
         "github.resolve_review_thread",
         "github.unresolve_review_thread",
@@ -221,22 +226,31 @@ async fn github_v2_package_discovers_and_publishes_issue_hot_catalog() {
         "github.search_issues_pull_requests",
         "github.list_branches",
         "github.create_branch",
+        "github.list_commits",
+        "github.get_commit",
         "github.get_file_content",
+        "github.list_tree",
         "github.create_or_update_file",
         "github.delete_file",
         "github.list_releases",
         "github.create_release",
         "github.trigger_workflow",
+        "github.repository_dispatch",
         "github.get_workflow_runs",
         "github.get_workflow_run_jobs",
         "github.get_job_logs",
+        "github.get_workflow_run_logs",
         "github.get_workflow_run_artifacts",
         "github.rerun_failed_workflow_run_jobs",
         "github.rerun_workflow_job",
+        "github.cancel_workflow_run",
+        "github.rerun_workflow",
         "github.fork_repo",
+        "github.create_webhook",
+        "github.graphql",
         "github.handle_webhook",
     ];
-    assert_eq!(expected_github_capability_ids.len(), 49);
+    assert_eq!(expected_github_capability_ids.len(), 63);
     assert_eq!(
         package
             .capabilities
@@ -362,7 +376,7 @@ async fn github_v2_package_discovers_and_publishes_issue_hot_catalog() {
             .as_slice(),
         expected_github_capability_ids
     );
-    assert_eq!(hot_catalog.capabilities.len(), 49);
+    assert_eq!(hot_catalog.capabilities.len(), 63);
 
     let search = hot_catalog
         .get(&CapabilityId::new("github.search_issues").unwrap())
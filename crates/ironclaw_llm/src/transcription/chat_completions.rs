@@ -78,6 +78,10 @@ fn audio_format_str(format: AudioFormat) -> &'static str {
 
 #[async_trait]
 impl TranscriptionProvider for ChatCompletionsTranscriptionProvider {
+    fn name(&self) -> &'static str {
+        "chat_completions"
+    }
+
     async fn transcribe(
         &self,
         audio_data: &[u8],
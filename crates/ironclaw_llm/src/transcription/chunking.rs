@@ -0,0 +1,303 @@
+//! Splitting audio that exceeds a provider's upload limit.
+//!
+//! WAV is split natively: the PCM payload is cut on sample-frame boundaries
+//! and every chunk gets its own RIFF header, so each piece is a standalone
+//! file. Consecutive chunks overlap by [`CHUNK_OVERLAP_MS`] so a word spoken
+//! across a cut is heard whole by at least one chunk;
+//! [`join_chunk_transcripts`] then drops the repeated words.
+//!
+//! Compressed container formats (OGG/Opus, MP3, MP4/M4A, WebM, FLAC) cannot
+//! be cut at arbitrary byte offsets and no decoder is linked into this crate,
+//! so [`chunk_audio`] reports them as [`TranscriptionError::FileTooLarge`].
+//! When the middleware has an [`super::AudioTranscoder`], it decodes them to
+//! WAV first and splits that instead.
+
+use super::{AudioFormat, TranscriptionError};
+
+/// Audio shared between the end of one chunk and the start of the next.
+pub const CHUNK_OVERLAP_MS: u64 = 1_000;
+
+/// Longest word run [`join_chunk_transcripts`] will treat as overlap.
+const MAX_OVERLAP_WORDS: usize = 20;
+
+/// One standalone piece of a split recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioChunk {
+    /// Complete audio file for this chunk (same format as the input).
+    pub data: Vec<u8>,
+    /// Offset of the chunk's first sample in the original audio.
+    pub start_ms: u64,
+    /// Leading audio repeated from the previous chunk (0 for the first).
+    pub overlap_ms: u64,
+}
+
+/// Split `data` into chunks of at most `max_bytes` each.
+///
+/// Input that already fits is returned as a single chunk. Returns
+/// [`TranscriptionError::FileTooLarge`] when the format cannot be split or
+/// `max_bytes` is too small to make progress past the overlap.
+pub fn chunk_audio(
+    data: &[u8],
+    format: AudioFormat,
+    max_bytes: u64,
+) -> Result<Vec<AudioChunk>, TranscriptionError> {
+    let size = data.len() as u64;
+    if size <= max_bytes {
+        return Ok(vec![AudioChunk {
+            data: data.to_vec(),
+            start_ms: 0,
+            overlap_ms: 0,
+        }]);
+    }
+    let too_large = || TranscriptionError::FileTooLarge {
+        size,
+        max: max_bytes,
+    };
+    match format {
+        AudioFormat::Wav => chunk_wav(data, max_bytes).ok_or_else(too_large),
+        _ => Err(too_large()),
+    }
+}
+
+struct WavLayout<'a> {
+    fmt_chunk: &'a [u8],
+    byte_rate: u64,
+    block_align: u64,
+    pcm: &'a [u8],
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// Locate the `fmt ` and `data` chunks of a RIFF/WAVE file.
+fn parse_wav(data: &[u8]) -> Option<WavLayout<'_>> {
+    if data.get(0..4)? != b"RIFF" || data.get(8..12)? != b"WAVE" {
+        return None;
+    }
+    let mut offset = 12;
+    let mut fmt_chunk = None;
+    while offset + 8 <= data.len() {
+        let id = &data[offset..offset + 4];
+        let len = read_u32(data, offset + 4)? as usize;
+        let body_start = offset + 8;
+        // Streaming writers leave the data length as 0xFFFFFFFF; clamp.
+        let body_end = body_start.saturating_add(len).min(data.len());
+        match id {
+            b"fmt " => fmt_chunk = Some(&data[offset..body_end]),
+            b"data" => {
+                let fmt_chunk = fmt_chunk?;
+                let block_align = u64::from(read_u16(fmt_chunk, 8 + 12)?);
+                let byte_rate = u64::from(read_u32(fmt_chunk, 8 + 8)?);
+                if block_align == 0 || byte_rate == 0 {
+                    return None;
+                }
+                return Some(WavLayout {
+                    fmt_chunk,
+                    byte_rate,
+                    block_align,
+                    pcm: &data[body_start..body_end],
+                });
+            }
+            _ => {}
+        }
+        // Chunks are word-aligned.
+        offset = body_start.checked_add(len)?.checked_add(len & 1)?;
+    }
+    None
+}
+
+/// Whether `data` is already 16 kHz mono 16-bit PCM WAV, the only input
+/// whisper.cpp reads without resampling.
+pub(super) fn is_16khz_mono_pcm_wav(data: &[u8]) -> bool {
+    parse_wav(data).is_some_and(|wav| {
+        read_u16(wav.fmt_chunk, 8) == Some(1)
+            && read_u16(wav.fmt_chunk, 8 + 2) == Some(1)
+            && read_u32(wav.fmt_chunk, 8 + 4) == Some(16_000)
+            && read_u16(wav.fmt_chunk, 8 + 14) == Some(16)
+    })
+}
+
+fn wav_file(fmt_chunk: &[u8], pcm: &[u8]) -> Vec<u8> {
+    let fmt_padded = fmt_chunk.len() + (fmt_chunk.len() & 1);
+    let riff_len = 4 + fmt_padded + 8 + pcm.len();
+    let mut out = Vec::with_capacity(8 + riff_len);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(riff_len as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+    out.extend_from_slice(fmt_chunk);
+    if fmt_chunk.len() & 1 == 1 {
+        out.push(0);
+    }
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(pcm.len() as u32).to_le_bytes());
+    out.extend_from_slice(pcm);
+    out
+}
+
+fn chunk_wav(data: &[u8], max_bytes: u64) -> Option<Vec<AudioChunk>> {
+    let wav = parse_wav(data)?;
+    let header_len = (12 + wav.fmt_chunk.len() + (wav.fmt_chunk.len() & 1) + 8) as u64;
+    let align = |bytes: u64| bytes - bytes % wav.block_align;
+    let payload = align(max_bytes.checked_sub(header_len)?);
+    let overlap = align(wav.byte_rate * CHUNK_OVERLAP_MS / 1000);
+    // Each chunk must contribute new audio beyond the overlap.
+    if payload <= overlap {
+        return None;
+    }
+    let step = (payload - overlap) as usize;
+    let payload = payload as usize;
+    let to_ms = |bytes: usize| bytes as u64 * 1000 / wav.byte_rate;
+
+    let mut chunks = Vec::new();
+    let mut start = 0usize;
+    loop {
+        let end = (start + payload).min(wav.pcm.len());
+        chunks.push(AudioChunk {
+            data: wav_file(wav.fmt_chunk, &wav.pcm[start..end]),
+            start_ms: to_ms(start),
+            overlap_ms: if start == 0 {
+                0
+            } else {
+                to_ms(overlap as usize)
+            },
+        });
+        if end == wav.pcm.len() {
+            break;
+        }
+        start += step;
+    }
+    Some(chunks)
+}
+
+/// Join per-chunk transcripts, dropping words repeated across the overlap.
+///
+/// The longest run of words (up to a small bound) that ends one transcript
+/// and starts the next, compared case- and punctuation-insensitively, is
+/// kept only once.
+pub fn join_chunk_transcripts(parts: &[String]) -> String {
+    fn normalize(word: &str) -> String {
+        word.trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase()
+    }
+
+    let mut words: Vec<&str> = Vec::new();
+    for part in parts {
+        let next: Vec<&str> = part.split_whitespace().collect();
+        let max = MAX_OVERLAP_WORDS.min(words.len()).min(next.len());
+        let overlap = (1..=max)
+            .rev()
+            .find(|&n| {
+                words[words.len() - n..]
+                    .iter()
+                    .zip(&next[..n])
+                    .all(|(a, b)| normalize(a) == normalize(b))
+            })
+            .unwrap_or(0);
+        words.extend_from_slice(&next[overlap..]);
+    }
+    words.join(" ")
+}
+
+/// 16-bit mono PCM WAV at 1 kHz (2000 bytes per second of audio).
+#[cfg(test)]
+pub(super) fn test_wav(samples: usize) -> Vec<u8> {
+    test_wav_at(1000, samples)
+}
+
+/// 16-bit mono PCM WAV at `sample_rate`.
+#[cfg(test)]
+pub(super) fn test_wav_at(sample_rate: u32, samples: usize) -> Vec<u8> {
+    let mut fmt = Vec::new();
+    fmt.extend_from_slice(b"fmt ");
+    fmt.extend_from_slice(&16u32.to_le_bytes());
+    fmt.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    fmt.extend_from_slice(&1u16.to_le_bytes()); // channels
+    fmt.extend_from_slice(&sample_rate.to_le_bytes()); // sample rate
+    fmt.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+    fmt.extend_from_slice(&2u16.to_le_bytes()); // block align
+    fmt.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    let pcm: Vec<u8> = (0..samples * 2).map(|i| (i % 251) as u8).collect();
+    wav_file(&fmt, &pcm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_16khz_mono_pcm_wav() {
+        assert!(is_16khz_mono_pcm_wav(&test_wav_at(16_000, 10)));
+        assert!(!is_16khz_mono_pcm_wav(&test_wav_at(44_100, 10)));
+        assert!(!is_16khz_mono_pcm_wav(b"not a wav file"));
+    }
+
+    #[test]
+    fn small_audio_is_a_single_chunk() {
+        let data = test_wav(10);
+        let chunks = chunk_audio(&data, AudioFormat::Wav, 1_000_000).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].data, data);
+    }
+
+    #[test]
+    fn wav_is_split_into_standalone_overlapping_chunks() {
+        // 10 s of audio (20_000 PCM bytes), 5_044-byte limit: 5_000 PCM
+        // bytes (2.5 s) per chunk, advancing 1.5 s after the 1 s overlap.
+        let data = test_wav(10_000);
+        let chunks = chunk_audio(&data, AudioFormat::Wav, 5_044).unwrap();
+
+        let starts: Vec<u64> = chunks.iter().map(|c| c.start_ms).collect();
+        assert_eq!(starts, vec![0, 1_500, 3_000, 4_500, 6_000, 7_500]);
+        assert_eq!(chunks[0].overlap_ms, 0);
+        assert!(chunks[1..].iter().all(|c| c.overlap_ms == 1_000));
+
+        let source = parse_wav(&data).unwrap();
+        for chunk in &chunks {
+            assert!(chunk.data.len() as u64 <= 5_044);
+            let parsed = parse_wav(&chunk.data).expect("chunk is a valid WAV file");
+            let start = (chunk.start_ms * 2) as usize;
+            assert_eq!(parsed.pcm, &source.pcm[start..start + parsed.pcm.len()]);
+        }
+        let last = parse_wav(&chunks.last().unwrap().data).unwrap();
+        assert_eq!(7_500 * 2 + last.pcm.len(), 20_000);
+    }
+
+    #[test]
+    fn unsplittable_input_is_too_large() {
+        assert!(matches!(
+            chunk_audio(&[0; 64], AudioFormat::Ogg, 16),
+            Err(TranscriptionError::FileTooLarge { size: 64, max: 16 })
+        ));
+        // A limit that cannot hold more than the overlap makes no progress.
+        assert!(matches!(
+            chunk_audio(&test_wav(10_000), AudioFormat::Wav, 2_000),
+            Err(TranscriptionError::FileTooLarge { .. })
+        ));
+        assert!(matches!(
+            chunk_audio(&[0; 64], AudioFormat::Wav, 16),
+            Err(TranscriptionError::FileTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn joined_transcripts_drop_overlapping_words() {
+        let parts = vec![
+            "The quick brown fox jumps".to_string(),
+            "fox Jumps, over the lazy".to_string(),
+            "dog.".to_string(),
+        ];
+        assert_eq!(
+            join_chunk_transcripts(&parts),
+            "The quick brown fox jumps over the lazy dog."
+        );
+        assert_eq!(
+            join_chunk_transcripts(&["no overlap".to_string(), "here".to_string()]),
+            "no overlap here"
+        );
+    }
+}
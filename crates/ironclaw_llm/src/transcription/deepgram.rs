@@ -0,0 +1,250 @@
+//! Deepgram transcription provider.
+//!
+//! Uses Deepgram's pre-recorded `/v1/listen` endpoint, which takes the raw
+//! audio bytes as the request body with the audio MIME type as
+//! `Content-Type`.
+
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+
+use super::{AudioFormat, TranscriptionError, TranscriptionProvider};
+
+/// Upload limit of the pre-recorded `/v1/listen` endpoint (2 GB).
+const DEEPGRAM_MAX_FILE_SIZE: u64 = 2 * 1024 * 1024 * 1024;
+
+/// Deepgram speech-to-text provider.
+pub struct DeepgramProvider {
+    client: reqwest::Client,
+    api_key: SecretString,
+    model: String,
+    base_url: String,
+}
+
+impl DeepgramProvider {
+    /// Create a new Deepgram provider with the given API key.
+    pub fn new(api_key: SecretString) -> Self {
+        Self {
+            // Transcription is not a turn-model call and is not gated by the
+            // runner lease, so its longer request budget is intentionally kept;
+            // it gains connect/keepalive/pool hygiene via the shared builder.
+            client: match crate::config::hardened_client_builder(
+                crate::config::TRANSCRIPTION_REQUEST_TIMEOUT_SECS,
+            )
+            .build()
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to build HTTP client with timeout, falling back to default: {e}"
+                    );
+                    reqwest::Client::default()
+                }
+            },
+            api_key,
+            model: "nova-3".to_string(),
+            base_url: "https://api.deepgram.com".to_string(),
+        }
+    }
+
+    /// Override the base URL (for proxied or self-hosted endpoints).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Override the model name.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+}
+
+/// `Content-Type` Deepgram expects for each [`AudioFormat`].
+fn content_type(format: AudioFormat) -> &'static str {
+    match format {
+        AudioFormat::Ogg => "audio/ogg",
+        AudioFormat::Mp3 => "audio/mpeg",
+        AudioFormat::Mp4 => "audio/mp4",
+        AudioFormat::Wav => "audio/wav",
+        AudioFormat::Webm => "audio/webm",
+        AudioFormat::Flac => "audio/flac",
+        AudioFormat::M4a => "audio/m4a",
+    }
+}
+
+/// Map a non-success response onto the middleware's error taxonomy.
+///
+/// A rejected key is a configuration problem, not a failed request, so it
+/// surfaces as `NotConfigured`; both it and `RequestFailed` let the fallback
+/// chain move on to the next provider.
+fn status_error(status: StatusCode, body: &str, size: u64) -> TranscriptionError {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => TranscriptionError::NotConfigured(
+            format!("Deepgram rejected the API key (HTTP {status})"),
+        ),
+        StatusCode::PAYLOAD_TOO_LARGE => TranscriptionError::FileTooLarge {
+            size,
+            max: DEEPGRAM_MAX_FILE_SIZE,
+        },
+        _ => TranscriptionError::RequestFailed(format!("HTTP {}: {}", status, body)),
+    }
+}
+
+/// `/v1/listen` response body (only the fields we use).
+#[derive(Debug, Deserialize)]
+struct ListenResponse {
+    results: ListenResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenResults {
+    channels: Vec<ListenChannel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenChannel {
+    alternatives: Vec<ListenAlternative>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenAlternative {
+    transcript: String,
+}
+
+impl ListenResponse {
+    /// The best alternative of the first channel.
+    fn into_transcript(self) -> Result<String, TranscriptionError> {
+        self.results
+            .channels
+            .into_iter()
+            .next()
+            .and_then(|channel| channel.alternatives.into_iter().next())
+            .map(|alternative| alternative.transcript.trim().to_string())
+            .ok_or_else(|| {
+                TranscriptionError::RequestFailed(
+                    "unexpected response format: missing results.channels[0].alternatives[0]"
+                        .to_string(),
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for DeepgramProvider {
+    fn name(&self) -> &'static str {
+        "deepgram"
+    }
+
+    fn max_file_size(&self) -> Option<u64> {
+        Some(DEEPGRAM_MAX_FILE_SIZE)
+    }
+
+    async fn transcribe(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<String, TranscriptionError> {
+        if audio_data.is_empty() {
+            return Err(TranscriptionError::EmptyAudio);
+        }
+        if self.api_key.expose_secret().is_empty() {
+            return Err(TranscriptionError::NotConfigured(
+                "Deepgram API key is empty".to_string(),
+            ));
+        }
+
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/v1/listen", self.base_url),
+            [("model", self.model.as_str()), ("smart_format", "true")],
+        )
+        .map_err(|e| TranscriptionError::NotConfigured(format!("invalid Deepgram URL: {e}")))?;
+
+        let response = self
+            .client
+            .post(url)
+            .header(
+                "Authorization",
+                format!("Token {}", self.api_key.expose_secret()),
+            )
+            .header("Content-Type", content_type(format))
+            .body(audio_data.to_vec())
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "unknown error".to_string());
+            return Err(status_error(status, &body, audio_data.len() as u64));
+        }
+
+        let listen: ListenResponse = response
+            .json()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        listen.into_transcript()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn listen_response_takes_first_channel_alternative() {
+        let listen: ListenResponse = serde_json::from_value(serde_json::json!({
+            "metadata": {"request_id": "abc"},
+            "results": {"channels": [{"alternatives": [
+                {"transcript": " Hello world. ", "confidence": 0.98},
+                {"transcript": "Hollow world.", "confidence": 0.4}
+            ]}]}
+        }))
+        .unwrap();
+        assert_eq!(listen.into_transcript().unwrap(), "Hello world.");
+
+        let empty: ListenResponse =
+            serde_json::from_value(serde_json::json!({"results": {"channels": []}})).unwrap();
+        assert!(matches!(
+            empty.into_transcript(),
+            Err(TranscriptionError::RequestFailed(_))
+        ));
+    }
+
+    #[test]
+    fn status_errors_map_to_fallback_aware_variants() {
+        assert!(matches!(
+            status_error(StatusCode::UNAUTHORIZED, "", 10),
+            TranscriptionError::NotConfigured(_)
+        ));
+        assert!(matches!(
+            status_error(StatusCode::FORBIDDEN, "", 10),
+            TranscriptionError::NotConfigured(_)
+        ));
+        assert!(matches!(
+            status_error(StatusCode::PAYLOAD_TOO_LARGE, "", 10),
+            TranscriptionError::FileTooLarge { size: 10, .. }
+        ));
+        match status_error(StatusCode::BAD_REQUEST, "corrupt audio", 10) {
+            TranscriptionError::RequestFailed(message) => {
+                assert_eq!(message, "HTTP 400 Bad Request: corrupt audio");
+            }
+            other => panic!("expected RequestFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_audio_and_missing_key_before_request() {
+        let provider = DeepgramProvider::new(SecretString::from("test-key".to_string()));
+        let result = provider.transcribe(&[], AudioFormat::Ogg).await;
+        assert!(matches!(result, Err(TranscriptionError::EmptyAudio)));
+
+        let provider = DeepgramProvider::new(SecretString::from(String::new()));
+        let result = provider.transcribe(&[1, 2, 3], AudioFormat::Ogg).await;
+        assert!(matches!(result, Err(TranscriptionError::NotConfigured(_))));
+    }
+}
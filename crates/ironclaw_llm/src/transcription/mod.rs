@@ -5,10 +5,22 @@
 //! on incoming messages and replaces them with transcribed text.
 
 mod chat_completions;
+mod chunking;
+mod deepgram;
 mod openai;
+mod store;
+mod transcode;
+mod whisper_cpp;
 
 pub use self::chat_completions::ChatCompletionsTranscriptionProvider;
+pub use self::chunking::{AudioChunk, CHUNK_OVERLAP_MS, chunk_audio, join_chunk_transcripts};
+pub use self::deepgram::DeepgramProvider;
 pub use self::openai::OpenAiWhisperProvider;
+pub use self::store::{TranscriptStore, WorkspaceTranscriptStore};
+pub use self::transcode::AudioTranscoder;
+pub use self::whisper_cpp::WhisperCppProvider;
+
+use std::sync::Arc;
 
 use async_trait::async_trait;
 
@@ -25,6 +37,17 @@ pub enum AudioFormat {
 }
 
 impl AudioFormat {
+    /// Every supported format.
+    pub const ALL: [Self; 7] = [
+        Self::Ogg,
+        Self::Mp3,
+        Self::Mp4,
+        Self::Wav,
+        Self::Webm,
+        Self::Flac,
+        Self::M4a,
+    ];
+
     /// Infer audio format from MIME type. Returns `None` for unsupported types.
     pub fn from_mime_type(mime: &str) -> Option<Self> {
         let base = ironclaw_common::normalize_mime_type(mime);
@@ -65,17 +88,118 @@ pub enum TranscriptionError {
 
     #[error("Audio data is empty")]
     EmptyAudio,
+
+    #[error("Audio file too large: {size} bytes (max {max} bytes)")]
+    FileTooLarge { size: u64, max: u64 },
+
+    /// The provider is missing required configuration (binary, endpoint,
+    /// credentials). Not an audio problem: the middleware leaves the
+    /// attachment untranscribed instead of reporting a failure.
+    #[error("Transcription provider not configured: {0}")]
+    NotConfigured(String),
+}
+
+/// A span of transcribed speech.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptSegment {
+    /// Start offset from the beginning of the audio, in milliseconds.
+    pub start_ms: u64,
+    /// End offset in milliseconds. `0` means the end is unknown (the segment
+    /// runs to the end of the audio).
+    pub end_ms: u64,
+    pub text: String,
+}
+
+/// A transcript together with the spoken language the provider detected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedTranscript {
+    pub text: String,
+    /// Language reported by the provider (e.g. `"english"` or `"en"`, in the
+    /// provider's own naming). `None` when the provider does not detect it.
+    pub detected_language: Option<String>,
+}
+
+/// Render segments as one `[mm:ss] text` line each (`[h:mm:ss]` past an hour).
+pub fn format_timestamped_transcript(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| {
+            let total_secs = segment.start_ms / 1000;
+            let (hours, minutes, seconds) =
+                (total_secs / 3600, (total_secs / 60) % 60, total_secs % 60);
+            let text = segment.text.trim();
+            if hours > 0 {
+                format!("[{hours}:{minutes:02}:{seconds:02}] {text}")
+            } else {
+                format!("[{minutes:02}:{seconds:02}] {text}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn undetected(text: String) -> DetectedTranscript {
+    DetectedTranscript {
+        text,
+        detected_language: None,
+    }
 }
 
 /// Trait for speech-to-text providers.
 #[async_trait]
 pub trait TranscriptionProvider: Send + Sync {
+    /// Stable provider identifier for logs (e.g. `"openai_whisper"`).
+    fn name(&self) -> &'static str;
+
+    /// Largest audio payload in bytes the provider accepts, if limited.
+    fn max_file_size(&self) -> Option<u64> {
+        None
+    }
+
+    /// Audio formats the provider can transcribe.
+    fn supported_formats(&self) -> &'static [AudioFormat] {
+        &AudioFormat::ALL
+    }
+
     /// Transcribe audio bytes into text.
     async fn transcribe(
         &self,
         audio_data: &[u8],
         format: AudioFormat,
     ) -> Result<String, TranscriptionError>;
+
+    /// Transcribe audio and report the spoken language the provider detected.
+    ///
+    /// Providers without language detection return the plain transcript
+    /// with `detected_language: None`.
+    async fn transcribe_detect(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<DetectedTranscript, TranscriptionError> {
+        let text = self.transcribe(audio_data, format).await?;
+        Ok(DetectedTranscript {
+            text,
+            detected_language: None,
+        })
+    }
+
+    /// Transcribe audio into timestamped segments.
+    ///
+    /// Providers without segment support return the plain transcript as a
+    /// single segment spanning the whole audio.
+    async fn transcribe_verbose(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<Vec<TranscriptSegment>, TranscriptionError> {
+        let text = self.transcribe(audio_data, format).await?;
+        Ok(vec![TranscriptSegment {
+            start_ms: 0,
+            end_ms: 0,
+            text,
+        }])
+    }
 }
 
 /// Middleware that processes audio attachments on incoming messages.
@@ -83,34 +207,245 @@ pub trait TranscriptionProvider: Send + Sync {
 /// When an incoming message has audio attachments with inline data,
 /// the middleware transcribes them and sets `extracted_text` on the attachment.
 /// If the message has no text content, the transcription becomes the message content.
+///
+/// Providers are tried in order. Providers whose
+/// [`supported_formats`](TranscriptionProvider::supported_formats) exclude
+/// the input are skipped, and input over a provider's
+/// [`max_file_size`](TranscriptionProvider::max_file_size) that cannot be
+/// chunked moves on to the next one. A provider that fails transiently
+/// (`RequestFailed`), is `NotConfigured`, or rejects the audio itself
+/// (`UnsupportedFormat`, `FileTooLarge`) also hands the attachment on, since
+/// those limits differ per provider; only `EmptyAudio` stops the chain.
 pub struct TranscriptionMiddleware {
-    provider: Box<dyn TranscriptionProvider>,
+    providers: Vec<Arc<dyn TranscriptionProvider>>,
+    timestamps: bool,
+    detect_language: bool,
+    transcript_files: Option<TranscriptFiles>,
+    transcoder: Option<AudioTranscoder>,
+}
+
+/// Where full transcripts go and how much of each stays inline.
+struct TranscriptFiles {
+    store: Arc<dyn TranscriptStore>,
+    preview_chars: usize,
 }
 
 impl TranscriptionMiddleware {
     /// Create a new middleware with the given transcription provider.
     pub fn new(provider: Box<dyn TranscriptionProvider>) -> Self {
-        Self { provider }
+        Self::with_providers(vec![Arc::from(provider)])
+    }
+
+    /// Create a middleware with an ordered provider fallback chain.
+    pub fn with_providers(providers: Vec<Arc<dyn TranscriptionProvider>>) -> Self {
+        Self {
+            providers,
+            timestamps: false,
+            detect_language: false,
+            transcript_files: None,
+            transcoder: None,
+        }
+    }
+
+    /// Produce timestamped transcripts (see [`format_timestamped_transcript`])
+    /// via [`TranscriptionProvider::transcribe_verbose`] instead of plain text.
+    pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Ask providers for the spoken language via
+    /// [`TranscriptionProvider::transcribe_detect`] and log it with each
+    /// transcript. Off by default: detection can need a richer response
+    /// format than plain transcription (OpenAI's `verbose_json`).
+    pub fn with_language_detection(mut self, detect_language: bool) -> Self {
+        self.detect_language = detect_language;
+        self
+    }
+
+    /// Also write each full transcript through `store` and attach a pointer
+    /// to the file (a `text/plain` document attachment whose `local_path` is
+    /// the workspace path), keeping only the first `preview_chars`
+    /// characters inline in `extracted_text` and the message content.
+    ///
+    /// If the write fails the full transcript stays inline.
+    pub fn with_transcript_store(
+        mut self,
+        store: Arc<dyn TranscriptStore>,
+        preview_chars: usize,
+    ) -> Self {
+        self.transcript_files = Some(TranscriptFiles {
+            store,
+            preview_chars,
+        });
+        self
+    }
+
+    /// Convert audio in formats outside [`AudioFormat`] (AMR, 3GP, ...) to
+    /// WAV with `transcoder` before transcribing it, and decode compressed
+    /// audio over a provider's size limit so it can be chunked. Without a
+    /// transcoder such attachments are skipped or fail as too large; truly
+    /// unknown types are skipped either way.
+    pub fn with_transcoder(mut self, transcoder: AudioTranscoder) -> Self {
+        self.transcoder = Some(transcoder);
+        self
+    }
+
+    /// Run `audio_data` through the provider chain, returning the first
+    /// transcript or the error that ended the chain.
+    async fn transcribe_with_fallback(
+        &self,
+        attachment_id: &str,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<String, TranscriptionError> {
+        let size = audio_data.len() as u64;
+        let mut last_error = None;
+        for provider in &self.providers {
+            if !provider.supported_formats().contains(&format) {
+                tracing::debug!(
+                    attachment_id = %attachment_id,
+                    provider = provider.name(),
+                    format = format.extension(),
+                    "Transcription provider does not accept this format; trying next provider"
+                );
+                keep_most_relevant(
+                    &mut last_error,
+                    TranscriptionError::UnsupportedFormat {
+                        mime_type: format!("audio/{}", format.extension()),
+                    },
+                );
+                continue;
+            }
+            let result = match provider.max_file_size() {
+                Some(max) if size > max => self
+                    .transcribe_chunked(provider.as_ref(), audio_data, format, max)
+                    .await
+                    .map(undetected),
+                _ if self.timestamps => provider
+                    .transcribe_verbose(audio_data, format)
+                    .await
+                    .map(|segments| undetected(format_timestamped_transcript(&segments))),
+                _ if self.detect_language => provider.transcribe_detect(audio_data, format).await,
+                _ => provider
+                    .transcribe(audio_data, format)
+                    .await
+                    .map(undetected),
+            };
+            match result {
+                Ok(transcript) => {
+                    tracing::info!(
+                        attachment_id = %attachment_id,
+                        provider = provider.name(),
+                        language = transcript.detected_language.as_deref().unwrap_or("unknown"),
+                        "Transcribed audio attachment"
+                    );
+                    return Ok(transcript.text);
+                }
+                Err(e @ TranscriptionError::EmptyAudio) => return Err(e),
+                Err(e) => {
+                    tracing::warn!(
+                        attachment_id = %attachment_id,
+                        provider = provider.name(),
+                        error = %e,
+                        "Transcription provider failed; trying next provider"
+                    );
+                    keep_most_relevant(&mut last_error, e);
+                }
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            TranscriptionError::NotConfigured("no transcription providers".to_string())
+        }))
+    }
+
+    /// Transcribe audio larger than `max_bytes` by splitting it with
+    /// [`chunk_audio`] and transcribing the pieces in order. Compressed
+    /// formats are first decoded to WAV by the transcoder, when configured,
+    /// since only WAV can be split.
+    ///
+    /// Plain transcripts are joined with [`join_chunk_transcripts`]. In
+    /// timestamp mode, segment times are shifted to the original audio and
+    /// segments starting inside a chunk's leading overlap are dropped (the
+    /// previous chunk already produced them).
+    async fn transcribe_chunked(
+        &self,
+        provider: &dyn TranscriptionProvider,
+        audio_data: &[u8],
+        format: AudioFormat,
+        max_bytes: u64,
+    ) -> Result<String, TranscriptionError> {
+        let (chunks, format) = match (chunk_audio(audio_data, format, max_bytes), &self.transcoder)
+        {
+            (Err(TranscriptionError::FileTooLarge { .. }), Some(transcoder))
+                if format != AudioFormat::Wav =>
+            {
+                let wav = transcoder.decode(audio_data, format).await?;
+                (
+                    chunk_audio(&wav, AudioFormat::Wav, max_bytes)?,
+                    AudioFormat::Wav,
+                )
+            }
+            (chunks, _) => (chunks?, format),
+        };
+        if self.timestamps {
+            let mut segments = Vec::new();
+            for chunk in &chunks {
+                for segment in provider.transcribe_verbose(&chunk.data, format).await? {
+                    if segment.start_ms < chunk.overlap_ms {
+                        continue;
+                    }
+                    segments.push(TranscriptSegment {
+                        start_ms: chunk.start_ms + segment.start_ms,
+                        end_ms: if segment.end_ms == 0 {
+                            0
+                        } else {
+                            chunk.start_ms + segment.end_ms
+                        },
+                        text: segment.text,
+                    });
+                }
+            }
+            Ok(format_timestamped_transcript(&segments))
+        } else {
+            let mut parts = Vec::with_capacity(chunks.len());
+            for chunk in &chunks {
+                parts.push(provider.transcribe(&chunk.data, format).await?);
+            }
+            Ok(join_chunk_transcripts(&parts))
+        }
     }
 
     /// Transcribe any audio attachments with inline data.
     ///
     /// Modifies the slice in place:
-    /// - Sets `extracted_text` on audio attachments that have inline data.
-    /// - If `content` is empty (or the placeholder `[Voice note]`), replaces
-    ///   it with the first successful transcription.
+    /// - Sets `extracted_text` on every audio attachment that has inline
+    ///   data: the transcript, or a `[Transcription failed: ...]` fallback
+    ///   for that attachment alone (one bad voice note never drops the rest).
+    /// - If `content` is empty (or the placeholder `[Voice note]`) and at
+    ///   least one note transcribed, replaces it with the transcriptions. A
+    ///   lone transcript is used as-is; otherwise every attempted note,
+    ///   failures included, is listed in attachment order behind a
+    ///   `[Voice note N]` marker, where N is the note's position among the
+    ///   message's audio attachments.
+    ///
+    /// - With a transcript store (see [`Self::with_transcript_store`]),
+    ///   writes each successful transcript to a file, appends a text
+    ///   attachment pointing at it, and uses a short preview in place of
+    ///   the full transcript above.
     ///
     /// The signature deliberately takes the attachments and content
     /// separately rather than the channel-layer `IncomingMessage`, so this
     /// crate doesn't have to depend on channel transport types.
     pub async fn process(
         &self,
-        attachments: &mut [ironclaw_common::IncomingAttachment],
+        attachments: &mut Vec<ironclaw_common::IncomingAttachment>,
         content: &mut String,
     ) {
         use ironclaw_common::AttachmentKind;
 
-        let mut transcriptions = Vec::new();
+        // (attachment index, text, transcribed successfully)
+        let mut transcriptions: Vec<(usize, String, bool)> = Vec::new();
 
         for (i, attachment) in attachments.iter().enumerate() {
             if attachment.kind != AttachmentKind::Audio {
@@ -124,21 +459,49 @@ impl TranscriptionMiddleware {
                 continue;
             }
 
-            let format = match AudioFormat::from_mime_type(&attachment.mime_type) {
-                Some(f) => f,
-                None => {
-                    tracing::warn!(
-                        attachment_id = %attachment.id,
-                        mime = %attachment.mime_type,
-                        "Skipping audio attachment with unsupported format"
-                    );
-                    continue;
+            let result = match AudioFormat::from_mime_type(&attachment.mime_type) {
+                Some(format) => {
+                    self.transcribe_with_fallback(&attachment.id, &attachment.data, format)
+                        .await
                 }
+                None => match &self.transcoder {
+                    Some(transcoder) if AudioTranscoder::can_transcode(&attachment.mime_type) => {
+                        match transcoder
+                            .to_wav(&attachment.data, &attachment.mime_type)
+                            .await
+                        {
+                            Ok(wav) => {
+                                self.transcribe_with_fallback(
+                                    &attachment.id,
+                                    &wav,
+                                    AudioFormat::Wav,
+                                )
+                                .await
+                            }
+                            Err(e) => Err(e),
+                        }
+                    }
+                    _ => {
+                        tracing::warn!(
+                            attachment_id = %attachment.id,
+                            mime = %attachment.mime_type,
+                            "Skipping audio attachment with unsupported format"
+                        );
+                        continue;
+                    }
+                },
             };
 
-            match self.provider.transcribe(&attachment.data, format).await {
+            match result {
                 Ok(text) => {
-                    transcriptions.push((i, text));
+                    transcriptions.push((i, text, true));
+                }
+                Err(TranscriptionError::NotConfigured(reason)) => {
+                    tracing::warn!(
+                        attachment_id = %attachment.id,
+                        reason = %reason,
+                        "Transcription provider not configured; leaving audio untranscribed"
+                    );
                 }
                 Err(e) => {
                     tracing::error!(
@@ -146,26 +509,110 @@ impl TranscriptionMiddleware {
                         error = %e,
                         "Failed to transcribe audio attachment"
                     );
-                    transcriptions.push((i, format!("[Transcription failed: {}]", e)));
+                    transcriptions.push((i, format!("[Transcription failed: {}]", e), false));
                 }
             }
         }
 
-        for (i, text) in &transcriptions {
+        if let Some(files) = &self.transcript_files {
+            for (i, text, ok) in &mut transcriptions {
+                if !*ok {
+                    continue;
+                }
+                let id = attachments[*i].id.clone();
+                match files.store.save_transcript(&id, text).await {
+                    Ok(path) => {
+                        attachments.push(transcript_file_attachment(&id, &path, text));
+                        *text = transcript_preview(text, files.preview_chars, &path);
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            attachment_id = %id,
+                            error = %e,
+                            "Failed to write transcript file; keeping transcript inline"
+                        );
+                    }
+                }
+            }
+        }
+
+        for (i, text, _) in &transcriptions {
             attachments[*i].extracted_text = Some(text.clone());
         }
 
-        // If message has no text content, use the first successful transcription
+        // If message has no text content, use the transcriptions
         if (content.is_empty() || content == "[Voice note]")
-            && let Some((_, text)) = transcriptions
-                .iter()
-                .find(|(_, t)| !t.starts_with("[Transcription failed"))
+            && transcriptions.iter().any(|(_, _, ok)| *ok)
         {
-            *content = text.clone();
+            *content = match transcriptions.as_slice() {
+                [(_, only, _)] => only.clone(),
+                many => many
+                    .iter()
+                    .map(|(i, text, _)| {
+                        let position = attachments[..=*i]
+                            .iter()
+                            .filter(|attachment| attachment.kind == AttachmentKind::Audio)
+                            .count();
+                        format!("[Voice note {position}] {text}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+            };
         }
     }
 }
 
+/// Keep whichever of `last` and `error` the attachment should report if no
+/// provider succeeds: a real request failure outranks a provider rejecting
+/// the audio, which outranks a provider that is merely not configured.
+fn keep_most_relevant(last: &mut Option<TranscriptionError>, error: TranscriptionError) {
+    fn rank(error: &TranscriptionError) -> u8 {
+        match error {
+            TranscriptionError::RequestFailed(_) => 3,
+            TranscriptionError::UnsupportedFormat { .. }
+            | TranscriptionError::FileTooLarge { .. }
+            | TranscriptionError::EmptyAudio => 2,
+            TranscriptionError::NotConfigured(_) => 1,
+        }
+    }
+    if last.as_ref().is_none_or(|last| rank(&error) > rank(last)) {
+        *last = Some(error);
+    }
+}
+
+/// Text attachment pointing the agent at a transcript written to `path`.
+fn transcript_file_attachment(
+    audio_id: &str,
+    path: &str,
+    transcript: &str,
+) -> ironclaw_common::IncomingAttachment {
+    ironclaw_common::IncomingAttachment {
+        id: format!("{audio_id}-transcript"),
+        kind: ironclaw_common::AttachmentKind::Document,
+        mime_type: "text/plain".to_string(),
+        filename: path.rsplit('/').next().map(str::to_string),
+        size_bytes: Some(transcript.len() as u64),
+        source_url: None,
+        storage_key: None,
+        local_path: Some(path.to_string()),
+        extracted_text: None,
+        data: Vec::new(),
+        duration_secs: None,
+    }
+}
+
+/// The first `max_chars` characters of `transcript`, followed by a pointer
+/// to the full file when anything was cut.
+fn transcript_preview(transcript: &str, max_chars: usize, path: &str) -> String {
+    match transcript.char_indices().nth(max_chars) {
+        None => transcript.to_string(),
+        Some((end, _)) => format!(
+            "{}… [full transcript: {path}]",
+            transcript[..end].trim_end()
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +648,10 @@ mod tests {
 
     #[async_trait]
     impl TranscriptionProvider for MockProvider {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
         async fn transcribe(
             &self,
             _audio_data: &[u8],
@@ -213,6 +664,103 @@ mod tests {
         }
     }
 
+    /// Echoes the audio bytes back as the transcript; `b"fail"` errors.
+    struct EchoProvider;
+
+    #[async_trait]
+    impl TranscriptionProvider for EchoProvider {
+        fn name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn max_file_size(&self) -> Option<u64> {
+            Some(16)
+        }
+
+        async fn transcribe(
+            &self,
+            audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<String, TranscriptionError> {
+            if audio_data == b"fail" {
+                return Err(TranscriptionError::RequestFailed("mock error".into()));
+            }
+            Ok(String::from_utf8_lossy(audio_data).into_owned())
+        }
+    }
+
+    /// Counts calls and always fails with the given error.
+    struct FailingProvider {
+        calls: std::sync::atomic::AtomicUsize,
+        error: fn() -> TranscriptionError,
+    }
+
+    impl FailingProvider {
+        fn new(error: fn() -> TranscriptionError) -> Arc<Self> {
+            Arc::new(Self {
+                calls: std::sync::atomic::AtomicUsize::new(0),
+                error,
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl TranscriptionProvider for FailingProvider {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn transcribe(
+            &self,
+            _audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<String, TranscriptionError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Err((self.error)())
+        }
+    }
+
+    /// Returns two fixed segments from `transcribe_verbose`.
+    struct SegmentedProvider;
+
+    #[async_trait]
+    impl TranscriptionProvider for SegmentedProvider {
+        fn name(&self) -> &'static str {
+            "segmented"
+        }
+
+        async fn transcribe(
+            &self,
+            _audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<String, TranscriptionError> {
+            Ok("intro outro".to_string())
+        }
+
+        async fn transcribe_verbose(
+            &self,
+            _audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<Vec<TranscriptSegment>, TranscriptionError> {
+            Ok(vec![
+                TranscriptSegment {
+                    start_ms: 0,
+                    end_ms: 4_200,
+                    text: " intro".to_string(),
+                },
+                TranscriptSegment {
+                    start_ms: 3_725_000,
+                    end_ms: 3_730_000,
+                    text: "outro ".to_string(),
+                },
+            ])
+        }
+    }
+
     fn voice_attachment(data: Vec<u8>) -> IncomingAttachment {
         IncomingAttachment {
             id: "voice_123".to_string(),
@@ -247,6 +795,376 @@ mod tests {
         assert_eq!(content, "Hello world");
     }
 
+    #[tokio::test]
+    async fn middleware_leaves_audio_untranscribed_when_provider_not_configured() {
+        let middleware = TranscriptionMiddleware::new(Box::new(WhisperCppProvider::new()));
+
+        let mut attachments = vec![voice_attachment(vec![1, 2, 3])];
+        let mut content = "[Voice note]".to_string();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert!(attachments[0].extracted_text.is_none());
+        assert_eq!(content, "[Voice note]");
+    }
+
+    #[tokio::test]
+    async fn middleware_transcribes_every_audio_attachment() {
+        let middleware = TranscriptionMiddleware::new(Box::new(EchoProvider));
+
+        let mut attachments = vec![
+            voice_attachment(b"first".to_vec()),
+            voice_attachment(b"fail".to_vec()),
+            voice_attachment(b"this one is far too long".to_vec()),
+            voice_attachment(b"second".to_vec()),
+        ];
+        let mut content = "[Voice note]".to_string();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert_eq!(attachments[0].extracted_text.as_deref(), Some("first"));
+        assert_eq!(
+            attachments[1].extracted_text.as_deref(),
+            Some("[Transcription failed: Transcription request failed: mock error]")
+        );
+        assert_eq!(
+            attachments[2].extracted_text.as_deref(),
+            Some("[Transcription failed: Audio file too large: 24 bytes (max 16 bytes)]")
+        );
+        assert_eq!(attachments[3].extracted_text.as_deref(), Some("second"));
+        assert_eq!(
+            content,
+            "[Voice note 1] first\n\n\
+             [Voice note 2] [Transcription failed: Transcription request failed: mock error]\n\n\
+             [Voice note 3] [Transcription failed: Audio file too large: 24 bytes (max 16 bytes)]\n\n\
+             [Voice note 4] second"
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_falls_back_to_next_provider_on_request_failure() {
+        let down = FailingProvider::new(|| TranscriptionError::RequestFailed("503".into()));
+        let unconfigured = FailingProvider::new(|| TranscriptionError::NotConfigured("x".into()));
+        let middleware = TranscriptionMiddleware::with_providers(vec![
+            down.clone(),
+            unconfigured.clone(),
+            Arc::new(EchoProvider),
+        ]);
+
+        let mut attachments = vec![voice_attachment(b"hello".to_vec())];
+        let mut content = String::new();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert_eq!(down.calls(), 1);
+        assert_eq!(unconfigured.calls(), 1);
+        assert_eq!(content, "hello");
+    }
+
+    #[tokio::test]
+    async fn middleware_tries_next_provider_on_per_provider_input_errors() {
+        let unsupported = FailingProvider::new(|| TranscriptionError::UnsupportedFormat {
+            mime_type: "audio/ogg".into(),
+        });
+        let too_large =
+            FailingProvider::new(|| TranscriptionError::FileTooLarge { size: 5, max: 1 });
+        let middleware = TranscriptionMiddleware::with_providers(vec![
+            unsupported.clone(),
+            too_large.clone(),
+            Arc::new(EchoProvider),
+        ]);
+        let mut attachments = vec![voice_attachment(b"hello".to_vec())];
+        let mut content = String::new();
+        middleware.process(&mut attachments, &mut content).await;
+        assert_eq!(unsupported.calls(), 1);
+        assert_eq!(too_large.calls(), 1);
+        assert_eq!(content, "hello");
+
+        // When every provider rejects the audio, that rejection is reported.
+        let unconfigured = FailingProvider::new(|| TranscriptionError::NotConfigured("x".into()));
+        let middleware = TranscriptionMiddleware::with_providers(vec![
+            unsupported.clone(),
+            unconfigured.clone(),
+        ]);
+        let mut attachments = vec![voice_attachment(b"hello".to_vec())];
+        let mut content = String::new();
+        middleware.process(&mut attachments, &mut content).await;
+        assert_eq!(unconfigured.calls(), 1);
+        assert_eq!(
+            attachments[0].extracted_text.as_deref(),
+            Some("[Transcription failed: Unsupported audio format: audio/ogg]")
+        );
+        assert!(content.is_empty());
+    }
+
+    #[tokio::test]
+    async fn middleware_skips_providers_that_cannot_take_the_input() {
+        // whisper.cpp without ffmpeg only accepts WAV: an OGG note must
+        // reach the next provider without whisper.cpp being run at all.
+        let wav_only = WhisperCppProvider::new()
+            .with_binary("/nonexistent/whisper-cli")
+            .with_model("/nonexistent/model.bin");
+        let counted = FailingProvider::new(|| TranscriptionError::RequestFailed("503".into()));
+        let middleware = TranscriptionMiddleware::with_providers(vec![
+            Arc::new(wav_only),
+            Arc::new(MockProvider {
+                result: Ok("from the cloud".to_string()),
+            }),
+        ]);
+        let mut attachments = vec![voice_attachment(vec![1, 2, 3])];
+        let mut content = String::new();
+        middleware.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "from the cloud");
+
+        // OGG over EchoProvider's 16-byte limit cannot be chunked, so the
+        // larger-limit provider after it gets the note.
+        let middleware = TranscriptionMiddleware::with_providers(vec![
+            Arc::new(EchoProvider),
+            Arc::new(MockProvider {
+                result: Ok("long note".to_string()),
+            }),
+            counted.clone(),
+        ]);
+        let mut attachments = vec![voice_attachment(vec![7; 64])];
+        let mut content = String::new();
+        middleware.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "long note");
+        assert_eq!(counted.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn middleware_attaches_timestamped_transcript_when_enabled() {
+        let plain = TranscriptionMiddleware::new(Box::new(SegmentedProvider));
+        let mut attachments = vec![voice_attachment(vec![1])];
+        let mut content = String::new();
+        plain.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "intro outro");
+
+        let timestamped =
+            TranscriptionMiddleware::new(Box::new(SegmentedProvider)).with_timestamps(true);
+        let mut attachments = vec![voice_attachment(vec![1])];
+        let mut content = String::new();
+        timestamped.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "[00:00] intro\n[1:02:05] outro");
+        assert_eq!(
+            attachments[0].extracted_text.as_deref(),
+            Some(content.as_str())
+        );
+    }
+
+    /// Reports a fixed detected language alongside its transcript.
+    struct DetectingProvider;
+
+    #[async_trait]
+    impl TranscriptionProvider for DetectingProvider {
+        fn name(&self) -> &'static str {
+            "detecting"
+        }
+
+        async fn transcribe(
+            &self,
+            _audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<String, TranscriptionError> {
+            Ok("bonjour".to_string())
+        }
+
+        async fn transcribe_detect(
+            &self,
+            audio_data: &[u8],
+            format: AudioFormat,
+        ) -> Result<DetectedTranscript, TranscriptionError> {
+            Ok(DetectedTranscript {
+                text: self.transcribe(audio_data, format).await?,
+                detected_language: Some("french".to_string()),
+            })
+        }
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn middleware_logs_detected_language() {
+        let plain = TranscriptionMiddleware::new(Box::new(DetectingProvider));
+        let mut attachments = vec![voice_attachment(vec![1])];
+        let mut content = String::new();
+        plain.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "bonjour");
+        assert!(!logs_contain("language=\"french\""));
+
+        let detecting =
+            TranscriptionMiddleware::new(Box::new(DetectingProvider)).with_language_detection(true);
+        let mut attachments = vec![voice_attachment(vec![1])];
+        let mut content = String::new();
+        detecting.process(&mut attachments, &mut content).await;
+        assert_eq!(content, "bonjour");
+        assert!(logs_contain("language=\"french\""));
+    }
+
+    #[tokio::test]
+    async fn default_detection_reports_no_language() {
+        let transcript = EchoProvider
+            .transcribe_detect(b"hello", AudioFormat::Ogg)
+            .await
+            .unwrap();
+        assert_eq!(
+            transcript,
+            DetectedTranscript {
+                text: "hello".to_string(),
+                detected_language: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn default_verbose_transcription_is_a_single_segment() {
+        let segments = EchoProvider
+            .transcribe_verbose(b"whole clip", AudioFormat::Ogg)
+            .await
+            .unwrap();
+        assert_eq!(
+            segments,
+            vec![TranscriptSegment {
+                start_ms: 0,
+                end_ms: 0,
+                text: "whole clip".to_string(),
+            }]
+        );
+    }
+
+    /// Transcribes each chunk as "word<N> shared" / "shared word<N+1>" so
+    /// consecutive chunks repeat one word across the overlap.
+    struct ChunkCountingProvider {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TranscriptionProvider for ChunkCountingProvider {
+        fn name(&self) -> &'static str {
+            "chunk-counting"
+        }
+
+        fn max_file_size(&self) -> Option<u64> {
+            Some(5_044)
+        }
+
+        async fn transcribe(
+            &self,
+            audio_data: &[u8],
+            _format: AudioFormat,
+        ) -> Result<String, TranscriptionError> {
+            assert!(audio_data.len() <= 5_044);
+            let n = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(if n == 0 {
+                "w0 w1".to_string()
+            } else {
+                format!("w{n} w{}", n + 1)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_chunks_wav_over_provider_limit() {
+        let provider = Arc::new(ChunkCountingProvider {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let middleware = TranscriptionMiddleware::with_providers(vec![provider.clone()]);
+
+        let mut attachment = voice_attachment(chunking::test_wav(10_000));
+        attachment.mime_type = "audio/wav".to_string();
+        let mut attachments = vec![attachment];
+        let mut content = String::new();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::SeqCst), 6);
+        assert_eq!(content, "w0 w1 w2 w3 w4 w5 w6");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn middleware_decodes_compressed_audio_over_provider_limit_to_chunk_it() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let decoded = dir.path().join("decoded.wav");
+        std::fs::write(&decoded, chunking::test_wav(10_000)).unwrap();
+        let ffmpeg = dir.path().join("ffmpeg");
+        // Stand-in decoder that always yields the same 10 s WAV.
+        std::fs::write(
+            &ffmpeg,
+            format!(
+                "#!/bin/sh\nfor last; do :; done\ncp {} \"$last\"\n",
+                decoded.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&ffmpeg, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let provider = Arc::new(ChunkCountingProvider {
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        });
+        let ogg = || voice_attachment(vec![7; 6_000]);
+
+        let without = TranscriptionMiddleware::with_providers(vec![provider.clone()]);
+        let mut attachments = vec![ogg()];
+        let mut content = String::new();
+        without.process(&mut attachments, &mut content).await;
+        assert_eq!(
+            attachments[0].extracted_text.as_deref(),
+            Some("[Transcription failed: Audio file too large: 6000 bytes (max 5044 bytes)]")
+        );
+
+        let with = TranscriptionMiddleware::with_providers(vec![provider.clone()])
+            .with_transcoder(AudioTranscoder::new(&ffmpeg));
+        let mut attachments = vec![ogg()];
+        let mut content = String::new();
+        with.process(&mut attachments, &mut content).await;
+        assert_eq!(provider.calls.load(std::sync::atomic::Ordering::SeqCst), 6);
+        assert_eq!(content, "w0 w1 w2 w3 w4 w5 w6");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn middleware_transcodes_formats_providers_do_not_accept() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let ffmpeg = dir.path().join("ffmpeg");
+        // Stand-in that copies the `-i` input to the output path.
+        std::fs::write(
+            &ffmpeg,
+            "#!/bin/sh\nfor last; do :; done\nwhile [ \"$1\" != \"-i\" ]; do shift; done\ncp \"$2\" \"$last\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&ffmpeg, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let amr = || IncomingAttachment {
+            mime_type: "audio/amr".to_string(),
+            ..voice_attachment(b"amr voice".to_vec())
+        };
+
+        let plain = TranscriptionMiddleware::new(Box::new(EchoProvider));
+        let mut attachments = vec![amr()];
+        let mut content = String::new();
+        plain.process(&mut attachments, &mut content).await;
+        assert_eq!(attachments[0].extracted_text, None);
+
+        let transcoding = TranscriptionMiddleware::new(Box::new(EchoProvider))
+            .with_transcoder(AudioTranscoder::new(&ffmpeg));
+        let mut attachments = vec![amr()];
+        let mut content = String::new();
+        transcoding.process(&mut attachments, &mut content).await;
+        assert_eq!(attachments[0].extracted_text.as_deref(), Some("amr voice"));
+        assert_eq!(content, "amr voice");
+
+        // Without ffmpeg installed the attachment degrades to untranscribed.
+        let missing = TranscriptionMiddleware::new(Box::new(EchoProvider))
+            .with_transcoder(AudioTranscoder::new(dir.path().join("no-ffmpeg")));
+        let mut attachments = vec![amr()];
+        let mut content = String::new();
+        missing.process(&mut attachments, &mut content).await;
+        assert_eq!(attachments[0].extracted_text, None);
+        assert!(content.is_empty());
+    }
+
     #[tokio::test]
     async fn middleware_skips_empty_audio_data() {
         let middleware = TranscriptionMiddleware::new(Box::new(MockProvider {
@@ -282,6 +1200,99 @@ mod tests {
         );
     }
 
+    /// Records saved transcripts; ids starting with `fail` error.
+    #[derive(Default)]
+    struct RecordingStore {
+        saved: std::sync::Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait]
+    impl TranscriptStore for RecordingStore {
+        async fn save_transcript(
+            &self,
+            attachment_id: &str,
+            transcript: &str,
+        ) -> std::io::Result<String> {
+            if attachment_id.starts_with("fail") {
+                return Err(std::io::Error::other("disk full"));
+            }
+            self.saved
+                .lock()
+                .unwrap()
+                .push((attachment_id.to_string(), transcript.to_string()));
+            Ok(format!("transcripts/{attachment_id}.txt"))
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_writes_transcript_file_and_keeps_preview() {
+        let store = Arc::new(RecordingStore::default());
+        let middleware = TranscriptionMiddleware::new(Box::new(MockProvider {
+            result: Ok("Hello world, this is a long voice note".to_string()),
+        }))
+        .with_transcript_store(store.clone(), 11);
+
+        let mut attachments = vec![voice_attachment(vec![1, 2, 3])];
+        let mut content = "[Voice note]".to_string();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert_eq!(
+            *store.saved.lock().unwrap(),
+            vec![(
+                "voice_123".to_string(),
+                "Hello world, this is a long voice note".to_string()
+            )]
+        );
+        assert_eq!(
+            content,
+            "Hello world… [full transcript: transcripts/voice_123.txt]"
+        );
+        assert_eq!(
+            attachments[0].extracted_text.as_deref(),
+            Some(content.as_str())
+        );
+
+        assert_eq!(attachments.len(), 2);
+        let pointer = &attachments[1];
+        assert_eq!(pointer.id, "voice_123-transcript");
+        assert_eq!(pointer.kind, AttachmentKind::Document);
+        assert_eq!(pointer.mime_type, "text/plain");
+        assert_eq!(pointer.filename.as_deref(), Some("voice_123.txt"));
+        assert_eq!(
+            pointer.local_path.as_deref(),
+            Some("transcripts/voice_123.txt")
+        );
+        assert_eq!(pointer.size_bytes, Some(38));
+    }
+
+    #[tokio::test]
+    async fn middleware_keeps_transcript_inline_when_file_write_fails() {
+        let middleware = TranscriptionMiddleware::new(Box::new(MockProvider {
+            result: Ok("Hello world, this is a long voice note".to_string()),
+        }))
+        .with_transcript_store(Arc::new(RecordingStore::default()), 11);
+
+        let mut attachment = voice_attachment(vec![1, 2, 3]);
+        attachment.id = "fail_1".to_string();
+        let mut attachments = vec![attachment];
+        let mut content = String::new();
+
+        middleware.process(&mut attachments, &mut content).await;
+
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(content, "Hello world, this is a long voice note");
+    }
+
+    #[test]
+    fn transcript_preview_cuts_on_char_boundary() {
+        assert_eq!(transcript_preview("héllo", 5, "t.txt"), "héllo");
+        assert_eq!(
+            transcript_preview("héllo there", 5, "t.txt"),
+            "héllo… [full transcript: t.txt]"
+        );
+    }
+
     #[tokio::test]
     async fn middleware_preserves_existing_content() {
         let middleware = TranscriptionMiddleware::new(Box::new(MockProvider {
@@ -3,8 +3,14 @@
 use async_trait::async_trait;
 use reqwest::multipart;
 use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
 
-use super::{AudioFormat, TranscriptionError, TranscriptionProvider};
+use super::{
+    AudioFormat, DetectedTranscript, TranscriptSegment, TranscriptionError, TranscriptionProvider,
+};
+
+/// Upload limit of the `/v1/audio/transcriptions` endpoint (25 MB).
+const OPENAI_MAX_FILE_SIZE: u64 = 25 * 1024 * 1024;
 
 /// OpenAI Whisper speech-to-text provider.
 ///
@@ -58,15 +64,21 @@ impl OpenAiWhisperProvider {
         self.model = model.into();
         self
     }
-}
 
-#[async_trait]
-impl TranscriptionProvider for OpenAiWhisperProvider {
-    async fn transcribe(
+    /// Only the `whisper-*` models answer `verbose_json`; the GPT-4o
+    /// transcribe models reject it, so they get the plain-text path.
+    fn supports_verbose_json(&self) -> bool {
+        self.model.starts_with("whisper")
+    }
+
+    /// POST the audio to `/v1/audio/transcriptions` and return the
+    /// successful response. `verbose_json` also requests segment timestamps.
+    async fn send(
         &self,
         audio_data: &[u8],
         format: AudioFormat,
-    ) -> Result<String, TranscriptionError> {
+        response_format: &str,
+    ) -> Result<reqwest::Response, TranscriptionError> {
         if audio_data.is_empty() {
             return Err(TranscriptionError::EmptyAudio);
         }
@@ -87,10 +99,13 @@ impl TranscriptionProvider for OpenAiWhisperProvider {
             .mime_str(mime_str)
             .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
 
-        let form = multipart::Form::new()
+        let mut form = multipart::Form::new()
             .text("model", self.model.clone())
-            .text("response_format", "text")
-            .part("file", file_part);
+            .text("response_format", response_format.to_string());
+        if response_format == "verbose_json" {
+            form = form.text("timestamp_granularities[]", "segment");
+        }
+        let form = form.part("file", file_part);
 
         let url = format!("{}/v1/audio/transcriptions", self.base_url);
 
@@ -117,7 +132,71 @@ impl TranscriptionProvider for OpenAiWhisperProvider {
                 status, body
             )));
         }
+        Ok(response)
+    }
+}
+
+/// `verbose_json` response body (only the fields we use).
+#[derive(Debug, Deserialize)]
+struct VerboseTranscription {
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    duration: Option<f64>,
+    /// Language Whisper detected (or was told), e.g. `"english"`.
+    #[serde(default)]
+    language: Option<String>,
+    #[serde(default)]
+    segments: Vec<VerboseSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerboseSegment {
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+fn secs_to_ms(secs: f64) -> u64 {
+    (secs.max(0.0) * 1000.0).round() as u64
+}
 
+impl VerboseTranscription {
+    fn into_segments(self) -> Vec<TranscriptSegment> {
+        if self.segments.is_empty() {
+            return vec![TranscriptSegment {
+                start_ms: 0,
+                end_ms: self.duration.map(secs_to_ms).unwrap_or(0),
+                text: self.text.trim().to_string(),
+            }];
+        }
+        self.segments
+            .into_iter()
+            .map(|segment| TranscriptSegment {
+                start_ms: secs_to_ms(segment.start),
+                end_ms: secs_to_ms(segment.end),
+                text: segment.text.trim().to_string(),
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAiWhisperProvider {
+    fn name(&self) -> &'static str {
+        "openai_whisper"
+    }
+
+    fn max_file_size(&self) -> Option<u64> {
+        Some(OPENAI_MAX_FILE_SIZE)
+    }
+
+    async fn transcribe(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<String, TranscriptionError> {
+        let response = self.send(audio_data, format, "text").await?;
         let text = response
             .text()
             .await
@@ -125,4 +204,161 @@ impl TranscriptionProvider for OpenAiWhisperProvider {
 
         Ok(text.trim().to_string())
     }
+
+    async fn transcribe_detect(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<DetectedTranscript, TranscriptionError> {
+        if !self.supports_verbose_json() {
+            return Ok(DetectedTranscript {
+                text: self.transcribe(audio_data, format).await?,
+                detected_language: None,
+            });
+        }
+        let response = self.send(audio_data, format, "verbose_json").await?;
+        let verbose: VerboseTranscription = response
+            .json()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        Ok(DetectedTranscript {
+            text: verbose.text.trim().to_string(),
+            detected_language: verbose.language.filter(|language| !language.is_empty()),
+        })
+    }
+
+    async fn transcribe_verbose(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<Vec<TranscriptSegment>, TranscriptionError> {
+        if !self.supports_verbose_json() {
+            return Ok(vec![TranscriptSegment {
+                start_ms: 0,
+                end_ms: 0,
+                text: self.transcribe(audio_data, format).await?,
+            }]);
+        }
+        let response = self.send(audio_data, format, "verbose_json").await?;
+        let verbose: VerboseTranscription = response
+            .json()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        Ok(verbose.into_segments())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbose_response_maps_segments_to_milliseconds() {
+        let verbose: VerboseTranscription = serde_json::from_value(serde_json::json!({
+            "text": "Hello there. General Kenobi.",
+            "duration": 4.2,
+            "segments": [
+                {"id": 0, "start": 0.0, "end": 1.5, "text": " Hello there."},
+                {"id": 1, "start": 1.5, "end": 4.2, "text": " General Kenobi."}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            verbose.into_segments(),
+            vec![
+                TranscriptSegment {
+                    start_ms: 0,
+                    end_ms: 1_500,
+                    text: "Hello there.".to_string(),
+                },
+                TranscriptSegment {
+                    start_ms: 1_500,
+                    end_ms: 4_200,
+                    text: "General Kenobi.".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn verbose_response_carries_detected_language() {
+        let verbose: VerboseTranscription = serde_json::from_value(serde_json::json!({
+            "text": "Bonjour.",
+            "language": "french",
+            "duration": 1.0
+        }))
+        .unwrap();
+        assert_eq!(verbose.language.as_deref(), Some("french"));
+
+        let verbose: VerboseTranscription =
+            serde_json::from_value(serde_json::json!({"text": "hi"})).unwrap();
+        assert_eq!(verbose.language, None);
+    }
+
+    /// Serve one canned `text/plain` response and hand back the raw request.
+    async fn serve_once(body: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            // The multipart body ends with its closing boundary (`--\r\n`),
+            // or with the last chunk when sent chunked.
+            while !(request.ends_with(b"--\r\n") || request.ends_with(b"0\r\n\r\n")) {
+                let n = stream.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn gpt4o_models_detect_through_the_plain_text_path() {
+        let (url, request) = serve_once("  bonjour  ").await;
+        let provider = OpenAiWhisperProvider::new(SecretString::from("test-key".to_string()))
+            .with_base_url(url)
+            .with_model("gpt-4o-transcribe");
+
+        let transcript = provider
+            .transcribe_detect(b"audio", AudioFormat::Ogg)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transcript,
+            DetectedTranscript {
+                text: "bonjour".to_string(),
+                detected_language: None,
+            }
+        );
+        let request = request.await.unwrap();
+        assert!(request.contains("gpt-4o-transcribe"), "{request}");
+        assert!(!request.contains("verbose_json"), "{request}");
+    }
+
+    #[test]
+    fn verbose_response_without_segments_spans_whole_audio() {
+        let verbose: VerboseTranscription =
+            serde_json::from_value(serde_json::json!({"text": " hi ", "duration": 2.0})).unwrap();
+        assert_eq!(
+            verbose.into_segments(),
+            vec![TranscriptSegment {
+                start_ms: 0,
+                end_ms: 2_000,
+                text: "hi".to_string(),
+            }]
+        );
+    }
 }
@@ -0,0 +1,103 @@
+//! Persisting full transcripts as workspace text files.
+//!
+//! Long voice notes make for long prompts. With a [`TranscriptStore`]
+//! configured, [`super::TranscriptionMiddleware`] writes the full transcript
+//! through the store and keeps only a short preview inline; the agent reads
+//! the file when it needs the rest.
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+
+/// Destination for full transcripts.
+#[async_trait]
+pub trait TranscriptStore: Send + Sync {
+    /// Write `transcript` for the audio attachment `attachment_id`, returning
+    /// the workspace-relative path the agent can read it back from.
+    async fn save_transcript(
+        &self,
+        attachment_id: &str,
+        transcript: &str,
+    ) -> std::io::Result<String>;
+}
+
+/// Writes transcripts to `<root>/<dir>/<attachment id>.txt`.
+pub struct WorkspaceTranscriptStore {
+    root: PathBuf,
+    dir: String,
+}
+
+impl WorkspaceTranscriptStore {
+    /// Default workspace-relative directory for transcript files.
+    pub const DEFAULT_DIR: &'static str = "transcripts";
+
+    /// Store transcripts under [`Self::DEFAULT_DIR`] in the workspace `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            dir: Self::DEFAULT_DIR.to_string(),
+        }
+    }
+
+    /// Use a different workspace-relative directory.
+    pub fn with_dir(mut self, dir: impl Into<String>) -> Self {
+        self.dir = dir.into();
+        self
+    }
+}
+
+#[async_trait]
+impl TranscriptStore for WorkspaceTranscriptStore {
+    async fn save_transcript(
+        &self,
+        attachment_id: &str,
+        transcript: &str,
+    ) -> std::io::Result<String> {
+        let relative = format!("{}/{}.txt", self.dir, transcript_file_stem(attachment_id));
+        tokio::fs::create_dir_all(self.root.join(&self.dir)).await?;
+        tokio::fs::write(self.root.join(&relative), transcript).await?;
+        Ok(relative)
+    }
+}
+
+/// Channel attachment ids are opaque tokens; keep only characters that are
+/// safe in a file name so an id can never escape the transcript directory.
+fn transcript_file_stem(attachment_id: &str) -> String {
+    let stem: String = attachment_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if stem.is_empty() {
+        "voice_note".to_string()
+    } else {
+        stem
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn workspace_store_writes_under_transcript_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let store = WorkspaceTranscriptStore::new(root.path());
+
+        let path = store
+            .save_transcript("../voice:123", "full text")
+            .await
+            .unwrap();
+
+        assert_eq!(path, "transcripts/___voice_123.txt");
+        assert_eq!(
+            std::fs::read_to_string(root.path().join(&path)).unwrap(),
+            "full text"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Converting audio no provider accepts into WAV.
+//!
+//! Channels deliver voice in formats outside [`AudioFormat`] (AMR from
+//! phones, 3GP from older messengers, AIFF/CAF from Apple devices). With an
+//! [`AudioTranscoder`] configured, [`super::TranscriptionMiddleware`] runs
+//! those through `ffmpeg` into 16 kHz mono WAV, which every provider accepts
+//! and [`super::chunk_audio`] can split. Without one they are skipped as
+//! before.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use tokio::process::Command;
+
+use super::{AudioFormat, TranscriptionError};
+
+/// Maximum stderr bytes carried into an error message.
+const MAX_STDERR_IN_ERROR: usize = 512;
+
+/// MIME types `ffmpeg` decodes that [`super::AudioFormat`] does not cover.
+const TRANSCODABLE_MIME_TYPES: &[(&str, &str)] = &[
+    ("audio/amr", "amr"),
+    ("audio/amr-wb", "awb"),
+    ("audio/3gpp", "3gp"),
+    ("audio/3gpp2", "3g2"),
+    ("audio/aiff", "aiff"),
+    ("audio/x-aiff", "aiff"),
+    ("audio/x-caf", "caf"),
+    ("audio/x-ms-wma", "wma"),
+    ("audio/basic", "au"),
+    ("audio/x-matroska", "mka"),
+];
+
+/// `ffmpeg`-backed converter from [`TRANSCODABLE_MIME_TYPES`] and compressed
+/// [`AudioFormat`]s to WAV.
+#[derive(Debug, Clone)]
+pub struct AudioTranscoder {
+    ffmpeg: PathBuf,
+}
+
+impl AudioTranscoder {
+    /// Convert with the `ffmpeg` binary at `ffmpeg`.
+    pub fn new(ffmpeg: impl Into<PathBuf>) -> Self {
+        Self {
+            ffmpeg: ffmpeg.into(),
+        }
+    }
+
+    /// Whether `mime` is a format this transcoder knows how to convert.
+    pub fn can_transcode(mime: &str) -> bool {
+        input_extension(mime).is_some()
+    }
+
+    /// Convert `audio_data` of type `mime` to 16 kHz mono PCM WAV.
+    ///
+    /// Returns [`TranscriptionError::UnsupportedFormat`] for MIME types
+    /// outside the known set and [`TranscriptionError::NotConfigured`] when
+    /// the `ffmpeg` binary cannot be started, so a deployment without it
+    /// leaves the attachment untranscribed instead of reporting a failure.
+    pub async fn to_wav(
+        &self,
+        audio_data: &[u8],
+        mime: &str,
+    ) -> Result<Vec<u8>, TranscriptionError> {
+        let extension =
+            input_extension(mime).ok_or_else(|| TranscriptionError::UnsupportedFormat {
+                mime_type: mime.to_string(),
+            })?;
+        self.convert(audio_data, extension).await
+    }
+
+    /// Decode audio in one of the [`AudioFormat`]s to 16 kHz mono PCM WAV,
+    /// so compressed audio over a provider's upload limit can be split by
+    /// [`super::chunk_audio`].
+    pub async fn decode(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<Vec<u8>, TranscriptionError> {
+        self.convert(audio_data, format.extension()).await
+    }
+
+    async fn convert(
+        &self,
+        audio_data: &[u8],
+        extension: &str,
+    ) -> Result<Vec<u8>, TranscriptionError> {
+        if audio_data.is_empty() {
+            return Err(TranscriptionError::EmptyAudio);
+        }
+
+        let work_dir =
+            std::env::temp_dir().join(format!("ironclaw-transcode-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir(&work_dir)
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let result = self.to_wav_in(&work_dir, audio_data, extension).await;
+        if let Err(e) = tokio::fs::remove_dir_all(&work_dir).await {
+            tracing::warn!(
+                path = %work_dir.display(),
+                error = %e,
+                "Failed to clean up transcode work directory"
+            );
+        }
+        result
+    }
+
+    async fn to_wav_in(
+        &self,
+        work_dir: &Path,
+        audio_data: &[u8],
+        extension: &str,
+    ) -> Result<Vec<u8>, TranscriptionError> {
+        // Containers like 3GP keep their index at the end, so ffmpeg needs a
+        // seekable file rather than a pipe.
+        let input = work_dir.join(format!("input.{extension}"));
+        tokio::fs::write(&input, audio_data)
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let wav = work_dir.join("converted.wav");
+        run_audio_command(
+            &self.ffmpeg,
+            &[
+                "-nostdin".as_ref(),
+                "-loglevel".as_ref(),
+                "error".as_ref(),
+                "-i".as_ref(),
+                input.as_os_str(),
+                "-ar".as_ref(),
+                "16000".as_ref(),
+                "-ac".as_ref(),
+                "1".as_ref(),
+                "-c:a".as_ref(),
+                "pcm_s16le".as_ref(),
+                wav.as_os_str(),
+            ],
+        )
+        .await?;
+        tokio::fs::read(&wav)
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))
+    }
+}
+
+fn input_extension(mime: &str) -> Option<&'static str> {
+    let base = ironclaw_common::normalize_mime_type(mime);
+    TRANSCODABLE_MIME_TYPES
+        .iter()
+        .find(|(candidate, _)| *candidate == base)
+        .map(|(_, extension)| *extension)
+}
+
+/// Run a local audio tool, returning its stdout or a `RequestFailed` error
+/// carrying the start of its stderr. A binary that does not exist is a
+/// configuration problem and reports `NotConfigured`.
+pub(super) async fn run_audio_command(
+    program: &Path,
+    args: &[&std::ffi::OsStr],
+) -> Result<Vec<u8>, TranscriptionError> {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    let timeout = Duration::from_secs(crate::config::TRANSCRIPTION_REQUEST_TIMEOUT_SECS);
+    let output = tokio::time::timeout(timeout, command.output())
+        .await
+        .map_err(|_| {
+            TranscriptionError::RequestFailed(format!(
+                "{} timed out after {}s",
+                program.display(),
+                timeout.as_secs()
+            ))
+        })?
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                TranscriptionError::NotConfigured(format!("{} not found", program.display()))
+            } else {
+                TranscriptionError::RequestFailed(format!(
+                    "failed to run {}: {e}",
+                    program.display()
+                ))
+            }
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr: String = stderr.trim().chars().take(MAX_STDERR_IN_ERROR).collect();
+        return Err(TranscriptionError::RequestFailed(format!(
+            "{} exited with {}: {}",
+            program.display(),
+            output.status,
+            stderr
+        )));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_only_formats_outside_audio_format() {
+        assert!(AudioTranscoder::can_transcode("audio/amr"));
+        assert!(AudioTranscoder::can_transcode("audio/3gpp; codecs=samr"));
+        assert!(!AudioTranscoder::can_transcode("audio/ogg"));
+        assert!(!AudioTranscoder::can_transcode("application/octet-stream"));
+        for (mime, _) in TRANSCODABLE_MIME_TYPES {
+            assert_eq!(AudioFormat::from_mime_type(mime), None, "{mime}");
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_ffmpeg_is_not_configured() {
+        let result = AudioTranscoder::new("/nonexistent/ffmpeg")
+            .to_wav(&[1, 2, 3], "audio/amr")
+            .await;
+        assert!(matches!(result, Err(TranscriptionError::NotConfigured(_))));
+    }
+
+    #[tokio::test]
+    async fn unknown_mime_stays_unsupported() {
+        let result = AudioTranscoder::new("/nonexistent/ffmpeg")
+            .to_wav(&[1, 2, 3], "application/octet-stream")
+            .await;
+        assert!(matches!(
+            result,
+            Err(TranscriptionError::UnsupportedFormat { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn returns_the_converted_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script = dir.path().join("ffmpeg");
+        // Copy the `-i` input to the last argument (the output path), so the
+        // test proves the audio round-trips through the written files.
+        std::fs::write(
+            &script,
+            "#!/bin/sh\nfor last; do :; done\nwhile [ \"$1\" != \"-i\" ]; do shift; done\ncp \"$2\" \"$last\"\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let wav = AudioTranscoder::new(&script)
+            .to_wav(b"#!AMR voice", "audio/amr")
+            .await
+            .unwrap();
+        assert_eq!(wav, b"#!AMR voice");
+    }
+}
@@ -0,0 +1,273 @@
+//! Local whisper.cpp transcription provider.
+//!
+//! Runs a configured `whisper.cpp` CLI binary (`whisper-cli` / `main`) on the
+//! host so audio never leaves the machine. whisper.cpp only reads 16 kHz WAV,
+//! so other formats are converted with `ffmpeg` first when one is configured.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+use super::chunking::is_16khz_mono_pcm_wav;
+use super::transcode::run_audio_command;
+use super::{AudioFormat, TranscriptionError, TranscriptionProvider};
+
+/// Largest input accepted. Local transcription has no upload limit, but the
+/// audio is written to a temp file and decoded in full, so keep it bounded.
+const WHISPER_CPP_MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Speech-to-text provider backed by a local whisper.cpp binary.
+///
+/// Both [`with_binary`](Self::with_binary) and [`with_model`](Self::with_model)
+/// must be set; otherwise [`transcribe`](TranscriptionProvider::transcribe)
+/// returns [`TranscriptionError::NotConfigured`].
+#[derive(Debug, Clone, Default)]
+pub struct WhisperCppProvider {
+    binary: Option<PathBuf>,
+    model: Option<PathBuf>,
+    ffmpeg: Option<PathBuf>,
+    language: Option<String>,
+}
+
+impl WhisperCppProvider {
+    /// Create an unconfigured provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Path to the whisper.cpp CLI binary.
+    pub fn with_binary(mut self, binary: impl Into<PathBuf>) -> Self {
+        self.binary = Some(binary.into());
+        self
+    }
+
+    /// Path to the ggml model file passed as `-m`.
+    pub fn with_model(mut self, model: impl Into<PathBuf>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Path to `ffmpeg`, used to convert non-WAV input (e.g. OGG/Opus voice
+    /// notes) and WAV at other rates. Without it only 16 kHz mono PCM WAV is
+    /// accepted.
+    pub fn with_ffmpeg(mut self, ffmpeg: impl Into<PathBuf>) -> Self {
+        self.ffmpeg = Some(ffmpeg.into());
+        self
+    }
+
+    /// Spoken language hint passed as `-l` (defaults to whisper.cpp's own).
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    async fn transcribe_in(
+        &self,
+        work_dir: &Path,
+        binary: &Path,
+        model: &Path,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<String, TranscriptionError> {
+        let input = work_dir.join(format!("input.{}", format.extension()));
+        tokio::fs::write(&input, audio_data)
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+
+        // whisper.cpp does not resample: anything but 16 kHz mono PCM WAV,
+        // including WAV at other rates or channel counts, goes through ffmpeg.
+        let wav = if format == AudioFormat::Wav && is_16khz_mono_pcm_wav(audio_data) {
+            input
+        } else {
+            let ffmpeg =
+                self.ffmpeg
+                    .as_deref()
+                    .ok_or_else(|| TranscriptionError::UnsupportedFormat {
+                        mime_type: if format == AudioFormat::Wav {
+                            "audio/wav (not 16 kHz mono PCM)".to_string()
+                        } else {
+                            format!("audio/{}", format.extension())
+                        },
+                    })?;
+            let wav = work_dir.join("converted.wav");
+            run_audio_command(
+                ffmpeg,
+                &[
+                    "-nostdin".as_ref(),
+                    "-loglevel".as_ref(),
+                    "error".as_ref(),
+                    "-i".as_ref(),
+                    input.as_os_str(),
+                    "-ar".as_ref(),
+                    "16000".as_ref(),
+                    "-ac".as_ref(),
+                    "1".as_ref(),
+                    "-c:a".as_ref(),
+                    "pcm_s16le".as_ref(),
+                    wav.as_os_str(),
+                ],
+            )
+            .await?;
+            wav
+        };
+
+        let mut args: Vec<&std::ffi::OsStr> = vec![
+            "-m".as_ref(),
+            model.as_os_str(),
+            "-f".as_ref(),
+            wav.as_os_str(),
+            // Plain text on stdout: no timestamps, no progress output.
+            "-nt".as_ref(),
+            "-np".as_ref(),
+        ];
+        if let Some(language) = &self.language {
+            args.push("-l".as_ref());
+            args.push(language.as_ref());
+        }
+        let stdout = run_audio_command(binary, &args).await?;
+        Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for WhisperCppProvider {
+    fn name(&self) -> &'static str {
+        "whisper_cpp"
+    }
+
+    fn max_file_size(&self) -> Option<u64> {
+        Some(WHISPER_CPP_MAX_FILE_SIZE)
+    }
+
+    fn supported_formats(&self) -> &'static [AudioFormat] {
+        // An unconfigured provider claims every format so the middleware
+        // still reaches `transcribe` and reports `NotConfigured`.
+        if self.ffmpeg.is_some() || self.binary.is_none() || self.model.is_none() {
+            &AudioFormat::ALL
+        } else {
+            &[AudioFormat::Wav]
+        }
+    }
+
+    async fn transcribe(
+        &self,
+        audio_data: &[u8],
+        format: AudioFormat,
+    ) -> Result<String, TranscriptionError> {
+        let (Some(binary), Some(model)) = (&self.binary, &self.model) else {
+            return Err(TranscriptionError::NotConfigured(
+                "whisper.cpp binary and model path must both be set".to_string(),
+            ));
+        };
+        if audio_data.is_empty() {
+            return Err(TranscriptionError::EmptyAudio);
+        }
+
+        let work_dir =
+            std::env::temp_dir().join(format!("ironclaw-whisper-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir(&work_dir)
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let result = self
+            .transcribe_in(&work_dir, binary, model, audio_data, format)
+            .await;
+        if let Err(e) = tokio::fs::remove_dir_all(&work_dir).await {
+            tracing::warn!(
+                path = %work_dir.display(),
+                error = %e,
+                "Failed to clean up whisper.cpp work directory"
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_not_configured_without_binary_or_model() {
+        let result = WhisperCppProvider::new()
+            .with_model("/models/ggml-base.bin")
+            .transcribe(&[1, 2, 3], AudioFormat::Wav)
+            .await;
+        assert!(matches!(result, Err(TranscriptionError::NotConfigured(_))));
+    }
+
+    #[tokio::test]
+    async fn rejects_non_wav_without_ffmpeg() {
+        let provider = WhisperCppProvider::new()
+            .with_binary("/nonexistent/whisper-cli")
+            .with_model("/nonexistent/model.bin");
+        assert_eq!(provider.supported_formats(), &[AudioFormat::Wav]);
+        let result = provider.transcribe(&[1, 2, 3], AudioFormat::Ogg).await;
+        assert!(matches!(
+            result,
+            Err(TranscriptionError::UnsupportedFormat { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    fn write_script(dir: &Path, name: &str, body: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = dir.join(name);
+        std::fs::write(&script, format!("#!/bin/sh\n{body}")).unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn returns_trimmed_stdout_of_configured_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        // Print the name and size of the `-f` file so the test also proves
+        // it points at the audio that was written.
+        let script = write_script(
+            dir.path(),
+            "whisper-cli",
+            "while [ \"$1\" != \"-f\" ]; do shift; done\nprintf '  %s %s  \\n' \"$(basename \"$2\")\" \"$(wc -c < \"$2\" | tr -d ' ')\"\n",
+        );
+
+        let provider = WhisperCppProvider::new()
+            .with_binary(&script)
+            .with_model(dir.path().join("model.bin"));
+        let wav = crate::transcription::chunking::test_wav_at(16_000, 10);
+        let text = provider.transcribe(&wav, AudioFormat::Wav).await.unwrap();
+        assert_eq!(text, format!("input.wav {}", wav.len()));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn resamples_wav_that_is_not_16khz_mono() {
+        let dir = tempfile::tempdir().unwrap();
+        let whisper = write_script(
+            dir.path(),
+            "whisper-cli",
+            "while [ \"$1\" != \"-f\" ]; do shift; done\nbasename \"$2\"\n",
+        );
+        // Stand-in that copies the `-i` input to the output path.
+        let ffmpeg = write_script(
+            dir.path(),
+            "ffmpeg",
+            "for last; do :; done\nwhile [ \"$1\" != \"-i\" ]; do shift; done\ncp \"$2\" \"$last\"\n",
+        );
+        let wav_8khz = crate::transcription::chunking::test_wav_at(8_000, 10);
+
+        let without_ffmpeg = WhisperCppProvider::new()
+            .with_binary(&whisper)
+            .with_model(dir.path().join("model.bin"));
+        assert!(matches!(
+            without_ffmpeg.transcribe(&wav_8khz, AudioFormat::Wav).await,
+            Err(TranscriptionError::UnsupportedFormat { .. })
+        ));
+
+        let with_ffmpeg = without_ffmpeg.with_ffmpeg(&ffmpeg);
+        let text = with_ffmpeg
+            .transcribe(&wav_8khz, AudioFormat::Wav)
+            .await
+            .unwrap();
+        assert_eq!(text, "converted.wav");
+    }
+}
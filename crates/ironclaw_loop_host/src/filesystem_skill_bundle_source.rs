@@ -1,5 +1,6 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
     sync::Arc,
 };
 
@@ -7,8 +8,9 @@ use async_trait::async_trait;
 use ironclaw_filesystem::{FileType, FilesystemError, RootFilesystem, ScopedFilesystem};
 use ironclaw_host_api::{ResourceScope, ScopedPath, TenantId};
 use ironclaw_skills::{
-    INSTALL_METADATA_FILE_NAME, InstalledSkillMetadata, MAX_INSTALL_METADATA_BYTES,
-    MAX_PROMPT_FILE_SIZE, SkillTrust, parse_skill_md,
+    DEFAULT_EMBEDDED_BLOB_MIN_LEN, INSTALL_METADATA_FILE_NAME, InstalledSkillMetadata, LoadedSkill,
+    MAX_INSTALL_METADATA_BYTES, MAX_PROMPT_FILE_SIZE, SkillIncludeError, SkillSource, SkillTrust,
+    compose_skill_md, compute_hash, find_embedded_blobs, parse_skill_md, resolve_skill_includes,
 };
 use ironclaw_turns::run_profile::{LoopRunContext, SkillVisibility};
 use parking_lot::Mutex as ParkingMutex;
@@ -107,12 +109,27 @@ impl FilesystemSkillBundleRoot {
     }
 }
 
+/// A bundle found under a root, with the `includes` its manifest declares.
+#[derive(Debug, Clone)]
+struct ListedBundle {
+    descriptor: SkillBundleDescriptor,
+    includes: Vec<String>,
+}
+
 /// Filesystem-backed skill bundle source over host-approved scoped roots.
+///
+/// Bundles whose manifests declare `includes` are composed the way the skill
+/// registry composes them: listing lowers their trust to the minimum across
+/// everything they include and skips those whose includes do not resolve,
+/// and reading their `SKILL.md` returns the composed prompt.
 pub struct FilesystemSkillBundleSource<F> {
     filesystem: Arc<ScopedFilesystem<F>>,
     roots: Vec<FilesystemSkillBundleRoot>,
     validated_manifests: ParkingMutex<HashSet<ScopedPath>>,
-    system_root_descriptor_cache: AsyncMutex<HashMap<String, Vec<SkillBundleDescriptor>>>,
+    /// `(bundle id, prompt hash)` pairs already scanned for embedded blobs,
+    /// so re-listed user roots warn once per prompt revision.
+    blob_scanned_prompts: ParkingMutex<HashSet<(String, String)>>,
+    system_root_descriptor_cache: AsyncMutex<HashMap<String, Vec<ListedBundle>>>,
     max_skill_md_bytes: usize,
     max_bundle_file_bytes: usize,
     max_bundles_per_root: usize,
@@ -160,6 +177,7 @@ where
             filesystem,
             roots,
             validated_manifests: ParkingMutex::new(HashSet::new()),
+            blob_scanned_prompts: ParkingMutex::new(HashSet::new()),
             system_root_descriptor_cache: AsyncMutex::new(HashMap::new()),
             max_skill_md_bytes: MAX_PROMPT_FILE_SIZE as usize,
             max_bundle_file_bytes: DEFAULT_MAX_BUNDLE_FILE_BYTES,
@@ -196,23 +214,37 @@ where
         &self,
         scope: &ResourceScope,
     ) -> Result<(), SkillBundleSourceError> {
-        let mut descriptors = Vec::new();
+        let mut listed = Vec::new();
         for root in self
             .roots
             .iter()
             .filter(|root| root.source_kind() == SkillSourceKind::System)
         {
-            self.list_root_with_cache(scope, root, &mut descriptors)
-                .await?;
+            self.list_root_with_cache(scope, root, &mut listed).await?;
         }
         Ok(())
     }
 
+    async fn list_visible_bundles(
+        &self,
+        run_context: &LoopRunContext,
+        scope: &ResourceScope,
+    ) -> Result<Vec<ListedBundle>, SkillBundleSourceError> {
+        let mut listed = Vec::new();
+        for root in &self.roots {
+            if !root_visible_for_run(root, run_context) {
+                continue;
+            }
+            self.list_root_with_cache(scope, root, &mut listed).await?;
+        }
+        Ok(listed)
+    }
+
     async fn list_root_with_cache(
         &self,
         scope: &ResourceScope,
         root: &FilesystemSkillBundleRoot,
-        descriptors: &mut Vec<SkillBundleDescriptor>,
+        descriptors: &mut Vec<ListedBundle>,
     ) -> Result<(), SkillBundleSourceError> {
         if root.source_kind() != SkillSourceKind::System {
             return self.list_root(scope, root, descriptors).await;
@@ -236,7 +268,7 @@ where
         &self,
         scope: &ResourceScope,
         root: &FilesystemSkillBundleRoot,
-        descriptors: &mut Vec<SkillBundleDescriptor>,
+        descriptors: &mut Vec<ListedBundle>,
     ) -> Result<(), SkillBundleSourceError> {
         let entries = match self.filesystem.list_dir(scope, root.root()).await {
             Ok(entries) => entries,
@@ -268,11 +300,11 @@ where
                 }
             };
             let skill_md_path = bundle_scoped_path(root.root(), &bundle_id, &skill_md_file)?;
-            let description = match self
+            let (description, includes) = match self
                 .validate_bundle_manifest(scope, &skill_md_path, &bundle_id)
                 .await
             {
-                Ok(description) => description,
+                Ok(manifest) => manifest,
                 Err(error) if is_skippable_manifest_error(&error) => {
                     debug!(
                         bundle_id = %bundle_id,
@@ -286,26 +318,28 @@ where
             self.validated_manifests.lock().insert(skill_md_path);
             let trust = self.bundle_trust(scope, root, &bundle_id).await?;
 
-            descriptors.push(
-                SkillBundleDescriptor::new(
+            descriptors.push(ListedBundle {
+                descriptor: SkillBundleDescriptor::new(
                     bundle_id,
                     trust,
                     root.visibility().copied(),
                     description,
                 )
                 .with_provenance(SkillBundleProvenance::new(root.source_kind())),
-            );
+                includes,
+            });
         }
 
         Ok(())
     }
 
+    /// Returns the manifest description and declared `includes`.
     async fn validate_bundle_manifest(
         &self,
         scope: &ResourceScope,
         skill_md_path: &ScopedPath,
         bundle_id: &SkillBundleId,
-    ) -> Result<String, SkillBundleSourceError> {
+    ) -> Result<(String, Vec<String>), SkillBundleSourceError> {
         let skill_md = self
             .read_bounded(scope, skill_md_path, self.max_skill_md_bytes)
             .await?;
@@ -320,7 +354,134 @@ where
         if description.trim().is_empty() {
             return Err(SkillBundleSourceError::InvalidSkillBundle);
         }
-        Ok(description)
+        self.warn_on_embedded_blobs(bundle_id, &parsed.prompt_content);
+        Ok((description, parsed.manifest.includes))
+    }
+
+    /// Warn about long base64-looking runs in a bundle's prompt, which can
+    /// smuggle instructions or payloads past a casual review. The bundle still
+    /// loads; trust decides what it can do.
+    fn warn_on_embedded_blobs(&self, bundle_id: &SkillBundleId, prompt_content: &str) {
+        let key = (bundle_id.to_string(), compute_hash(prompt_content));
+        if !self.blob_scanned_prompts.lock().insert(key) {
+            return;
+        }
+        for blob in find_embedded_blobs(prompt_content, DEFAULT_EMBEDDED_BLOB_MIN_LEN) {
+            tracing::warn!(
+                bundle_id = %bundle_id,
+                "Skill '{}' prompt contains a {}; review it before trusting this skill",
+                bundle_id.name(),
+                blob
+            );
+        }
+    }
+
+    /// Compose `includer` with the bundles it includes, transitively, using
+    /// the same resolution, cycle, depth, size, and trust rules as the skill
+    /// registry.
+    async fn compose_includes(
+        &self,
+        scope: &ResourceScope,
+        listed: &[ListedBundle],
+        includer: &ListedBundle,
+    ) -> Result<LoadedSkill, SkillIncludeError> {
+        let includer_name = includer.descriptor.id().name();
+        let mut skills = Vec::new();
+        let mut seen = HashSet::from([includer_name.to_string()]);
+        let mut pending = VecDeque::from([includer]);
+        while let Some(bundle) = pending.pop_front() {
+            let name = bundle.descriptor.id().name();
+            let skill = self
+                .load_bundle_skill(scope, &bundle.descriptor)
+                .await
+                .map_err(|_| SkillIncludeError::Unresolved(name.to_string()))?;
+            for include in &bundle.includes {
+                if seen.insert(include.clone())
+                    && let Some(target) =
+                        include_target(listed, include, bundle.descriptor.id().source_kind())
+                {
+                    pending.push_back(target);
+                }
+            }
+            skills.push(skill);
+        }
+
+        if let Some((_, error)) = resolve_skill_includes(&mut skills)
+            .into_iter()
+            .find(|(name, _)| name == includer_name)
+        {
+            return Err(error);
+        }
+        skills
+            .into_iter()
+            .find(|skill| skill.name() == includer_name)
+            .ok_or_else(|| SkillIncludeError::Unresolved(includer_name.to_string()))
+    }
+
+    async fn load_bundle_skill(
+        &self,
+        scope: &ResourceScope,
+        descriptor: &SkillBundleDescriptor,
+    ) -> Result<LoadedSkill, SkillBundleSourceError> {
+        let root = self
+            .roots
+            .iter()
+            .find(|root| root.source_kind() == descriptor.id().source_kind())
+            .ok_or(SkillBundleSourceError::BundleNotFound)?;
+        let skill_md_path =
+            bundle_scoped_path(root.root(), descriptor.id(), &SkillFilePath::skill_md())?;
+        let skill_md = self
+            .read_bounded(scope, &skill_md_path, self.max_skill_md_bytes)
+            .await?;
+        let skill_md = String::from_utf8(skill_md)
+            .map_err(|_| SkillBundleSourceError::BundleUtf8DecodeFailed)?;
+        let parsed =
+            parse_skill_md(&skill_md).map_err(|_| SkillBundleSourceError::ManifestParseFailed)?;
+        Ok(LoadedSkill {
+            content_hash: compute_hash(&parsed.prompt_content),
+            manifest: parsed.manifest,
+            prompt_content: parsed.prompt_content,
+            // Unknown trust composes as the least trusted.
+            trust: descriptor.trust().copied().unwrap_or(SkillTrust::Installed),
+            source: match descriptor.id().source_kind() {
+                SkillSourceKind::System => SkillSource::Bundled(PathBuf::new()),
+                SkillSourceKind::TenantShared => SkillSource::Workspace(PathBuf::new()),
+                SkillSourceKind::User => SkillSource::User(PathBuf::new()),
+            },
+            compiled_patterns: Vec::new(),
+            compiled_exclude_patterns: Vec::new(),
+            lowercased_keywords: Vec::new(),
+            lowercased_exclude_keywords: Vec::new(),
+            lowercased_tags: Vec::new(),
+        })
+    }
+
+    /// The `SKILL.md` served for `bundle_id`: as written, or with its
+    /// includes composed into the prompt.
+    async fn composed_skill_md(
+        &self,
+        run_context: &LoopRunContext,
+        scope: &ResourceScope,
+        bundle_id: &SkillBundleId,
+        skill_md: Vec<u8>,
+    ) -> Result<Vec<u8>, SkillBundleSourceError> {
+        let Some(raw) = std::str::from_utf8(&skill_md).ok().filter(|raw| {
+            parse_skill_md(raw).is_ok_and(|parsed| !parsed.manifest.includes.is_empty())
+        }) else {
+            return Ok(skill_md);
+        };
+        let listed = self.list_visible_bundles(run_context, scope).await?;
+        let includer = listed
+            .iter()
+            .find(|bundle| bundle.descriptor.id() == bundle_id)
+            .ok_or(SkillBundleSourceError::BundleNotFound)?;
+        let composed = self
+            .compose_includes(scope, &listed, includer)
+            .await
+            .map_err(|_| SkillBundleSourceError::InvalidSkillBundle)?;
+        compose_skill_md(raw, &composed.prompt_content)
+            .map(String::into_bytes)
+            .map_err(|_| SkillBundleSourceError::ManifestParseFailed)
     }
 
     async fn bundle_trust(
@@ -379,13 +540,26 @@ where
         run_context: &LoopRunContext,
     ) -> Result<Vec<SkillBundleDescriptor>, SkillBundleSourceError> {
         let scope = resource_scope_for_run(run_context);
-        let mut descriptors = Vec::new();
-        for root in &self.roots {
-            if !root_visible_for_run(root, run_context) {
+        let listed = self.list_visible_bundles(run_context, &scope).await?;
+        let mut descriptors = Vec::with_capacity(listed.len());
+        for bundle in &listed {
+            if bundle.includes.is_empty() {
+                descriptors.push(bundle.descriptor.clone());
                 continue;
             }
-            self.list_root_with_cache(&scope, root, &mut descriptors)
-                .await?;
+            match self.compose_includes(&scope, &listed, bundle).await {
+                Ok(composed) => descriptors.push(
+                    bundle
+                        .descriptor
+                        .clone()
+                        .with_trust(bundle.descriptor.trust().map(|_| composed.trust)),
+                ),
+                Err(error) => tracing::warn!(
+                    bundle_id = %bundle.descriptor.id(),
+                    %error,
+                    "skipping skill bundle whose includes cannot be resolved"
+                ),
+            }
         }
         sort_skill_bundle_descriptors(&mut descriptors);
         Ok(descriptors)
@@ -421,8 +595,15 @@ where
             }
         }
         let scoped_path = bundle_scoped_path(root.root(), bundle_id, path)?;
-        self.read_bounded(&scope, &scoped_path, self.max_bundle_file_bytes)
-            .await
+        let bytes = self
+            .read_bounded(&scope, &scoped_path, self.max_bundle_file_bytes)
+            .await?;
+        if path.as_str() == SkillFilePath::skill_md().as_str() {
+            return self
+                .composed_skill_md(run_context, &scope, bundle_id, bytes)
+                .await;
+        }
+        Ok(bytes)
     }
 }
 
@@ -443,6 +624,23 @@ fn root_visible_for_run(root: &FilesystemSkillBundleRoot, run_context: &LoopRunC
     }
 }
 
+/// The listed bundle an `include` names: the one in `preferred` (the
+/// including bundle's source) when present, otherwise the first in source
+/// order.
+fn include_target<'a>(
+    listed: &'a [ListedBundle],
+    include: &str,
+    preferred: SkillSourceKind,
+) -> Option<&'a ListedBundle> {
+    listed
+        .iter()
+        .filter(|bundle| bundle.descriptor.id().name() == include)
+        .min_by_key(|bundle| {
+            let source_kind = bundle.descriptor.id().source_kind();
+            (source_kind != preferred, source_kind)
+        })
+}
+
 fn bundle_scoped_path(
     root: &ScopedPath,
     bundle_id: &SkillBundleId,
@@ -543,6 +741,8 @@ mod tests {
         run_profile::InMemoryRunProfileResolver,
     };
 
+    use tracing_test::traced_test;
+
     fn skill_md(name: &str, description: &str) -> String {
         format!("---\nname: {name}\ndescription: {description}\n---\nUse the {name} skill.\n")
     }
@@ -948,6 +1148,46 @@ mod tests {
         assert!(descriptors.is_empty());
     }
 
+    #[tokio::test]
+    #[traced_test]
+    async fn filesystem_source_warns_once_on_embedded_base64_blobs() {
+        let (root, source) = mounted_source();
+        let blob: String = (0..300u32)
+            .map(|index| {
+                let alphabet = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+                alphabet[(index.wrapping_mul(2_654_435_761) >> 7) as usize % alphabet.len()] as char
+            })
+            .collect();
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/smuggler/SKILL.md",
+            format!("---\nname: smuggler\ndescription: Smuggler\n---\nDecode this: {blob}\n"),
+        )
+        .await;
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/plain/SKILL.md",
+            skill_md("plain", "Plain skill"),
+        )
+        .await;
+
+        let run_context = run_context().await;
+        let descriptors = source.list_skill_bundles(&run_context).await.unwrap();
+        source.list_skill_bundles(&run_context).await.unwrap();
+
+        assert_eq!(descriptors.len(), 2, "the flagged skill still loads");
+        logs_assert(|lines: &[&str]| {
+            let warnings = lines
+                .iter()
+                .filter(|line| line.contains("prompt contains a base64-like blob"))
+                .collect::<Vec<_>>();
+            match warnings.as_slice() {
+                [warning] if warning.contains("Skill 'smuggler'") => Ok(()),
+                other => Err(format!("expected one smuggler warning, got {other:?}")),
+            }
+        });
+    }
+
     #[tokio::test]
     async fn filesystem_source_skips_empty_skill_descriptions() {
         let (root, source) = mounted_source();
@@ -1052,6 +1292,67 @@ mod tests {
         assert!(descriptors.is_empty());
     }
 
+    #[tokio::test]
+    async fn filesystem_source_composes_includes_and_attenuates_trust() {
+        let (root, source) = mounted_source();
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/release/SKILL.md",
+            "---\nname: release\ndescription: Release\nincludes: [\"house-style\"]\n---\n\nCut the release.\n",
+        )
+        .await;
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/broken/SKILL.md",
+            "---\nname: broken\ndescription: Broken\nincludes: [\"missing\"]\n---\n\nNever listed.\n",
+        )
+        .await;
+        write_root(
+            &root,
+            "/system/skills/house-style/SKILL.md",
+            "---\nname: house-style\ndescription: Style\n---\n\nWrite tersely.\n",
+        )
+        .await;
+        // The user's own copy wins over the system one, and because it was
+        // installed from a URL it lowers the composed trust.
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/house-style/SKILL.md",
+            "---\nname: house-style\ndescription: Style\n---\n\nWrite warmly.\n",
+        )
+        .await;
+        write_root(
+            &root,
+            "/tenants/tenant-a/users/user-a/skills/house-style/.ironclaw-install.json",
+            br#"{"source":"installed_url","source_url":"https://example.test/SKILL.md"}"#.to_vec(),
+        )
+        .await;
+        let context = run_context().await;
+
+        let descriptors = source.list_skill_bundles(&context).await.unwrap();
+        let release = descriptors
+            .iter()
+            .find(|descriptor| descriptor.id().name() == "release")
+            .expect("release listed");
+        assert_eq!(release.trust(), Some(&SkillTrust::Installed));
+        assert!(
+            descriptors
+                .iter()
+                .all(|descriptor| descriptor.id().name() != "broken")
+        );
+
+        let skill_md = source
+            .read_skill_bundle_file(
+                &context,
+                &SkillBundleId::new(SkillSourceKind::User, "release").unwrap(),
+                &SkillFilePath::skill_md(),
+            )
+            .await
+            .unwrap();
+        let parsed = parse_skill_md(&String::from_utf8(skill_md).unwrap()).unwrap();
+        assert_eq!(parsed.prompt_content, "Write warmly.\n\nCut the release.\n");
+    }
+
     #[tokio::test]
     async fn filesystem_source_skips_bad_manifest_without_hiding_valid_bundles() {
         let (root, source) = mounted_source();
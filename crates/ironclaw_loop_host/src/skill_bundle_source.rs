@@ -230,6 +230,14 @@ impl SkillBundleDescriptor {
         self
     }
 
+    /// Replaces the trust metadata, e.g. after a host lowers it to that of
+    /// the skills this bundle includes.
+    #[must_use]
+    pub fn with_trust(mut self, trust: Option<SkillTrust>) -> Self {
+        self.trust = trust;
+        self
+    }
+
     /// Attaches the safe manifest description that may be shown before prompt loading.
     #[must_use]
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
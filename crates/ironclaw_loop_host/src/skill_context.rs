@@ -1,5 +1,8 @@
 use async_trait::async_trait;
-use ironclaw_skills::{ParsedSkill, SkillTrust, parse_skill_md};
+use ironclaw_skills::{
+    ParsedSkill, SkillTemplateVars, SkillTrust, approx_prompt_tokens, parse_skill_md,
+    render_skill_template, truncate_prompt_to_tokens,
+};
 use ironclaw_turns::run_profile::{
     AgentLoopHostError, AgentLoopHostErrorKind, InstalledSkillSnapshot, LoopContextSnippet,
     LoopRunContext, SkillActivationState, SkillContextError, SkillContextService,
@@ -177,7 +180,20 @@ pub(crate) async fn build_skill_instruction_snippets(
         .load_skill_context_candidates(run_context)
         .await
         .map_err(HostSkillContextBuildError::into_host_error)?;
-    let snapshot = build_skill_run_snapshot(candidates)
+    let date = chrono::Utc::now().date_naive().to_string();
+    let vars = SkillTemplateVars {
+        user_name: run_context
+            .actor
+            .as_ref()
+            .map(|actor| actor.user_id.as_str()),
+        date: Some(&date),
+        channel: run_context
+            .product_context
+            .as_ref()
+            .and_then(|context| context.source_channel.as_ref())
+            .map(|channel| channel.as_str()),
+    };
+    let snapshot = build_skill_run_snapshot_with_template(candidates, &vars)
         .map_err(HostSkillContextBuildError::into_host_error)?;
     let service = SkillContextService::new(snapshot.clone());
     let snippets = service
@@ -192,6 +208,15 @@ pub(crate) async fn build_skill_instruction_snippets(
 
 pub fn build_skill_run_snapshot(
     candidates: Vec<HostSkillContextCandidate>,
+) -> Result<SkillRunSnapshot, HostSkillContextBuildError> {
+    build_skill_run_snapshot_with_template(candidates, &SkillTemplateVars::default())
+}
+
+/// Like [`build_skill_run_snapshot`], but renders `{{user_name}}` / `{{date}}`
+/// / `{{channel}}` placeholders in loaded prompt content from `vars`.
+fn build_skill_run_snapshot_with_template(
+    candidates: Vec<HostSkillContextCandidate>,
+    vars: &SkillTemplateVars<'_>,
 ) -> Result<SkillRunSnapshot, HostSkillContextBuildError> {
     if candidates.is_empty() {
         return Ok(SkillRunSnapshot::empty());
@@ -217,6 +242,7 @@ pub fn build_skill_run_snapshot(
                     trust,
                     visibility,
                     candidate.ordering_key,
+                    vars,
                 ));
             }
             HostSkillContextCandidatePayload::DiscoverableMetadata {
@@ -245,12 +271,21 @@ fn parsed_skill_to_snapshot_entry(
     trust: SkillTrust,
     visibility: SkillVisibility,
     ordering_key: Option<String>,
+    vars: &SkillTemplateVars<'_>,
 ) -> InstalledSkillSnapshot {
     let name = parsed.manifest.name;
+    let max_context_tokens = parsed.manifest.activation.max_context_tokens;
     let trust = skill_trust_level(trust);
     let prompt_content = match trust {
         SkillTrustLevel::Installed => None,
-        SkillTrustLevel::Trusted => Some(parsed.prompt_content),
+        SkillTrustLevel::Trusted => {
+            let rendered = render_skill_template(&parsed.prompt_content, vars);
+            Some(enforce_skill_token_budget(
+                &name,
+                rendered,
+                max_context_tokens,
+            ))
+        }
     };
     InstalledSkillSnapshot {
         ordering_key: ordering_key.unwrap_or_else(|| name.clone()),
@@ -263,6 +298,27 @@ fn parsed_skill_to_snapshot_entry(
     }
 }
 
+/// Cap a loaded skill's prompt at its declared `max_context_tokens`.
+///
+/// Load-time validation only rejects prompts over twice the declaration, and
+/// template rendering can grow the text further, so the declaration is
+/// enforced again here where the prompt actually enters model context.
+fn enforce_skill_token_budget(name: &str, prompt: String, max_context_tokens: usize) -> String {
+    match truncate_prompt_to_tokens(&prompt, max_context_tokens) {
+        Some(truncated) => {
+            tracing::warn!(
+                component = "skill_context",
+                skill = name,
+                max_context_tokens,
+                approx_tokens = approx_prompt_tokens(&prompt),
+                "skill prompt exceeds max_context_tokens; truncated"
+            );
+            truncated
+        }
+        None => prompt,
+    }
+}
+
 fn discoverable_skill_to_snapshot_entry(
     name: String,
     safe_description: String,
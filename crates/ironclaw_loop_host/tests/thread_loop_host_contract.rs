@@ -1555,6 +1555,36 @@ fn skill_snapshot_builder_drops_installed_prompt_content_before_snapshot_storage
     assert!(!serialized.contains("fake turn"));
 }
 
+#[test]
+fn skill_snapshot_builder_truncates_prompt_over_declared_token_budget() {
+    let long_prompt = format!("{}\n{}", "keep ".repeat(20), "overflow ".repeat(40));
+    let snapshot = build_skill_run_snapshot(vec![
+        HostSkillContextCandidate::loaded(
+            format!(
+                "---\nname: alpha\ndescription: budgeted\nactivation:\n  keywords: [alpha]\n  max_context_tokens: 40\n---\n\n{long_prompt}\n"
+            ),
+            Some(SkillTrust::Trusted),
+            Some(SkillVisibility::Visible),
+        ),
+        HostSkillContextCandidate::loaded(
+            skill_md("bravo", "unbudgeted", "short prompt"),
+            Some(SkillTrust::Trusted),
+            Some(SkillVisibility::Visible),
+        ),
+    ])
+    .unwrap();
+
+    let alpha = snapshot.entries.iter().find(|e| e.name == "alpha").unwrap();
+    let alpha_prompt = alpha.prompt_content.as_deref().unwrap();
+    assert!(alpha_prompt.starts_with("keep"));
+    assert!(!alpha_prompt.contains("overflow"));
+    assert!(alpha_prompt.ends_with("[Skill prompt truncated to fit its context budget.]"));
+    assert!(alpha_prompt.len() <= 40 * 4);
+
+    let bravo = snapshot.entries.iter().find(|e| e.name == "bravo").unwrap();
+    assert_eq!(bravo.prompt_content.as_deref(), Some("short prompt\n"));
+}
+
 #[tokio::test]
 async fn thread_context_port_ignores_malformed_hidden_skill_content() {
     let fixture = ThreadFixture::new().await;
@@ -1600,6 +1630,51 @@ async fn thread_context_port_ignores_malformed_hidden_skill_content() {
     assert!(snippet.model_content.contains("visible prompt"));
 }
 
+#[tokio::test]
+async fn thread_context_port_renders_template_variables_in_trusted_skill_prompt() {
+    let fixture = ThreadFixture::new().await;
+    let run_context = fixture
+        .run_context
+        .clone()
+        .with_actor(TurnActor::new(UserId::new("user-loop-support").unwrap()));
+    let source = Arc::new(StaticSkillContextSource::new(vec![
+        HostSkillContextCandidate::loaded(
+            skill_md(
+                "alpha",
+                "visible description",
+                "Greet {{user_name}} on {{date}} via {{channel}}; keep {{unknown}}.",
+            ),
+            Some(SkillTrust::Trusted),
+            Some(SkillVisibility::Visible),
+        ),
+    ]));
+    let adapter = ThreadBackedLoopContextPort::new(
+        Arc::clone(&fixture.thread_service),
+        fixture.thread_scope.clone(),
+        run_context,
+        16,
+    )
+    .with_skill_context_source(source);
+
+    let bundle = adapter
+        .load_loop_context(LoopContextRequest {
+            after: None,
+            limit: 16,
+            mode: ironclaw_turns::run_profile::PromptMode::TextOnly,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(bundle.instruction_snippets.len(), 1);
+    let content = &bundle.instruction_snippets[0].model_content;
+    assert!(content.contains("Greet user-loop-support on "));
+    assert!(!content.contains("{{user_name}}"));
+    assert!(!content.contains("{{date}}"));
+    // No product context on this run, so the channel stays literal.
+    assert!(content.contains("via {{channel}}"));
+    assert!(content.contains("keep {{unknown}}."));
+}
+
 #[tokio::test]
 async fn thread_context_port_fails_closed_when_visible_skill_content_is_missing() {
     let fixture = ThreadFixture::new().await;
@@ -167,8 +167,8 @@ pub use ironclaw_host_api::product_adapter::{
     ExternalActorRef, ExternalConversationRef, ExternalEventId, FinalReplyView, GatePromptView,
     ImmediateResponse, InboundCommandPayload, InboundOutcome, InboundRetryDisposition,
     LinkedThreadActionPayload, MAX_IMMEDIATE_RESPONSE_BYTES, MAX_REPLY_CONTEXT_BYTES,
-    NormalizedInboundMessage, OutboundDeliverySink, OutboundEnvelope, OutboundPart, OutboundTarget,
-    PROJECTION_SKILL_ACTIVATION_MAX_ITEMS, PROJECTION_SKILL_FEEDBACK_MAX_BYTES,
+    NormalizedInboundMessage, OutboundChoice, OutboundDeliverySink, OutboundEnvelope, OutboundPart,
+    OutboundTarget, PROJECTION_SKILL_ACTIVATION_MAX_ITEMS, PROJECTION_SKILL_FEEDBACK_MAX_BYTES,
     PROJECTION_SKILL_NAME_MAX_BYTES, PROJECTION_TEXT_MAX_BYTES, PairingPromptView,
     ParsedProductInbound, PartDeliveryOutcome, PreferenceTargetCodec,
     PreferenceTargetEncodeRequest, ProductAdapterCapabilities, ProductAdapterError,
@@ -386,10 +386,11 @@ pub use reborn_services::{
     RebornViewQuery, RunArtifactLogs, RunArtifactMessage, RunArtifactRedaction,
     RunArtifactToolCall, SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY,
     SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY_ID, SKILL_AUTO_ACTIVATE_SET_CAPABILITY,
-    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_CONTENT_VIEW, SKILL_INSTALL_CAPABILITY,
-    SKILL_INSTALL_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY, SKILL_REMOVE_CAPABILITY_ID,
-    SKILL_SEARCH_VIEW, SKILL_UPDATE_CAPABILITY, SKILL_UPDATE_CAPABILITY_ID, SKILLS_VIEW,
-    SUBMIT_TURN_COMMAND, SetActiveLlmRequest, SettingsToolPermissionState, SkillsProductFacade,
+    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_CONTENT_VIEW, SKILL_ENABLED_SET_CAPABILITY,
+    SKILL_ENABLED_SET_CAPABILITY_ID, SKILL_INSTALL_CAPABILITY, SKILL_INSTALL_CAPABILITY_ID,
+    SKILL_REMOVE_CAPABILITY, SKILL_REMOVE_CAPABILITY_ID, SKILL_SEARCH_VIEW,
+    SKILL_UPDATE_CAPABILITY, SKILL_UPDATE_CAPABILITY_ID, SKILLS_VIEW, SUBMIT_TURN_COMMAND,
+    SetActiveLlmRequest, SettingsToolPermissionState, SkillsProductFacade,
     StaticOperatorStatusService, THREAD_DELETE_CAPABILITY, THREAD_DELETE_CAPABILITY_ID,
     THREADS_VIEW, TIMELINE_VIEW, TRACE_ACCOUNT_LOGIN_LINK_COMMAND, TRACE_ACCOUNT_TRACES_VIEW,
     TRACE_CREDITS_VIEW, TRACE_HOLD_AUTHORIZE_COMMAND, TriggerRunThreadScope,
@@ -488,6 +488,9 @@ pub const SKILL_REMOVE_CAPABILITY: ProductCapabilityDescriptor =
 pub const SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID: &str = "builtin.skill_auto_activate_set";
 pub const SKILL_AUTO_ACTIVATE_SET_CAPABILITY: ProductCapabilityDescriptor =
     ProductCapabilityDescriptor::api_only(SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID);
+pub const SKILL_ENABLED_SET_CAPABILITY_ID: &str = "builtin.skill_enabled_set";
+pub const SKILL_ENABLED_SET_CAPABILITY: ProductCapabilityDescriptor =
+    ProductCapabilityDescriptor::api_only(SKILL_ENABLED_SET_CAPABILITY_ID);
 pub const SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY_ID: &str =
     "builtin.skill_auto_activate_learned_set";
 pub const SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY: ProductCapabilityDescriptor =
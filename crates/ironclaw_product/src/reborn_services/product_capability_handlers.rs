@@ -528,6 +528,7 @@ mod tests {
             SKILL_UPDATE_CAPABILITY_ID,
             SKILL_REMOVE_CAPABILITY_ID,
             SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID,
+            SKILL_ENABLED_SET_CAPABILITY_ID,
         ] {
             let capability = CapabilityId::new(id).expect("valid capability id");
             assert!(
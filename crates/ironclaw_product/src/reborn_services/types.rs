@@ -1257,6 +1257,10 @@ pub struct RebornSkillInfo {
     /// only runs when explicitly invoked with `/name`. Defaults to `true`.
     #[serde(default = "default_true")]
     pub auto_activate: bool,
+    /// Whether the operator left the skill enabled. A disabled skill never
+    /// runs, not even with `/name`. Defaults to `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 }
 
 fn default_true() -> bool {
@@ -404,7 +404,7 @@ impl RunDeliveryEventHandler {
                     event_kind: RunNotificationEventKind::ApprovalNeeded,
                     intent: DeliveryIntent::GatePrompt,
                     access: StoredProductReplyTargetAccess::AuthorityBearingPrompt,
-                    part: OutboundPart::Text(prompts::gate_prompt_text(&view, direct)),
+                    part: prompts::gate_prompt_part(&view, direct),
                     gate_ref: Some(gate_ref.as_str().to_string()),
                     require_direct_message_target: false,
                 }
@@ -5,7 +5,10 @@
 //! are authored in markdown (`**bold**`, backticks) so adapters can render
 //! them into their native markup.
 
-use crate::{ApprovalPromptContextView, AuthPromptChallengeKind, AuthPromptView, GatePromptView};
+use crate::{
+    ApprovalPromptContextView, AuthPromptChallengeKind, AuthPromptView, GatePromptView,
+    OutboundChoice, OutboundPart,
+};
 use ironclaw_outbound::RunNotificationEventKind;
 use ironclaw_turns::{GateRef, TurnRunId};
 
@@ -86,6 +89,26 @@ pub(crate) fn gate_prompt_text(view: &GatePromptView, direct_message: bool) -> S
     )
 }
 
+/// Render a gate prompt as a choices part: the full prompt text (channels
+/// without buttons keep the typed-reply instruction) plus Approve/Deny
+/// buttons. Each reply names the gate, so a tap resolves this prompt even
+/// when several approvals are pending in the conversation.
+pub(crate) fn gate_prompt_part(view: &GatePromptView, direct_message: bool) -> OutboundPart {
+    OutboundPart::Choices {
+        text: gate_prompt_text(view, direct_message),
+        choices: vec![
+            OutboundChoice {
+                label: "Approve".to_string(),
+                reply: format!("approve {}", view.gate_ref),
+            },
+            OutboundChoice {
+                label: "Deny".to_string(),
+                reply: format!("deny {}", view.gate_ref),
+            },
+        ],
+    }
+}
+
 fn gate_prompt_reply_instruction(direct_message: bool, gate_ref: &str) -> String {
     if direct_message {
         format!(
@@ -849,7 +849,7 @@ async fn triggered_notification_for_state(
                 // Preference targets are personal DMs or picked shared
                 // channels; the DM reply instruction applies to the personal
                 // target this delivery resolves to.
-                part: OutboundPart::Text(prompts::gate_prompt_text(&view, true)),
+                part: prompts::gate_prompt_part(&view, true),
                 gate_ref_for_routing: Some(gate_ref.as_str().to_string()),
                 require_direct_message_target: false,
             }))
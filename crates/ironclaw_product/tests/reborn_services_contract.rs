@@ -11144,6 +11144,7 @@ fn skill_info(name: &str) -> RebornSkillInfo {
         can_edit: true,
         can_delete: true,
         auto_activate: true,
+        enabled: true,
     }
 }
 
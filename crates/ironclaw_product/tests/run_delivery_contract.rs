@@ -23,8 +23,8 @@ use ironclaw_outbound::{
 use ironclaw_product::{
     AdapterInstallationId, AuthPromptChallengeKind, AuthPromptView, AuthRequirement,
     AuthResolutionPayload, AuthResolutionResult, ChannelAdapter, ChannelError, DeliveryReport,
-    ExternalActorRef, ExternalConversationRef, ExternalEventId, InboundOutcome, OutboundEnvelope,
-    OutboundPart, PairingPromptView, ParsedProductInbound, PartDeliveryOutcome,
+    ExternalActorRef, ExternalConversationRef, ExternalEventId, InboundOutcome, OutboundChoice,
+    OutboundEnvelope, OutboundPart, PairingPromptView, ParsedProductInbound, PartDeliveryOutcome,
     ProductAdapterError, ProductAdapterId, ProductInboundAck, ProductInboundEnvelope,
     ProductInboundPayload, ProductRejection, ProductRejectionKind, ProductTriggerReason,
     ProtocolAuthEvidence, TrustedInboundContext, UserMessagePayload, VerifiedInbound,
@@ -2309,6 +2309,49 @@ async fn duplicate_lifecycle_events_deliver_once_after_delayed_oauth() {
     assert_eq!(harness.adapter.retracted_refs().len(), 3);
 }
 
+#[tokio::test]
+async fn approval_gate_prompt_is_delivered_as_choices_naming_the_gate() {
+    let harness = build_event_delivery_harness(None, ProductConversationRouteKind::Direct);
+    let submitted = harness.turns.transition(TurnStatus::Running, None, 1);
+    harness.publish(submitted, TurnEventKind::Submitted).await;
+    let blocked = harness.turns.transition(
+        TurnStatus::BlockedApproval,
+        Some("gate:approval-choices"),
+        2,
+    );
+    harness.publish(blocked, TurnEventKind::Blocked).await;
+
+    let choices = harness
+        .adapter
+        .envelopes()
+        .into_iter()
+        .flat_map(|envelope| envelope.parts)
+        .find_map(|part| match part {
+            OutboundPart::Choices { text, choices } => Some((text, choices)),
+            _ => None,
+        })
+        .expect("the approval prompt is delivered as a choices part");
+    let (text, choices) = choices;
+    assert!(
+        text.starts_with("Approval needed\n\n"),
+        "the prompt text is kept for channels without buttons: {text}"
+    );
+    assert!(text.contains("`approve gate:approval-choices`"));
+    assert_eq!(
+        choices,
+        vec![
+            OutboundChoice {
+                label: "Approve".to_string(),
+                reply: "approve gate:approval-choices".to_string(),
+            },
+            OutboundChoice {
+                label: "Deny".to_string(),
+                reply: "deny gate:approval-choices".to_string(),
+            },
+        ]
+    );
+}
+
 #[tokio::test]
 async fn post_admission_reconciliation_does_not_duplicate_initial_working_notice() {
     let harness = build_event_delivery_harness(None, ProductConversationRouteKind::Direct);
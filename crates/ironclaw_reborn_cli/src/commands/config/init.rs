@@ -218,6 +218,9 @@ poll_interval_ms        = 200
 # context. Keyword/tag activation and explicit skill mentions such as
 # `$code-review` still activate skills.
 regex_activation_enabled = true
+# When true, keyword activation tolerates small typos in the user's
+# message ("deploymnet" still matches a `deployment` keyword).
+fuzzy_keyword_activation_enabled = false
 
 # [storage]
 # # PostgreSQL storage selection for hosted-single-tenant / production. The
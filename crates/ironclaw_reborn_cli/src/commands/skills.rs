@@ -106,9 +106,10 @@ fn build_skill_list_config(config: &RebornBootConfig) -> anyhow::Result<SkillLis
 
 fn print_skill(skill: &RebornSkillSummary, verbose: bool) {
     println!(
-        "- {} ({})",
+        "- {} ({}{})",
         crate::render::terminal_safe_text(&skill.name),
-        skill.source.as_str()
+        skill.source.as_str(),
+        if skill.enabled { "" } else { ", disabled" }
     );
     if !skill.description.is_empty() {
         println!(
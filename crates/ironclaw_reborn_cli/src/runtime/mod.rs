@@ -554,6 +554,9 @@ pub(crate) fn build_runtime_input_with_options(
         .with_identity(runtime_identity(runtime_services.config_file.as_ref()))
         .with_regex_skill_activation_enabled(regex_skill_activation_enabled(
             runtime_services.config_file.as_ref(),
+        ))
+        .with_fuzzy_skill_keywords_enabled(fuzzy_skill_keywords_enabled(
+            runtime_services.config_file.as_ref(),
         ));
 
     {
@@ -1167,6 +1170,15 @@ fn regex_skill_activation_enabled(
         .unwrap_or(true)
 }
 
+fn fuzzy_skill_keywords_enabled(
+    config_file: Option<&ironclaw_reborn_config::RebornConfigFile>,
+) -> bool {
+    config_file
+        .and_then(|file| file.skills.as_ref())
+        .and_then(|skills| skills.fuzzy_keyword_activation_enabled)
+        .unwrap_or(false)
+}
+
 pub(crate) fn effective_profile(
     config: &RebornBootConfig,
     config_file: Option<&ironclaw_reborn_config::RebornConfigFile>,
@@ -2129,6 +2141,7 @@ default_owner = "custom-owner"
             r#"
 [skills]
 regex_activation_enabled = false
+fuzzy_keyword_activation_enabled = true
 "#,
         )
         .expect("write config");
@@ -2144,6 +2157,7 @@ regex_activation_enabled = false
             build_runtime_input(&config, RuntimeInputCaller::Run).expect("runtime input");
 
         assert!(!runtime_input.regex_skill_activation_enabled);
+        assert!(runtime_input.fuzzy_skill_keywords_enabled);
     }
 
     #[test]
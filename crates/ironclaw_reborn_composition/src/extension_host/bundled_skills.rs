@@ -103,6 +103,7 @@ pub(crate) fn bundled_reborn_skill_summaries() -> Result<Vec<SkillSummary>, Rebo
             tags: skill.tags,
             requires_skills: skill.requires_skills,
             auto_activate: true,
+            enabled: true,
         })
         .collect())
 }
@@ -122,6 +122,9 @@ pub struct ChannelHostIdentity {
 /// then ingress-only (turns run; no channel lifecycle output is delivered).
 pub(crate) struct ChannelHostDeliveryDeps {
     pub(crate) coordinator: Arc<DeliveryCoordinator>,
+    /// The coordinator's channel resolver; inbound sinks fetch adapter
+    /// context (e.g. thread history) through the same egress.
+    pub(crate) channels: Arc<dyn ironclaw_product::ChannelDeliveryResolver>,
     pub(crate) outbound_store: Arc<dyn OutboundStateStorePort>,
     pub(crate) route_store: Arc<dyn DeliveredGateRouteStore>,
     pub(crate) communication_preferences: Arc<dyn CommunicationPreferenceRepository>,
@@ -614,6 +617,9 @@ impl GenericChannelHostAssembly {
                 .clone()
                 .map(|observer| observer as Arc<dyn PostAdmissionObserver>),
         });
+        if let Some(delivery) = &self.deps.delivery {
+            sink = sink.with_inbound_context(Arc::clone(&delivery.channels));
+        }
         if let Some(pairing) = pairing {
             sink = sink.with_pairing(
                 pairing,
@@ -148,6 +148,7 @@ use crate::{RebornUserIdentityLookup, RebornUserIdentityLookupError};
 use ironclaw_host_ingress::PublicRouteMount;
 use ironclaw_product::AuthChallengeProvider;
 use ironclaw_product::BlockedAuthPromptSource;
+use ironclaw_product::ChannelDeliveryResolver;
 
 #[path = "e2e_auth_challenge.rs"]
 mod e2e_auth_challenge;
@@ -358,6 +359,10 @@ impl Harness {
         self.egress.bodies_for("/api/chat.postMessage")
     }
 
+    fn slack_ephemerals(&self) -> Vec<serde_json::Value> {
+        self.egress.bodies_for("/api/chat.postEphemeral")
+    }
+
     fn slack_deletes(&self) -> Vec<serde_json::Value> {
         self.egress.bodies_for("/api/chat.delete")
     }
@@ -478,12 +483,12 @@ async fn build_harness_with_options(options: HarnessOptions) -> Harness {
             ),
         ),
     );
+    let channels: Arc<dyn ChannelDeliveryResolver> = Arc::new(
+        SnapshotChannelDeliveryResolver::new(host.snapshot_watch(), Arc::new(egress.clone())),
+    );
     let delivery_coordinator = Arc::new(DeliveryCoordinator::new(
         Arc::clone(&outbound_store),
-        Arc::new(SnapshotChannelDeliveryResolver::new(
-            host.snapshot_watch(),
-            Arc::new(egress.clone()),
-        )),
+        Arc::clone(&channels),
         Arc::new(IngressReplyContextSource::new(Arc::clone(
             &ingress.reply_context,
         ))),
@@ -534,6 +539,7 @@ async fn build_harness_with_options(options: HarnessOptions) -> Harness {
             as Arc<dyn crate::provider_identity::RebornUserIdentityLookup>),
         delivery: Some(ChannelHostDeliveryDeps {
             coordinator: delivery_coordinator,
+            channels,
             outbound_store,
             route_store: Arc::clone(&route_store),
             communication_preferences: preferences,
@@ -2481,11 +2487,13 @@ async fn slack_channel_auth_prompt_omits_setup_link_after_immediate_ack() {
     assert_eq!(response.status(), StatusCode::OK);
     harness.drain().await;
 
-    let messages = harness.slack_messages();
-    assert_eq!(messages.len(), 1);
-    assert_eq!(messages[0]["channel"], "C123");
-    assert_eq!(messages[0]["thread_ts"], "1710000000.000008");
-    let text = messages[0]["text"].as_str().expect("Slack message text");
+    assert!(harness.slack_messages().is_empty());
+    let ephemerals = harness.slack_ephemerals();
+    assert_eq!(ephemerals.len(), 1);
+    assert_eq!(ephemerals[0]["channel"], "C123");
+    assert_eq!(ephemerals[0]["user"], "U123");
+    assert_eq!(ephemerals[0]["thread_ts"], "1710000000.000008");
+    let text = ephemerals[0]["text"].as_str().expect("Slack message text");
     assert!(text.contains("Authentication required"));
     assert!(!text.contains("Setup link:"));
     assert!(!text.contains("https://provider.example/oauth"));
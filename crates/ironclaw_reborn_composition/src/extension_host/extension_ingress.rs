@@ -14,6 +14,7 @@
 
 use std::collections::{BTreeSet, HashMap};
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use chrono::Utc;
@@ -21,6 +22,7 @@ use ironclaw_extension_host::ingress::{
     ExtensionIngressRouter, InboundAdmission, InboundAdmissionAck, InboundSink, InboundSinkError,
     IngressPortError, IngressSecretsPort, VerificationCandidate,
 };
+use ironclaw_host_api::product_adapter::{MAX_INBOUND_CONTEXT_BYTES, NormalizedInboundMessage};
 use ironclaw_host_api::{ChannelInboundProductSurface, SecretHandle};
 use ironclaw_product::{
     AdapterInstallationId, ChannelInboundClassification, ExternalConversationRef, ExternalEventId,
@@ -29,14 +31,21 @@ use ironclaw_product::{
     strip_wrapping_inline_code,
 };
 use ironclaw_product::{
-    ChannelInboundSurfaceOutcome, ChannelInboundSurfaceRejectedAdmission,
-    ChannelInboundSurfaceRequest,
+    ChannelDeliveryResolver, ChannelPairingConsumeOutcome, ChannelPairingInterception,
+    ChannelPairingInterceptor,
 };
 use ironclaw_product::{
-    ChannelPairingConsumeOutcome, ChannelPairingInterception, ChannelPairingInterceptor,
+    ChannelInboundSurfaceOutcome, ChannelInboundSurfaceRejectedAdmission,
+    ChannelInboundSurfaceRequest,
 };
 use tokio::task::JoinSet;
 
+/// Upper bound on an adapter's [`ChannelAdapter::inbound_context`] fetch, so
+/// a slow vendor cannot push admission past the ingress deadline.
+///
+/// [`ChannelAdapter::inbound_context`]: ironclaw_host_api::product_adapter::ChannelAdapter::inbound_context
+const INBOUND_CONTEXT_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// Fixed host route paths inside the extension ingress namespace
 /// (`/webhooks/extensions/…`). An extension whose canonical route collides
 /// with one of these fails activation (`SnapshotConflict::ReservedRoute`).
@@ -340,6 +349,7 @@ pub struct GenericChannelInboundSink {
     config: ChannelInboundSinkConfig,
     pairing: Option<Arc<dyn ChannelPairingInterceptor>>,
     pairing_outcome_observer: Option<ChannelPairingOutcomeObserver>,
+    inbound_context: Option<Arc<dyn ChannelDeliveryResolver>>,
     observer_tasks: tokio::sync::Mutex<JoinSet<()>>,
 }
 
@@ -349,10 +359,22 @@ impl GenericChannelInboundSink {
             config,
             pairing: None,
             pairing_outcome_observer: None,
+            inbound_context: None,
             observer_tasks: tokio::sync::Mutex::new(JoinSet::new()),
         }
     }
 
+    /// Prepend the adapter's inbound context (e.g. the thread above a
+    /// mid-thread mention) to user messages, fetched through the channel's
+    /// delivery egress.
+    pub(super) fn with_inbound_context(
+        mut self,
+        channels: Arc<dyn ChannelDeliveryResolver>,
+    ) -> Self {
+        self.inbound_context = Some(channels);
+        self
+    }
+
     pub(super) fn with_pairing(
         mut self,
         pairing: Arc<dyn ChannelPairingInterceptor>,
@@ -417,7 +439,7 @@ impl InboundSink for GenericChannelInboundSink {
         admission: InboundAdmission,
     ) -> Result<InboundAdmissionAck, InboundSinkError> {
         let InboundAdmission {
-            extension_id: _,
+            extension_id,
             installation_id,
             message,
         } = admission;
@@ -479,6 +501,13 @@ impl InboundSink for GenericChannelInboundSink {
             }
             None => None,
         };
+        // Only user messages gain context; interaction commands stay verbatim.
+        let message = match (&self.inbound_context, &classification) {
+            (Some(channels), None) => {
+                with_inbound_context(channels.as_ref(), &extension_id, message).await
+            }
+            _ => message,
+        };
         // Durable dedupe + admission commit (idempotency ledger keyed by
         // installation + external event fingerprint) plus identity/
         // conversation binding and turn submission — synchronous, so the
@@ -553,6 +582,36 @@ impl InboundSink for GenericChannelInboundSink {
     }
 }
 
+/// `message` with the adapter's inbound context prepended. Best-effort: an
+/// unresolved channel, a timeout, or an out-of-bounds context admits the
+/// message as parsed.
+async fn with_inbound_context(
+    channels: &dyn ChannelDeliveryResolver,
+    extension_id: &str,
+    mut message: NormalizedInboundMessage,
+) -> NormalizedInboundMessage {
+    let Some(channel) = channels.resolve_channel_delivery(extension_id) else {
+        return message;
+    };
+    let context = tokio::time::timeout(
+        INBOUND_CONTEXT_TIMEOUT,
+        channel
+            .adapter
+            .inbound_context(&message, channel.egress.as_ref()),
+    )
+    .await;
+    match context {
+        Ok(Some(context)) if !context.is_empty() && context.len() <= MAX_INBOUND_CONTEXT_BYTES => {
+            message.text = format!("{context}\n\n{}", message.text);
+        }
+        Ok(_) => {}
+        Err(_) => {
+            tracing::debug!(extension_id, "channel inbound context timed out");
+        }
+    }
+    message
+}
+
 /// A static secrets port: fixed candidates for one extension (operator
 /// config resolved at registration time). Dynamic setups implement
 /// [`IngressSecretsPort`] directly and re-read their stores per request.
@@ -790,10 +849,14 @@ mod tests {
 
     use ironclaw_host_api::ChannelInboundProductSurface;
     use ironclaw_host_api::UserId;
+    use ironclaw_host_api::{
+        RestrictedEgress, RestrictedEgressError, RestrictedEgressRequest, RestrictedEgressResponse,
+    };
     use ironclaw_product::{
-        ChannelAdapter, ExternalActorRef, ExternalConversationRef, ExternalEventId, InboundOutcome,
-        NormalizedInboundMessage, ParsedProductInbound, ProductInboundPayload,
-        ProductTriggerReason, TrustedInboundContext, UserMessagePayload, VerifiedInbound,
+        ChannelAdapter, ChannelError, DeliveryReport, ExternalActorRef, ExternalConversationRef,
+        ExternalEventId, InboundOutcome, NormalizedInboundMessage, OutboundEnvelope,
+        ParsedProductInbound, ProductInboundPayload, ProductTriggerReason, ResolvedChannelDelivery,
+        TrustedInboundContext, UserMessagePayload, VerifiedInbound,
     };
     use ironclaw_product::{ChannelInboundSurfaceAdmission, ChannelInboundSurfaceOutcome};
     use ironclaw_turns::{AcceptedMessageRef, TurnRunId};
@@ -1157,6 +1220,90 @@ mod tests {
         );
     }
 
+    /// Adapter fake that supplies fixed inbound context.
+    struct ContextAdapter;
+
+    #[async_trait]
+    impl ChannelAdapter for ContextAdapter {
+        fn inbound(&self, _request: VerifiedInbound<'_>) -> Result<InboundOutcome, ChannelError> {
+            unreachable!("the sink admits already-normalized messages")
+        }
+
+        async fn deliver(
+            &self,
+            _envelope: OutboundEnvelope,
+            _egress: &dyn RestrictedEgress,
+        ) -> Result<DeliveryReport, ChannelError> {
+            unreachable!("inbound context never delivers")
+        }
+
+        async fn inbound_context(
+            &self,
+            _message: &NormalizedInboundMessage,
+            _egress: &dyn RestrictedEgress,
+        ) -> Option<String> {
+            Some("earlier in the thread".to_string())
+        }
+    }
+
+    struct NoopEgress;
+
+    #[async_trait]
+    impl RestrictedEgress for NoopEgress {
+        async fn send(
+            &self,
+            _request: RestrictedEgressRequest,
+        ) -> Result<RestrictedEgressResponse, RestrictedEgressError> {
+            unreachable!("the context fake never reaches the network")
+        }
+    }
+
+    struct ContextResolver;
+
+    impl ChannelDeliveryResolver for ContextResolver {
+        fn resolve_channel_delivery(&self, extension_id: &str) -> Option<ResolvedChannelDelivery> {
+            Some(ResolvedChannelDelivery {
+                extension_id: extension_id.to_string(),
+                installation_id: "install".to_string(),
+                adapter: Arc::new(ContextAdapter),
+                egress: Arc::new(NoopEgress),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn adapter_context_is_prepended_to_user_messages_only() {
+        let surface = Arc::new(CountingSurface::new());
+        let sink = GenericChannelInboundSink::new(ChannelInboundSinkConfig {
+            adapter_id: ProductAdapterId::new("vendorx").expect("adapter id"),
+            evidence: VerifiedEvidenceMint::SharedSecretHeader {
+                header: "X-Vendor-Secret".to_string(),
+            },
+            surface: Arc::clone(&surface) as Arc<dyn ChannelInboundProductSurface>,
+            observer: None,
+        })
+        .with_inbound_context(Arc::new(ContextResolver));
+
+        sink.admit(admission_for("what did we decide?"))
+            .await
+            .expect("user message reaches the workflow");
+        let mut gate = admission_for("approve gate:approval-xyz");
+        gate.message.event_id = ExternalEventId::new("evt-2").expect("event");
+        sink.admit(gate)
+            .await
+            .expect("gate command reaches the workflow");
+
+        let payloads = surface.payloads();
+        let [
+            ProductInboundPayload::UserMessage(message),
+            ProductInboundPayload::ApprovalResolution(_),
+        ] = payloads.as_slice()
+        else {
+            panic!("unexpected payloads: {payloads:?}");
+        };
+        assert_eq!(message.text, "earlier in the thread\n\nwhat did we decide?");
+    }
+
     struct FailingSink;
 
     #[async_trait]
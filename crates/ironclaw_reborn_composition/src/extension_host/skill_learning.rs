@@ -1322,6 +1322,7 @@ mod learning {
                 tags: tags.iter().map(|t| t.to_string()).collect(),
                 requires_skills: Vec::new(),
                 auto_activate: true,
+                enabled: true,
             }
         }
 
@@ -30,6 +30,13 @@ pub async fn list_reborn_local_skills(
         .iter()
         .map(|skill| skill.name.clone())
         .collect::<HashSet<_>>();
+    // Bundled summaries replace the installed system copies below; keep the
+    // operator's enablement choice from the copy being replaced.
+    let disabled_names = skills
+        .iter()
+        .filter(|skill| !skill.enabled)
+        .map(|skill| skill.name.clone())
+        .collect::<HashSet<_>>();
     skills.retain(|skill| {
         !(skill.source == ManagedSkillSource::System && bundled_names.contains(&skill.name))
     });
@@ -41,7 +48,11 @@ pub async fn list_reborn_local_skills(
     skills.extend(
         bundled_skills
             .into_iter()
-            .filter(|skill| !existing_keys.contains(&(skill.name.clone(), skill.source.as_str()))),
+            .filter(|skill| !existing_keys.contains(&(skill.name.clone(), skill.source.as_str())))
+            .map(|mut skill| {
+                skill.enabled = !disabled_names.contains(&skill.name);
+                skill
+            }),
     );
     skills.sort_by(|left, right| {
         left.name
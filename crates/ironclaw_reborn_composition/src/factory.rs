@@ -595,20 +595,25 @@ impl RebornRuntimeStores {
         let outbound_state = Arc::clone(&self.outbound_state);
         let delivered_gate_routes = Arc::clone(&self.delivered_gate_routes);
         let outbound_preferences = Arc::clone(&self.outbound_preferences);
-        let delivery = self.delivery_coordinator.clone().map(|coordinator| {
-            crate::extension_host::channel_host::ChannelHostDeliveryDeps {
-                coordinator,
-                outbound_store: Arc::clone(&outbound_state),
-                route_store: Arc::clone(&delivered_gate_routes),
-                communication_preferences: Arc::clone(&outbound_preferences),
-                current_delivery_targets: Arc::clone(&self.current_delivery_targets)
-                    as Arc<dyn CurrentDeliveryTargetResolver>,
-                approval_context,
-                blocked_auth_prompts,
-                auth_flow_cancel,
-                event_router: run_delivery_events,
-            }
-        });
+        let delivery = self
+            .delivery_coordinator
+            .clone()
+            .zip(self.channel_delivery_resolver.clone())
+            .map(|(coordinator, channels)| {
+                crate::extension_host::channel_host::ChannelHostDeliveryDeps {
+                    coordinator,
+                    channels,
+                    outbound_store: Arc::clone(&outbound_state),
+                    route_store: Arc::clone(&delivered_gate_routes),
+                    communication_preferences: Arc::clone(&outbound_preferences),
+                    current_delivery_targets: Arc::clone(&self.current_delivery_targets)
+                        as Arc<dyn CurrentDeliveryTargetResolver>,
+                    approval_context,
+                    blocked_auth_prompts,
+                    auth_flow_cancel,
+                    event_router: run_delivery_events,
+                }
+            });
 
         let identity_lookup = Some(Arc::clone(&self.channel_identity_store)
             as Arc<dyn crate::provider_identity::RebornUserIdentityLookup>);
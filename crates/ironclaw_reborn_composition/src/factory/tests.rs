@@ -23,9 +23,9 @@ use ironclaw_host_api::{
 use ironclaw_host_runtime::{
     MEMORY_SEARCH_CAPABILITY_ID, MEMORY_TREE_CAPABILITY_ID, MEMORY_WRITE_CAPABILITY_ID,
     RuntimeCapabilityOutcome, RuntimeFailureKind, SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID,
-    SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID,
-    SKILL_UPDATE_CAPABILITY_ID, TRIGGER_CREATE_CAPABILITY_ID, TRIGGER_LIST_CAPABILITY_ID,
-    TRIGGER_REMOVE_CAPABILITY_ID,
+    SKILL_ENABLED_SET_CAPABILITY_ID, SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID,
+    SKILL_REMOVE_CAPABILITY_ID, SKILL_UPDATE_CAPABILITY_ID, TRIGGER_CREATE_CAPABILITY_ID,
+    TRIGGER_LIST_CAPABILITY_ID, TRIGGER_REMOVE_CAPABILITY_ID,
 };
 use ironclaw_host_runtime::{RuntimeCredentialAccountRequest, RuntimeCredentialAccountResolver};
 use ironclaw_product::{LifecyclePackageKind, LifecyclePackageRef, LifecyclePublicState};
@@ -2275,6 +2275,46 @@ async fn local_dev_skill_management_invokes_through_first_party_runtime() {
     .expect("updated skill");
     assert!(updated_skill.contains("auto_activate: false"));
 
+    let enabled_output = invoke_json(
+        &services,
+        SKILL_ENABLED_SET_CAPABILITY_ID,
+        skill_context(SKILL_ENABLED_SET_CAPABILITY_ID),
+        serde_json::json!({
+            "name": "runtime-sentinel",
+            "enabled": false
+        }),
+    )
+    .await
+    .expect("skill enablement update succeeds");
+    assert_eq!(enabled_output["updated"], true);
+    assert_eq!(enabled_output["enabled"], false);
+    let skill_state = std::fs::read_to_string(
+        storage_root
+            .join("tenants/default/users/local-dev-test-user/skills/.ironclaw-skill-state.json"),
+    )
+    .expect("skill state written");
+    assert_eq!(
+        ironclaw_skills::SkillRegistryState::from_json(skill_state.as_bytes())
+            .expect("skill state parses")
+            .disabled,
+        vec!["runtime-sentinel".to_string()]
+    );
+    let list_output = invoke_json(
+        &services,
+        SKILL_LIST_CAPABILITY_ID,
+        skill_context(SKILL_LIST_CAPABILITY_ID),
+        serde_json::json!({}),
+    )
+    .await
+    .expect("skill list succeeds");
+    assert!(
+        list_output["skills"]
+            .as_array()
+            .expect("skills array")
+            .iter()
+            .any(|skill| skill["name"] == "runtime-sentinel" && skill["enabled"] == false)
+    );
+
     let remove_output = invoke_json(
         &services,
         SKILL_REMOVE_CAPABILITY_ID,
@@ -2418,6 +2458,7 @@ fn builtin_first_party_package_declares_skill_management_tools() {
     assert!(ids.contains(&SKILL_INSTALL_CAPABILITY_ID));
     assert!(ids.contains(&SKILL_UPDATE_CAPABILITY_ID));
     assert!(ids.contains(&SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID));
+    assert!(ids.contains(&SKILL_ENABLED_SET_CAPABILITY_ID));
     assert!(ids.contains(&SKILL_REMOVE_CAPABILITY_ID));
     assert!(ids.contains(&TRIGGER_CREATE_CAPABILITY_ID));
     assert!(ids.contains(&TRIGGER_LIST_CAPABILITY_ID));
@@ -2432,6 +2473,7 @@ fn builtin_first_party_package_declares_skill_management_tools() {
         SKILL_INSTALL_CAPABILITY_ID,
         SKILL_UPDATE_CAPABILITY_ID,
         SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID,
+        SKILL_ENABLED_SET_CAPABILITY_ID,
         SKILL_REMOVE_CAPABILITY_ID,
         TRIGGER_CREATE_CAPABILITY_ID,
         TRIGGER_LIST_CAPABILITY_ID,
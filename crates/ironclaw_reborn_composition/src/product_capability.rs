@@ -20,8 +20,8 @@ use ironclaw_host_api::{
 use ironclaw_host_runtime::{HostRuntime, RuntimeCapabilityOutcome, RuntimeFailureKind};
 use ironclaw_product::{
     EXTENSION_INSTALL_CAPABILITY_ID, EXTENSION_REMOVE_CAPABILITY_ID, ProductCapabilityInvoker,
-    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_INSTALL_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID,
-    SKILL_UPDATE_CAPABILITY_ID,
+    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_ENABLED_SET_CAPABILITY_ID,
+    SKILL_INSTALL_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID, SKILL_UPDATE_CAPABILITY_ID,
 };
 
 use crate::RebornRuntime;
@@ -294,6 +294,7 @@ fn is_skill_management_capability(capability: &CapabilityId) -> bool {
             | SKILL_UPDATE_CAPABILITY_ID
             | SKILL_REMOVE_CAPABILITY_ID
             | SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID
+            | SKILL_ENABLED_SET_CAPABILITY_ID
     )
 }
 
@@ -456,6 +456,7 @@ fn skill_info(skill: ironclaw_skills::SkillSummary) -> RebornSkillInfo {
         can_edit: can_manage,
         can_delete: can_manage,
         auto_activate: skill.auto_activate,
+        enabled: skill.enabled,
     }
 }
 
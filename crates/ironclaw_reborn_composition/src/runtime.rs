@@ -1507,20 +1507,25 @@ impl RebornRuntime {
         let workflow_state = Arc::new(ironclaw_product::ChannelWorkflowStateService::new(
             workflow_filesystem,
         ));
-        let delivery = self.delivery_coordinator.clone().map(|coordinator| {
-            crate::extension_host::channel_host::ChannelHostDeliveryDeps {
-                coordinator,
-                outbound_store: Arc::clone(&self.outbound_state),
-                route_store: Arc::clone(&self.delivered_gate_routes),
-                communication_preferences: Arc::clone(&self.outbound_preferences),
-                current_delivery_targets: Arc::clone(&self.current_delivery_targets)
-                    as Arc<dyn ironclaw_product::CurrentDeliveryTargetResolver>,
-                approval_context: None,
-                blocked_auth_prompts: None,
-                auth_flow_cancel: None,
-                event_router: run_delivery_events,
-            }
-        });
+        let delivery = self
+            .delivery_coordinator
+            .clone()
+            .zip(self.channel_delivery_resolver.clone())
+            .map(|(coordinator, channels)| {
+                crate::extension_host::channel_host::ChannelHostDeliveryDeps {
+                    coordinator,
+                    channels,
+                    outbound_store: Arc::clone(&self.outbound_state),
+                    route_store: Arc::clone(&self.delivered_gate_routes),
+                    communication_preferences: Arc::clone(&self.outbound_preferences),
+                    current_delivery_targets: Arc::clone(&self.current_delivery_targets)
+                        as Arc<dyn ironclaw_product::CurrentDeliveryTargetResolver>,
+                    approval_context: None,
+                    blocked_auth_prompts: None,
+                    auth_flow_cancel: None,
+                    event_router: run_delivery_events,
+                }
+            });
         let identity_lookup = Some(Arc::clone(&self.channel_identity_store)
             as Arc<dyn crate::provider_identity::RebornUserIdentityLookup>);
         Some(
@@ -3337,6 +3342,7 @@ pub async fn build_runtime(input: RebornRuntimeInput) -> Result<RebornRuntime, R
         identity,
         default_project_id,
         regex_skill_activation_enabled,
+        fuzzy_skill_keywords_enabled,
         skill_context_source: configured_skill_context_source,
         hooks: hooks_config,
         budget_defaults,
@@ -3484,6 +3490,7 @@ pub async fn build_runtime(input: RebornRuntimeInput) -> Result<RebornRuntime, R
                 runtime,
                 &validated_identity.tenant_id,
                 regex_skill_activation_enabled,
+                fuzzy_skill_keywords_enabled,
             )?;
             let skill_warm_scope = ResourceScope {
                 tenant_id: validated_identity.tenant_id.clone(),
@@ -4783,7 +4790,8 @@ fn optional_nonzero_u32_env(
 /// Build the [`SkillActivationSelectorConfig`] used by the local-dev
 /// filesystem skill context source. Extracted from
 /// [`local_dev_filesystem_skill_context_source`] so the wiring of the
-/// `regex_skill_activation_enabled` flag from [`RebornRuntimeInput`] is
+/// `regex_skill_activation_enabled` and `fuzzy_skill_keywords_enabled`
+/// flags from [`RebornRuntimeInput`] is
 /// covered by a unit test (see `tests::local_dev_selector_config_*`).
 /// Without this seam the propagation was tested only indirectly through
 /// the full [`build_reborn_runtime`] path, where an accidental
@@ -4791,6 +4799,7 @@ fn optional_nonzero_u32_env(
 /// through silently.
 fn local_dev_selector_config(
     regex_skill_activation_enabled: bool,
+    fuzzy_skill_keywords_enabled: bool,
     injection_mode: SkillInjectionMode,
 ) -> SkillActivationSelectorConfig {
     SkillActivationSelectorConfig {
@@ -4807,6 +4816,8 @@ fn local_dev_selector_config(
         selection_mode:
             ironclaw_first_party_extension_ports::SkillActivationSelectionMode::ExplicitAndCriteria,
         regex_activation_enabled: regex_skill_activation_enabled,
+        fuzzy_keywords: fuzzy_skill_keywords_enabled
+            .then(ironclaw_skills::FuzzyKeywordMatch::default),
         injection_mode,
         ..SkillActivationSelectorConfig::default()
     }
@@ -4848,6 +4859,7 @@ fn local_dev_filesystem_skill_context_source(
     runtime: &RebornRuntimeStores,
     tenant_id: &TenantId,
     regex_skill_activation_enabled: bool,
+    fuzzy_skill_keywords_enabled: bool,
 ) -> Result<ComposedSkillContextSource, RebornRuntimeError> {
     let skill_filesystem = &runtime.skill_filesystem;
     let workspace_filesystem = &runtime.workspace_filesystem;
@@ -4864,8 +4876,11 @@ fn local_dev_filesystem_skill_context_source(
     .map_err(|reason| RebornRuntimeError::InvalidArgument {
         reason: format!("first-party skills extension source: {reason}"),
     })?;
-    let selector_config =
-        local_dev_selector_config(regex_skill_activation_enabled, skill_injection_mode_env()?);
+    let selector_config = local_dev_selector_config(
+        regex_skill_activation_enabled,
+        fuzzy_skill_keywords_enabled,
+        skill_injection_mode_env()?,
+    );
     let selectable_skills = extension.selectable_skill_runtime_with_setup_markers(
         selector_config,
         Arc::clone(workspace_filesystem),
@@ -23,8 +23,9 @@ mod tests {
         APPLY_PATCH_CAPABILITY_ID, GLOB_CAPABILITY_ID, GREP_CAPABILITY_ID, HTTP_CAPABILITY_ID,
         HTTP_SAVE_CAPABILITY_ID, LIST_DIR_CAPABILITY_ID, MEMORY_WRITE_CAPABILITY_ID,
         READ_FILE_CAPABILITY_ID, SHELL_CAPABILITY_ID, SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID,
-        SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID,
-        SKILL_UPDATE_CAPABILITY_ID, SPAWN_SUBAGENT_CAPABILITY_ID, WRITE_FILE_CAPABILITY_ID,
+        SKILL_ENABLED_SET_CAPABILITY_ID, SKILL_INSTALL_CAPABILITY_ID, SKILL_LIST_CAPABILITY_ID,
+        SKILL_REMOVE_CAPABILITY_ID, SKILL_UPDATE_CAPABILITY_ID, SPAWN_SUBAGENT_CAPABILITY_ID,
+        WRITE_FILE_CAPABILITY_ID,
     };
     use ironclaw_loop_host::{
         CapabilityWriteResult, DurablePersistence, HostManagedModelError,
@@ -2001,6 +2002,7 @@ mod tests {
         assert!(capability_ids.contains(&SKILL_INSTALL_CAPABILITY_ID));
         assert!(capability_ids.contains(&SKILL_UPDATE_CAPABILITY_ID));
         assert!(capability_ids.contains(&SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID));
+        assert!(capability_ids.contains(&SKILL_ENABLED_SET_CAPABILITY_ID));
         assert!(capability_ids.contains(&SKILL_REMOVE_CAPABILITY_ID));
         assert!(capability_ids.contains(&SHELL_CAPABILITY_ID));
         assert!(capability_ids.contains(&HTTP_CAPABILITY_ID));
@@ -2258,6 +2260,21 @@ mod tests {
             NetworkPolicy::default()
         );
 
+        let skill_enabled_grant = grant_for(SKILL_ENABLED_SET_CAPABILITY_ID);
+        assert_eq!(
+            skill_enabled_grant.constraints.allowed_effects,
+            vec![
+                EffectKind::DispatchCapability,
+                EffectKind::ReadFilesystem,
+                EffectKind::WriteFilesystem,
+            ]
+        );
+        assert_eq!(skill_enabled_grant.constraints.mounts, skill_mounts);
+        assert_eq!(
+            skill_enabled_grant.constraints.network,
+            NetworkPolicy::default()
+        );
+
         let skill_remove_grant = grant_for(SKILL_REMOVE_CAPABILITY_ID);
         assert_eq!(
             skill_remove_grant.constraints.allowed_effects,
@@ -2322,6 +2339,7 @@ mod tests {
             runtime_surfaces,
             &run_context.scope.tenant_id,
             false,
+            false,
         )
         .expect("skill context source");
         let activation_source = skill_context.activation_source;
@@ -2506,6 +2524,7 @@ mod tests {
             runtime_surfaces,
             &run_context.scope.tenant_id,
             false,
+            false,
         )
         .expect("skill context source");
         let policy = Arc::new(
@@ -259,6 +259,7 @@ async fn runtime_channel_identity_bind_uses_deployment_channel_before_user_activ
 #[test]
 fn local_dev_selector_config_propagates_regex_activation_disabled() {
     let cfg = super::local_dev_selector_config(
+        false,
         false,
         ironclaw_first_party_extension_ports::SkillInjectionMode::Listing,
     );
@@ -280,6 +281,7 @@ fn local_dev_selector_config_propagates_regex_activation_disabled() {
 fn local_dev_selector_config_propagates_regex_activation_enabled() {
     let cfg = super::local_dev_selector_config(
         true,
+        false,
         ironclaw_first_party_extension_ports::SkillInjectionMode::Listing,
     );
     assert!(
@@ -288,10 +290,25 @@ fn local_dev_selector_config_propagates_regex_activation_enabled() {
     );
 }
 
+#[test]
+fn local_dev_selector_config_propagates_fuzzy_keyword_activation() {
+    let listing = ironclaw_first_party_extension_ports::SkillInjectionMode::Listing;
+    assert_eq!(
+        super::local_dev_selector_config(true, false, listing).fuzzy_keywords,
+        None
+    );
+    assert_eq!(
+        super::local_dev_selector_config(true, true, listing).fuzzy_keywords,
+        Some(ironclaw_skills::FuzzyKeywordMatch::default()),
+        "fuzzy_skill_keywords_enabled=true must propagate into SkillActivationSelectorConfig"
+    );
+}
+
 #[test]
 fn local_dev_selector_config_uses_large_skill_context_budget() {
     let cfg = super::local_dev_selector_config(
         true,
+        false,
         ironclaw_first_party_extension_ports::SkillInjectionMode::Listing,
     );
     assert_eq!(
@@ -311,7 +328,7 @@ fn local_dev_selector_config_propagates_injection_mode() {
         ironclaw_first_party_extension_ports::SkillInjectionMode::Listing,
         ironclaw_first_party_extension_ports::SkillInjectionMode::Full,
     ] {
-        let cfg = super::local_dev_selector_config(true, mode);
+        let cfg = super::local_dev_selector_config(true, false, mode);
         assert_eq!(cfg.injection_mode, mode);
     }
 }
@@ -496,6 +496,9 @@ pub struct RebornRuntimeInput {
     /// otherwise the loop host rejects the run before model execution.
     pub default_project_id: Option<ProjectId>,
     pub regex_skill_activation_enabled: bool,
+    /// Typo-tolerant keyword activation for the local filesystem skill
+    /// selector. Off by default.
+    pub fuzzy_skill_keywords_enabled: bool,
     pub skill_context_source: Option<Arc<dyn HostSkillContextSource>>,
     /// Hook-framework activation knobs. Default OFF. Callers resolve
     /// environment or config into this typed value once at the edge.
@@ -562,6 +565,7 @@ impl RebornRuntimeInput {
             identity: RebornRuntimeIdentity::default(),
             default_project_id: None,
             regex_skill_activation_enabled: true,
+            fuzzy_skill_keywords_enabled: false,
             skill_context_source: None,
             hooks: HooksActivationConfig::default(),
             budget_defaults: None,
@@ -750,6 +754,11 @@ impl RebornRuntimeInput {
         self
     }
 
+    pub fn with_fuzzy_skill_keywords_enabled(mut self, enabled: bool) -> Self {
+        self.fuzzy_skill_keywords_enabled = enabled;
+        self
+    }
+
     /// Override the runtime owner id after the input (and its host-access
     /// disclosure gate) has been built. The WebChat v2 serve path uses this to
     /// align the runtime owner with the authenticated WebUI user. No-op when
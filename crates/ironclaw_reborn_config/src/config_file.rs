@@ -202,6 +202,9 @@ pub struct SkillsSection {
     /// When false, regex activation criteria no longer auto-load full skill context.
     /// Keyword/tag activation and explicit skill mentions still work.
     pub regex_activation_enabled: Option<bool>,
+    /// When true, keyword activation tolerates small typos ("deploymnet"
+    /// still matches `deployment`). Off by default.
+    pub fuzzy_keyword_activation_enabled: Option<bool>,
 }
 
 /// Durable storage backend names accepted by the Reborn production boot config.
@@ -1517,6 +1520,7 @@ poll_interval_ms = 200
 
 [skills]
 regex_activation_enabled = false
+fuzzy_keyword_activation_enabled = true
 
 [storage]
 backend = "postgres"
@@ -1553,6 +1557,13 @@ api_key_env = "ANTHROPIC_API_KEY"
             cfg.skills.as_ref().unwrap().regex_activation_enabled,
             Some(false)
         );
+        assert_eq!(
+            cfg.skills
+                .as_ref()
+                .unwrap()
+                .fuzzy_keyword_activation_enabled,
+            Some(true)
+        );
         let storage = cfg.storage.as_ref().expect("storage section present");
         assert_eq!(storage.backend, Some(StorageBackend::Postgres));
         assert_eq!(
@@ -253,6 +253,30 @@ where
         Self::new(Arc::new(scoped), Arc::new(SecretsCrypto::ephemeral()))
     }
 
+    /// Deletes every secret under exactly the caller's owner scope whose
+    /// `expires_at` has passed and returns the removed handles.
+    ///
+    /// Expired secrets already refuse new leases; this is the maintenance
+    /// sweep that stops them accumulating (e.g. superseded OAuth access
+    /// tokens). Secrets without an expiry are never removed.
+    pub async fn prune_expired(
+        &self,
+        scope: &ResourceScope,
+    ) -> Result<Vec<SecretHandle>, SecretStoreError> {
+        let now = Utc::now();
+        let mut pruned = Vec::new();
+        for metadata in self.metadata_for_scope(scope).await? {
+            if metadata
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= now)
+                && self.delete(scope, &metadata.handle).await?
+            {
+                pruned.push(metadata.handle);
+            }
+        }
+        Ok(pruned)
+    }
+
     // The FS-stored master-key sentinel and `verify_can_decrypt_existing_secrets`
     // method that used to live here were removed when the per-tenant
     // `ScopedFilesystem` design landed: the sentinel record would have moved to
@@ -1793,6 +1817,47 @@ mod tests {
         assert_eq!(handles, vec!["api_key", "model_key"]);
     }
 
+    #[tokio::test]
+    async fn filesystem_secret_store_prunes_only_expired_secrets() {
+        let fs = Arc::new(InMemoryBackend::new());
+        let store = SecretStore::new(default_scoped_fs(fs), test_crypto());
+        let scope = sample_scope("tenant-a", "user-a");
+        let expired = SecretHandle::new("expired_token").unwrap();
+        let fresh = SecretHandle::new("fresh_token").unwrap();
+        let permanent = SecretHandle::new("api_key").unwrap();
+
+        for (handle, expires_at) in [
+            (&expired, Some(Utc::now() - chrono::Duration::seconds(1))),
+            (&fresh, Some(Utc::now() + chrono::Duration::seconds(60))),
+            (&permanent, None),
+        ] {
+            store
+                .put(
+                    scope.clone(),
+                    handle.clone(),
+                    SecretMaterial::from("material"),
+                    expires_at,
+                )
+                .await
+                .unwrap();
+        }
+        assert!(
+            store
+                .lease_once(&scope, &expired)
+                .await
+                .unwrap_err()
+                .is_expired()
+        );
+
+        let pruned = store.prune_expired(&scope).await.unwrap();
+
+        assert_eq!(pruned, vec![expired.clone()]);
+        assert!(store.metadata(&scope, &expired).await.unwrap().is_none());
+        assert!(store.metadata(&scope, &fresh).await.unwrap().is_some());
+        assert!(store.metadata(&scope, &permanent).await.unwrap().is_some());
+        assert!(store.prune_expired(&scope).await.unwrap().is_empty());
+    }
+
     /// Operator-wide secrets are stored under [`ResourceScope::system`], whose
     /// reserved tenant/user id carries control bytes that normal `TenantId`
     /// validation rejects. The persisted `StoredSecret` tags the entry with
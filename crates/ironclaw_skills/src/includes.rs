@@ -0,0 +1,440 @@
+//! `includes` composition: prepend other loaded skills' prompts to a skill.
+//!
+//! A skill that lists `includes` gets each included skill's effective prompt
+//! (escaped, in declaration order) prepended to its own. Included skills are
+//! resolved first, so nested includes compose transitively. The content hash
+//! is recomputed over the combined prompt, and trust is attenuated to the
+//! minimum across the skill and everything it includes.
+
+use std::collections::HashMap;
+
+use crate::parser::{SkillParseError, split_skill_md_frontmatter};
+use crate::types::{LoadedSkill, MAX_PROMPT_FILE_SIZE, SkillTrust, compute_hash};
+use crate::validation::escape_skill_content;
+
+/// Maximum include nesting below the including skill.
+pub const MAX_SKILL_INCLUDE_DEPTH: usize = 3;
+
+/// Why a skill's `includes` could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SkillIncludeError {
+    #[error("included skill '{0}' is not loaded")]
+    Missing(String),
+
+    #[error("include cycle: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+
+    #[error("includes nest deeper than {MAX_SKILL_INCLUDE_DEPTH} levels at '{0}'")]
+    TooDeep(String),
+
+    #[error("included skill '{0}' could not be resolved")]
+    Unresolved(String),
+
+    #[error("resolved prompt is {size} bytes (max {MAX_PROMPT_FILE_SIZE} bytes)")]
+    TooLarge { size: usize },
+}
+
+/// Resolve `includes` for every skill in a freshly discovered set.
+///
+/// Skills whose includes fail to resolve are removed from `skills` and
+/// returned with the reason; a skill including a removed skill is removed too.
+pub fn resolve_skill_includes(skills: &mut Vec<LoadedSkill>) -> Vec<(String, SkillIncludeError)> {
+    let index: HashMap<String, usize> = skills
+        .iter()
+        .enumerate()
+        .map(|(position, skill)| (skill.name().to_string(), position))
+        .collect();
+
+    let mut outcome: Vec<Option<Result<(), SkillIncludeError>>> = skills
+        .iter()
+        .map(|skill| {
+            if skill.manifest.includes.is_empty() {
+                return Some(Ok(()));
+            }
+            let includes_of = |name: &str| {
+                index
+                    .get(name)
+                    .map(|&position| skills[position].manifest.includes.as_slice())
+            };
+            check_include_graph(skill.name(), &includes_of)
+                .err()
+                .map(Err)
+        })
+        .collect();
+
+    for position in 0..skills.len() {
+        compose_at(position, skills, &index, &mut outcome);
+    }
+
+    let mut failures = Vec::new();
+    let mut outcome = outcome.into_iter();
+    skills.retain(|skill| match outcome.next().flatten() {
+        Some(Err(error)) => {
+            failures.push((skill.name().to_string(), error));
+            false
+        }
+        _ => true,
+    });
+    failures
+}
+
+/// Rewrite `skill_md` with its prompt body replaced by `prompt_content`,
+/// keeping the frontmatter as written.
+///
+/// For hosts that serve skills as `SKILL.md` text: after
+/// [`resolve_skill_includes`] composes a skill, this yields a file whose
+/// parsed prompt is the composed one.
+pub fn compose_skill_md(skill_md: &str, prompt_content: &str) -> Result<String, SkillParseError> {
+    let (frontmatter, _) = split_skill_md_frontmatter(skill_md)?;
+    Ok(format!("---\n{frontmatter}---\n\n{prompt_content}"))
+}
+
+/// Resolve `includes` for one skill against an already-resolved loaded set.
+///
+/// Used when a single skill is installed or updated at runtime. The loaded
+/// skills' prompts already carry their own includes; when `skill` replaces a
+/// loaded skill of the same name, its new `includes` are the ones checked.
+#[cfg(feature = "registry")]
+pub(crate) fn resolve_single_skill_includes(
+    skill: &mut LoadedSkill,
+    loaded: &[LoadedSkill],
+) -> Result<(), SkillIncludeError> {
+    if skill.manifest.includes.is_empty() {
+        return Ok(());
+    }
+    let find = |name: &str| loaded.iter().find(|loaded| loaded.name() == name);
+    let includes_of = |name: &str| {
+        if name == skill.name() {
+            Some(skill.manifest.includes.as_slice())
+        } else {
+            find(name).map(|loaded| loaded.manifest.includes.as_slice())
+        }
+    };
+    check_include_graph(skill.name(), &includes_of)?;
+
+    let parts = skill
+        .manifest
+        .includes
+        .iter()
+        .map(|name| {
+            find(name)
+                .map(|included| (included.prompt_content.clone(), included.trust))
+                .ok_or_else(|| SkillIncludeError::Missing(name.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    apply_includes(skill, parts)
+}
+
+/// Recompose the loaded set with `updated` replacing the loaded skill of the
+/// same name, so skills that include it pick up its new prompt and trust.
+///
+/// `uncomposed` holds the as-loaded copy of every skill that declares
+/// `includes`. When anything no longer resolves, the failure of `updated`
+/// itself is preferred, otherwise the first dependent that broke.
+#[cfg(feature = "registry")]
+pub(crate) fn recompose_with_update(
+    loaded: &[LoadedSkill],
+    uncomposed: &HashMap<String, LoadedSkill>,
+    updated: &LoadedSkill,
+) -> Result<Vec<LoadedSkill>, (String, SkillIncludeError)> {
+    let mut skills: Vec<LoadedSkill> = loaded
+        .iter()
+        .map(|skill| {
+            if skill.name() == updated.name() {
+                updated.clone()
+            } else {
+                uncomposed.get(skill.name()).unwrap_or(skill).clone()
+            }
+        })
+        .collect();
+    let mut failures = resolve_skill_includes(&mut skills);
+    if failures.is_empty() {
+        return Ok(skills);
+    }
+    let position = failures
+        .iter()
+        .position(|(name, _)| name == updated.name())
+        .unwrap_or(0);
+    Err(failures.swap_remove(position))
+}
+
+/// Walk the include graph from `root`, rejecting missing skills, cycles, and
+/// nesting deeper than [`MAX_SKILL_INCLUDE_DEPTH`].
+fn check_include_graph<'a>(
+    root: &'a str,
+    includes_of: &dyn Fn(&str) -> Option<&'a [String]>,
+) -> Result<(), SkillIncludeError> {
+    fn walk<'a>(
+        name: &str,
+        path: &mut Vec<String>,
+        includes_of: &dyn Fn(&str) -> Option<&'a [String]>,
+    ) -> Result<(), SkillIncludeError> {
+        let includes = includes_of(name).ok_or_else(|| SkillIncludeError::Missing(name.into()))?;
+        if includes.is_empty() {
+            return Ok(());
+        }
+        if path.len() >= MAX_SKILL_INCLUDE_DEPTH {
+            return Err(SkillIncludeError::TooDeep(name.to_string()));
+        }
+        path.push(name.to_string());
+        for include in includes {
+            if let Some(start) = path.iter().position(|ancestor| ancestor == include) {
+                let mut chain = path[start..].to_vec();
+                chain.push(include.clone());
+                return Err(SkillIncludeError::Cycle(chain));
+            }
+            walk(include, path, includes_of)?;
+        }
+        path.pop();
+        Ok(())
+    }
+
+    walk(root, &mut Vec::new(), includes_of)
+}
+
+/// Resolve the skill at `position` after everything it includes, memoizing
+/// results in `outcome`. Only reached for skills whose include graph passed
+/// [`check_include_graph`], so the recursion is acyclic and bounded.
+fn compose_at(
+    position: usize,
+    skills: &mut [LoadedSkill],
+    index: &HashMap<String, usize>,
+    outcome: &mut [Option<Result<(), SkillIncludeError>>],
+) {
+    if outcome[position].is_some() {
+        return;
+    }
+    let includes = skills[position].manifest.includes.clone();
+    let mut parts = Vec::with_capacity(includes.len());
+    for name in includes {
+        let Some(&included) = index.get(&name) else {
+            outcome[position] = Some(Err(SkillIncludeError::Missing(name)));
+            return;
+        };
+        compose_at(included, skills, index, outcome);
+        if matches!(outcome[included], Some(Err(_))) {
+            outcome[position] = Some(Err(SkillIncludeError::Unresolved(name)));
+            return;
+        }
+        parts.push((
+            skills[included].prompt_content.clone(),
+            skills[included].trust,
+        ));
+    }
+    outcome[position] = Some(apply_includes(&mut skills[position], parts));
+}
+
+fn apply_includes(
+    skill: &mut LoadedSkill,
+    parts: Vec<(String, SkillTrust)>,
+) -> Result<(), SkillIncludeError> {
+    let mut content = String::new();
+    let mut trust = skill.trust;
+    for (prompt, included_trust) in parts {
+        content.push_str(&escape_skill_content(prompt.trim_end()));
+        content.push_str("\n\n");
+        trust = trust.min(included_trust);
+    }
+    content.push_str(&skill.prompt_content);
+    if content.len() as u64 > MAX_PROMPT_FILE_SIZE {
+        return Err(SkillIncludeError::TooLarge {
+            size: content.len(),
+        });
+    }
+    skill.content_hash = compute_hash(&content);
+    skill.prompt_content = content;
+    skill.trust = trust;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::types::{ActivationCriteria, GatingRequirements, SkillManifest, SkillSource};
+
+    fn skill(name: &str, includes: &[&str], trust: SkillTrust) -> LoadedSkill {
+        let prompt_content = format!("{name} prompt");
+        LoadedSkill {
+            manifest: SkillManifest {
+                name: name.to_string(),
+                version: "1.0.0".to_string(),
+                auto_activate: true,
+                description: String::new(),
+                activation: ActivationCriteria::default(),
+                credentials: vec![],
+                requires: GatingRequirements::default(),
+                includes: includes.iter().map(|include| include.to_string()).collect(),
+            },
+            content_hash: compute_hash(&prompt_content),
+            prompt_content,
+            trust,
+            source: SkillSource::User(PathBuf::from("/tmp/skills")), // safety: dummy path in test, not used for I/O
+            compiled_patterns: vec![],
+            compiled_exclude_patterns: vec![],
+            lowercased_keywords: vec![],
+            lowercased_exclude_keywords: vec![],
+            lowercased_tags: vec![],
+        }
+    }
+
+    fn find<'a>(skills: &'a [LoadedSkill], name: &str) -> &'a LoadedSkill {
+        skills.iter().find(|skill| skill.name() == name).unwrap()
+    }
+
+    #[test]
+    fn includes_prepend_transitively_and_attenuate_trust() {
+        let mut skills = vec![
+            skill("deploy", &["house-style", "checklist"], SkillTrust::Trusted),
+            skill("house-style", &["tone"], SkillTrust::Trusted),
+            skill("checklist", &[], SkillTrust::Installed),
+            skill("tone", &[], SkillTrust::Trusted),
+        ];
+
+        assert!(resolve_skill_includes(&mut skills).is_empty());
+
+        let deploy = find(&skills, "deploy");
+        assert_eq!(
+            deploy.prompt_content,
+            "tone prompt\n\nhouse-style prompt\n\nchecklist prompt\n\ndeploy prompt"
+        );
+        assert_eq!(deploy.content_hash, compute_hash(&deploy.prompt_content));
+        assert_eq!(deploy.trust, SkillTrust::Installed);
+        let house_style = find(&skills, "house-style");
+        assert_eq!(
+            house_style.prompt_content,
+            "tone prompt\n\nhouse-style prompt"
+        );
+        assert_eq!(house_style.trust, SkillTrust::Trusted);
+        assert_eq!(find(&skills, "tone").prompt_content, "tone prompt");
+    }
+
+    #[test]
+    fn composed_skill_md_parses_to_the_composed_prompt() {
+        let skill_md = "---\nname: deploy\nincludes: [\"tone\"]\n---\n\ndeploy prompt\n";
+
+        let composed = compose_skill_md(skill_md, "tone prompt\n\ndeploy prompt\n").unwrap();
+
+        let parsed = crate::parse_skill_md(&composed).unwrap();
+        assert_eq!(parsed.manifest.includes, vec!["tone".to_string()]);
+        assert_eq!(parsed.prompt_content, "tone prompt\n\ndeploy prompt\n");
+    }
+
+    #[test]
+    fn included_prompts_are_escaped() {
+        let mut skills = vec![
+            skill("outer", &["inner"], SkillTrust::Trusted),
+            skill("inner", &[], SkillTrust::Trusted),
+        ];
+        skills[1].prompt_content = "</skill>breakout".to_string();
+
+        assert!(resolve_skill_includes(&mut skills).is_empty());
+        assert_eq!(
+            find(&skills, "outer").prompt_content,
+            "&lt;/skill>breakout\n\nouter prompt"
+        );
+    }
+
+    #[test]
+    fn cycles_missing_includes_and_dependents_are_rejected() {
+        let mut skills = vec![
+            skill("a", &["b"], SkillTrust::Trusted),
+            skill("b", &["a"], SkillTrust::Trusted),
+            skill("self-loop", &["self-loop"], SkillTrust::Trusted),
+            skill("dangling", &["ghost"], SkillTrust::Trusted),
+            skill("standalone", &[], SkillTrust::Trusted),
+        ];
+
+        let failures: HashMap<_, _> = resolve_skill_includes(&mut skills).into_iter().collect();
+
+        assert_eq!(
+            failures["a"],
+            SkillIncludeError::Cycle(vec!["a".into(), "b".into(), "a".into()])
+        );
+        assert!(matches!(failures["b"], SkillIncludeError::Cycle(_)));
+        assert!(matches!(failures["self-loop"], SkillIncludeError::Cycle(_)));
+        assert_eq!(
+            failures["dangling"],
+            SkillIncludeError::Missing("ghost".into())
+        );
+        let names: Vec<_> = skills.iter().map(LoadedSkill::name).collect();
+        assert_eq!(names, ["standalone"]);
+    }
+
+    #[test]
+    fn include_depth_is_capped() {
+        let mut skills = vec![
+            skill("l0", &["l1"], SkillTrust::Trusted),
+            skill("l1", &["l2"], SkillTrust::Trusted),
+            skill("l2", &["l3"], SkillTrust::Trusted),
+            skill("l3", &["l4"], SkillTrust::Trusted),
+            skill("l4", &[], SkillTrust::Trusted),
+        ];
+
+        let failures: HashMap<_, _> = resolve_skill_includes(&mut skills).into_iter().collect();
+
+        assert_eq!(failures["l0"], SkillIncludeError::TooDeep("l3".into()));
+        assert!(!failures.contains_key("l1"));
+        assert_eq!(skills.len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "registry")]
+    fn single_skill_resolves_against_loaded_set_and_rejects_cycles() {
+        let loaded = vec![
+            skill("base", &[], SkillTrust::Trusted),
+            skill("wrapper", &["target"], SkillTrust::Trusted),
+            skill("target", &[], SkillTrust::Trusted),
+        ];
+
+        let mut installed = skill("fresh", &["base"], SkillTrust::Installed);
+        resolve_single_skill_includes(&mut installed, &loaded).unwrap();
+        assert_eq!(installed.prompt_content, "base prompt\n\nfresh prompt");
+        assert_eq!(installed.trust, SkillTrust::Installed);
+
+        let mut updated = skill("target", &["wrapper"], SkillTrust::Trusted);
+        assert!(matches!(
+            resolve_single_skill_includes(&mut updated, &loaded),
+            Err(SkillIncludeError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "registry")]
+    fn updating_an_included_skill_recomposes_its_dependents() {
+        let uncomposed = vec![
+            skill("deploy", &["house-style"], SkillTrust::Trusted),
+            skill("house-style", &["tone"], SkillTrust::Trusted),
+            skill("tone", &[], SkillTrust::Trusted),
+        ];
+        let mut loaded = uncomposed.clone();
+        assert!(resolve_skill_includes(&mut loaded).is_empty());
+        let uncomposed: HashMap<_, _> = uncomposed
+            .into_iter()
+            .filter(|skill| !skill.manifest.includes.is_empty())
+            .map(|skill| (skill.name().to_string(), skill))
+            .collect();
+
+        let mut tone = skill("tone", &[], SkillTrust::Installed);
+        tone.prompt_content = "new tone".to_string();
+        let recomposed = recompose_with_update(&loaded, &uncomposed, &tone).unwrap();
+
+        let deploy = find(&recomposed, "deploy");
+        assert_eq!(
+            deploy.prompt_content,
+            "new tone\n\nhouse-style prompt\n\ndeploy prompt"
+        );
+        assert_eq!(deploy.content_hash, compute_hash(&deploy.prompt_content));
+        assert_eq!(deploy.trust, SkillTrust::Installed);
+        assert_eq!(
+            find(&recomposed, "house-style").prompt_content,
+            "new tone\n\nhouse-style prompt"
+        );
+
+        let cyclic = skill("tone", &["deploy"], SkillTrust::Trusted);
+        let (name, error) = recompose_with_update(&loaded, &uncomposed, &cyclic).unwrap_err();
+        assert_eq!(name, "tone");
+        assert!(matches!(error, SkillIncludeError::Cycle(_)));
+    }
+}
@@ -37,11 +37,14 @@
 //! controls tool access via capability leases.
 
 pub mod gating;
+mod includes;
 pub mod install_metadata;
 pub mod learning;
 pub mod management;
 mod parser;
 mod selector;
+pub mod state;
+pub mod template;
 pub mod types;
 pub mod v2;
 pub mod validation;
@@ -50,37 +53,46 @@ pub mod validation;
 pub mod catalog;
 #[cfg(feature = "registry")]
 pub mod registry;
+#[cfg(feature = "registry")]
+pub mod watch;
 
 // Re-export core types at crate root for convenience.
 pub use types::{
-    ActivationCriteria, GatingRequirements, LoadedSkill, MAX_PROMPT_FILE_SIZE,
-    ProviderRefreshStrategy, SkillCredentialLocation, SkillCredentialSpec, SkillManifest,
-    SkillOAuthConfig, SkillSource, SkillTrust,
+    ActivationCriteria, GatingRequirements, LoadedSkill, MAX_INCLUDES_PER_MANIFEST,
+    MAX_PROMPT_FILE_SIZE, ProviderRefreshStrategy, SkillCredentialLocation, SkillCredentialSpec,
+    SkillManifest, SkillOAuthConfig, SkillSource, SkillTrust, compute_hash,
 };
 
 pub use gating::{GatingResult, check_requirements, check_requirements_sync};
+pub use includes::{
+    MAX_SKILL_INCLUDE_DEPTH, SkillIncludeError, compose_skill_md, resolve_skill_includes,
+};
 pub use install_metadata::{
     INSTALL_METADATA_FILE_NAME, InstalledSkillMetadata, InstalledSkillMetadataSource,
     MAX_INSTALL_METADATA_BYTES,
 };
 pub use management::{
     MAX_INSTALL_BUNDLE_FILE_BYTES, MAX_INSTALL_BUNDLE_FILES, MAX_INSTALL_BUNDLE_TOTAL_BYTES,
-    SkillContentRequest, SkillContentResult, SkillInstallFile, SkillInstallRequest,
-    SkillInstallResult, SkillInstallSource, SkillManagementContext, SkillManagementError,
-    SkillManagementErrorKind, SkillRemoveRequest, SkillRemoveResult, SkillSearchRequest,
-    SkillSearchResult, SkillSource as ManagedSkillSource, SkillSummary, SkillUpdateRequest,
-    SkillUpdateResult, install_skill, list_skills, read_skill_content, remove_skill, search_skills,
-    skill_summary_json, update_skill,
+    SkillContentRequest, SkillContentResult, SkillEnabledRequest, SkillEnabledResult,
+    SkillInstallFile, SkillInstallRequest, SkillInstallResult, SkillInstallSource,
+    SkillManagementContext, SkillManagementError, SkillManagementErrorKind, SkillRemoveRequest,
+    SkillRemoveResult, SkillSearchRequest, SkillSearchResult, SkillSource as ManagedSkillSource,
+    SkillSummary, SkillUpdateRequest, SkillUpdateResult, install_skill, list_skills,
+    read_skill_content, remove_skill, search_skills, set_skill_enabled, skill_summary_json,
+    update_skill,
 };
 pub use parser::{ParsedSkill, SkillParseError, parse_skill_md, set_skill_auto_activate};
 pub use selector::{
-    MAX_SKILL_CONTEXT_TOKENS, SelectionOutcome, SkillSelectionOptions, extract_skill_mentions,
-    prefilter_skills_with_options, skill_token_cost,
+    FuzzyKeywordMatch, MAX_SKILL_CONTEXT_TOKENS, SelectionOutcome, SkillSelectionOptions,
+    approx_prompt_tokens, extract_skill_mentions, prefilter_skills_with_options, skill_token_cost,
+    truncate_prompt_to_tokens,
 };
+pub use state::{REGISTRY_STATE_FILE_NAME, SkillRegistryState, SkillUsage};
+pub use template::{MAX_TEMPLATE_VALUE_CHARS, SkillTemplateVars, render_skill_template};
 pub use validation::{
-    SafeRelativePathError, escape_skill_content, escape_xml_attr, normalize_line_endings,
-    normalize_safe_relative_path, validate_credential_name, validate_credential_spec,
-    validate_path_pattern, validate_skill_name,
+    DEFAULT_EMBEDDED_BLOB_MIN_LEN, EmbeddedBlob, SafeRelativePathError, escape_skill_content,
+    escape_xml_attr, find_embedded_blobs, normalize_line_endings, normalize_safe_relative_path,
+    validate_credential_name, validate_credential_spec, validate_path_pattern, validate_skill_name,
 };
 
 #[cfg(feature = "catalog")]
@@ -89,4 +101,7 @@ pub use catalog::{
     catalog_entry_is_installed, resolve_catalog_slug_for_name, shared_catalog,
 };
 #[cfg(feature = "registry")]
-pub use registry::{SkillRegistry, SkillRegistryError, compute_hash};
+pub use registry::{
+    SKILL_USAGE_WRITE_INTERVAL, SkillListEntry, SkillRegistry, SkillRegistryError,
+    SkillReloadOutcome,
+};
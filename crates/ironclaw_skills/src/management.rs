@@ -4,6 +4,7 @@ use std::{
 };
 
 use crate::parser::starts_with_frontmatter_delimiter;
+use crate::state::{REGISTRY_STATE_FILE_NAME, SkillRegistryState};
 use crate::validation::normalize_skill_identifier;
 use crate::{
     MAX_PROMPT_FILE_SIZE, ParsedSkill, SkillParseError, normalize_line_endings, parse_skill_md,
@@ -170,6 +171,9 @@ pub struct SkillSummary {
     /// Whether the skill participates in automatic activation (mirrors
     /// `SkillManifest::auto_activate`). `false` means explicit-mention only.
     pub auto_activate: bool,
+    /// Whether the operator left the skill enabled. Disabled skills are
+    /// listed but never activate, not even by explicit mention.
+    pub enabled: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -198,6 +202,7 @@ pub fn skill_summary_json(skill: &SkillSummary) -> serde_json::Value {
         "keywords": skill.keywords,
         "tags": skill.tags,
         "requires_skills": skill.requires_skills,
+        "enabled": skill.enabled,
     })
 }
 
@@ -260,6 +265,18 @@ pub struct SkillUpdateResult {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillEnabledRequest<'a> {
+    pub name: &'a str,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillEnabledResult {
+    pub name: String,
+    pub enabled: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SkillSearchRequest<'a> {
     pub query: &'a str,
@@ -279,6 +296,7 @@ pub async fn list_skills(
     let mut skills = Vec::new();
     skills.extend(list_skill_root(context, SYSTEM_SKILLS_ROOT, SkillSource::System).await?);
     skills.extend(list_skill_root(context, USER_SKILLS_ROOT, SkillSource::User).await?);
+    apply_skill_state(context, &mut skills).await;
     tracing::debug!(skill_count = skills.len(), "skill management listed skills");
     Ok(skills)
 }
@@ -317,6 +335,7 @@ pub async fn search_skills(
         )
         .await?;
     }
+    apply_skill_state(context, &mut skills).await;
     tracing::debug!(
         skill_count = skills.len(),
         truncated,
@@ -620,6 +639,117 @@ pub async fn update_skill(
     })
 }
 
+/// Enable or disable a system or user skill without touching its files.
+///
+/// The choice is written to the disabled list in [`REGISTRY_STATE_FILE_NAME`]
+/// under the user skills root, the file the runtime selector and
+/// `SkillRegistry` honor. Recorded usage counters are preserved, and a state
+/// file that fails to parse is left alone rather than overwritten.
+#[tracing::instrument(
+    level = "debug",
+    skip(context, request),
+    fields(skill_name = %request.name, enabled = request.enabled)
+)]
+pub async fn set_skill_enabled(
+    context: &SkillManagementContext,
+    request: SkillEnabledRequest<'_>,
+) -> Result<SkillEnabledResult, SkillManagementError> {
+    if !validate_skill_name(request.name) {
+        return Err(SkillManagementError::new(
+            SkillManagementErrorKind::InvalidInput,
+        ));
+    }
+    let mut exists = false;
+    for root in [SYSTEM_SKILLS_ROOT, USER_SKILLS_ROOT] {
+        let skill_path = skill_scoped_path(root, request.name, SKILL_FILE_NAME)?;
+        match context.filesystem.stat(&context.scope, &skill_path).await {
+            Ok(_) => {
+                exists = true;
+                break;
+            }
+            Err(FilesystemError::NotFound { .. }) => {}
+            Err(error) if is_unmounted_scoped_root(&error) => {}
+            Err(error) => return Err(filesystem_error(error)),
+        }
+    }
+    if !exists {
+        return Err(SkillManagementError::new(
+            SkillManagementErrorKind::NotFound,
+        ));
+    }
+
+    let mutation_lock = skill_mutation_lock(REGISTRY_STATE_FILE_NAME);
+    let _mutation_guard = mutation_lock.lock().await;
+    let state_path = skill_state_scoped_path()?;
+    let mut state = read_skill_state(context, &state_path).await?;
+    if state.set_enabled(request.name, request.enabled) {
+        let bytes = state.to_json().map_err(|error| {
+            SkillManagementError::with_reason(
+                SkillManagementErrorKind::InvalidInput,
+                format!("skill state failed to serialize: {error}"),
+            )
+        })?;
+        context
+            .filesystem
+            .write_file(&context.scope, &state_path, &bytes)
+            .await
+            .map_err(filesystem_error)?;
+    }
+    tracing::debug!("skill enablement update completed");
+    Ok(SkillEnabledResult {
+        name: request.name.to_string(),
+        enabled: request.enabled,
+    })
+}
+
+/// Fill in [`SkillSummary::enabled`] from the disabled list. An unreadable
+/// state file counts as nothing disabled, matching the runtime selector.
+async fn apply_skill_state(context: &SkillManagementContext, skills: &mut [SkillSummary]) {
+    let Ok(state_path) = skill_state_scoped_path() else {
+        return;
+    };
+    let state = match read_skill_state(context, &state_path).await {
+        Ok(state) => state,
+        Err(error) => {
+            tracing::debug!(?error, "skill management ignored unreadable skill state");
+            return;
+        }
+    };
+    for skill in skills {
+        skill.enabled = state.is_enabled(&skill.name);
+    }
+}
+
+fn skill_state_scoped_path() -> Result<ScopedPath, SkillManagementError> {
+    ScopedPath::new(format!("{USER_SKILLS_ROOT}/{REGISTRY_STATE_FILE_NAME}")).map_err(|error| {
+        SkillManagementError::with_reason(
+            SkillManagementErrorKind::InvalidInput,
+            format!("invalid skill state path: {error}"),
+        )
+    })
+}
+
+async fn read_skill_state(
+    context: &SkillManagementContext,
+    path: &ScopedPath,
+) -> Result<SkillRegistryState, SkillManagementError> {
+    if stat_optional(context, path).await?.is_none() {
+        return Ok(SkillRegistryState::default());
+    }
+    let bytes = context
+        .filesystem
+        .read_bytes_bounded(&context.scope, path, MAX_PROMPT_FILE_SIZE as usize)
+        .await
+        .map_err(filesystem_error)?
+        .ok_or_else(|| SkillManagementError::new(SkillManagementErrorKind::Resource))?;
+    SkillRegistryState::from_json(&bytes).map_err(|error| {
+        SkillManagementError::with_reason(
+            SkillManagementErrorKind::InvalidSkill,
+            format!("skill state failed to parse: {error}"),
+        )
+    })
+}
+
 fn skill_mutation_lock(skill_name: &str) -> SkillMutationLock {
     let mut guard = SKILL_MUTATION_LOCKS
         .lock()
@@ -802,6 +932,7 @@ async fn read_skill_summary(
         tags: parsed.manifest.activation.tags,
         requires_skills: parsed.manifest.requires.skills,
         auto_activate: parsed.manifest.auto_activate,
+        enabled: true,
     }))
 }
 
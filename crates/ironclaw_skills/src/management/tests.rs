@@ -823,6 +823,131 @@ async fn remove_rejects_system_skill() {
     assert_eq!(error.kind(), SkillManagementErrorKind::NotFound);
 }
 
+#[tokio::test]
+async fn set_skill_enabled_persists_disabled_list_and_listing_reflects_it() {
+    let filesystem = Arc::new(InMemoryBackend::default());
+    write_file(
+        filesystem.as_ref(),
+        "/projects/system/skills/system-helper/SKILL.md",
+        skill_md("system-helper", "system skill description", "PROMPT"),
+    )
+    .await;
+    write_file(
+        filesystem.as_ref(),
+        "/projects/skills/local-helper/SKILL.md",
+        skill_md("local-helper", "local skill description", "PROMPT"),
+    )
+    .await;
+    write_file(
+        filesystem.as_ref(),
+        "/projects/skills/.ironclaw-skill-state.json",
+        r#"{"usage":{"local-helper":{"activation_count":3,"last_activated_at":"2026-01-01T00:00:00Z"}}}"#
+            .to_string(),
+    )
+    .await;
+    let context = skill_management_context(filesystem.clone(), skill_mounts());
+    assert!(
+        list_skills(&context)
+            .await
+            .unwrap()
+            .iter()
+            .all(|skill| skill.enabled)
+    );
+
+    for name in ["system-helper", "local-helper"] {
+        let result = set_skill_enabled(
+            &context,
+            SkillEnabledRequest {
+                name,
+                enabled: false,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(!result.enabled);
+    }
+
+    let state = SkillRegistryState::from_json(
+        read_file(
+            filesystem.as_ref(),
+            "/projects/skills/.ironclaw-skill-state.json",
+        )
+        .await
+        .as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(state.disabled, vec!["local-helper", "system-helper"]);
+    assert_eq!(state.usage["local-helper"].activation_count, 3);
+    let listed = list_skills(&context).await.unwrap();
+    assert!(listed.iter().all(|skill| !skill.enabled));
+    assert_eq!(skill_summary_json(&listed[0])["enabled"], false);
+
+    set_skill_enabled(
+        &context,
+        SkillEnabledRequest {
+            name: "system-helper",
+            enabled: true,
+        },
+    )
+    .await
+    .unwrap();
+    let listed = list_skills(&context).await.unwrap();
+    assert!(
+        listed
+            .iter()
+            .all(|skill| skill.enabled == (skill.name == "system-helper"))
+    );
+}
+
+#[tokio::test]
+async fn set_skill_enabled_rejects_missing_skill_and_malformed_state() {
+    let filesystem = Arc::new(InMemoryBackend::default());
+    write_file(
+        filesystem.as_ref(),
+        "/projects/skills/local-helper/SKILL.md",
+        skill_md("local-helper", "local skill description", "PROMPT"),
+    )
+    .await;
+    let context = skill_management_context(filesystem.clone(), user_skill_mounts());
+
+    let error = set_skill_enabled(
+        &context,
+        SkillEnabledRequest {
+            name: "missing-helper",
+            enabled: false,
+        },
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.kind(), SkillManagementErrorKind::NotFound);
+
+    write_file(
+        filesystem.as_ref(),
+        "/projects/skills/.ironclaw-skill-state.json",
+        "not json".to_string(),
+    )
+    .await;
+    let error = set_skill_enabled(
+        &context,
+        SkillEnabledRequest {
+            name: "local-helper",
+            enabled: false,
+        },
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.kind(), SkillManagementErrorKind::InvalidSkill);
+    assert_eq!(
+        read_file(
+            filesystem.as_ref(),
+            "/projects/skills/.ironclaw-skill-state.json"
+        )
+        .await,
+        "not json"
+    );
+    assert!(list_skills(&context).await.unwrap()[0].enabled);
+}
+
 #[tokio::test]
 async fn read_skill_content_rejects_invalid_or_missing_user_skill() {
     let filesystem = Arc::new(InMemoryBackend::default());
@@ -3,7 +3,7 @@
 //! Parses files with YAML frontmatter delimited by `---` lines, followed by a
 //! markdown prompt body.
 
-use crate::types::SkillManifest;
+use crate::types::{MAX_INCLUDES_PER_MANIFEST, SkillManifest};
 use crate::validation::{validate_skill_name, validate_skill_version};
 
 /// Error type for SKILL.md parsing failures.
@@ -122,8 +122,8 @@ pub(crate) fn parse_skill_md_for_install_recovery(
 /// deserializing into a typed [`SkillManifest`].
 ///
 /// Used by install recovery to mutate a single field (`name`) while preserving
-/// any unknown YAML keys that the typed `SkillManifest` would otherwise drop.
-#[cfg(feature = "registry")]
+/// any unknown YAML keys that the typed `SkillManifest` would otherwise drop,
+/// and by [`crate::compose_skill_md`] to swap in a composed prompt body.
 pub(crate) fn split_skill_md_frontmatter(
     content: &str,
 ) -> Result<(String, String), SkillParseError> {
@@ -219,6 +219,7 @@ fn parse_skill_md_impl(content: &str, validate_name: bool) -> Result<ParsedSkill
     // Enforce gating requirement limits (currently only `requires.skills`
     // is capped to keep the chain installer's queue bounded).
     manifest.requires.enforce_limits();
+    manifest.includes.truncate(MAX_INCLUDES_PER_MANIFEST);
 
     // Extract prompt content (everything after the closing `---` line)
     let after_yaml = &after_first_line[yaml_end..];
@@ -354,6 +355,7 @@ name: regex-skill
 activation:
   keywords: ["test"]
   patterns: ["(?i)\\bwrite\\b"]
+  exclude_patterns: ["(?i)\\bwrite-off\\b"]
   tags: ["writing", "email"]
 ---
 
@@ -361,6 +363,7 @@ Test prompt.
 "#;
         let result = parse_skill_md(content).expect("should parse");
         assert_eq!(result.manifest.activation.patterns.len(), 1);
+        assert_eq!(result.manifest.activation.exclude_patterns.len(), 1);
         assert_eq!(result.manifest.activation.tags.len(), 2);
     }
 
@@ -13,7 +13,7 @@
 //! user overrides installed overrides bundled).
 //! Uses async I/O throughout to avoid blocking the tokio runtime.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 // `io`/`Read` are used only by the `#[cfg(unix)]` permission-check helpers
 // (`identity_matches`, `read_file_bytes_limited`); gate the import to match so
 // the non-unix build doesn't see them as unused (`std::io::ErrorKind` elsewhere
@@ -24,19 +24,26 @@ use std::path::{Path, PathBuf};
 
 use sha2::{Digest, Sha256};
 
+pub use crate::types::compute_hash;
+
 use crate::gating;
+use crate::includes;
+pub use crate::includes::{MAX_SKILL_INCLUDE_DEPTH, SkillIncludeError};
 use crate::install_metadata::INSTALL_METADATA_FILE_NAME;
 pub use crate::install_metadata::InstalledSkillMetadata;
 use crate::parser::{
     SkillParseError, parse_skill_md, parse_skill_md_for_install_recovery,
     split_skill_md_frontmatter,
 };
+use crate::state::record_usage;
+pub use crate::state::{REGISTRY_STATE_FILE_NAME, SkillRegistryState, SkillUsage};
 use crate::types::{
     GatingRequirements, LoadedSkill, MAX_PROMPT_FILE_SIZE, SkillSource, SkillTrust,
+    compute_hash_bytes,
 };
 use crate::validation::{
-    SafeRelativePathError, normalize_line_endings, normalize_safe_relative_path,
-    normalize_skill_identifier,
+    DEFAULT_EMBEDDED_BLOB_MIN_LEN, SafeRelativePathError, find_embedded_blobs,
+    normalize_line_endings, normalize_safe_relative_path, normalize_skill_identifier,
 };
 
 /// Maximum total number of skills that can be discovered across all sources.
@@ -47,6 +54,11 @@ const MAX_DISCOVERED_SKILLS: usize = 100;
 /// Default recursion depth for bundle directory scanning.
 const DEFAULT_MAX_SCAN_DEPTH: usize = 3;
 
+/// Minimum time between usage-counter writes to [`REGISTRY_STATE_FILE_NAME`].
+/// Activations in between are batched into the next write, so the state file
+/// is not rewritten on every turn.
+pub const SKILL_USAGE_WRITE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
 fn to_lowercase_vec(items: &[String]) -> Vec<String> {
     items.iter().map(|s| s.to_lowercase()).collect()
 }
@@ -197,6 +209,12 @@ pub enum SkillRegistryError {
 
     #[error("Failed to write skill file {path}: {reason}")]
     WriteError { path: String, reason: String },
+
+    #[error("Skill '{name}' includes could not be resolved: {source}")]
+    IncludeFailed {
+        name: String,
+        source: SkillIncludeError,
+    },
 }
 
 /// Registry of available skills.
@@ -214,6 +232,56 @@ pub struct SkillRegistry {
     bundled_content: &'static [(String, String)],
     /// Maximum recursion depth for bundle directory scanning (default: 3).
     max_scan_depth: usize,
+    /// Minimum length of a base64-looking run reported as an embedded blob
+    /// warning during discovery. `None` disables the check.
+    embedded_blob_min_len: Option<usize>,
+    /// Names of skills disabled by the operator; loaded from and persisted to
+    /// [`REGISTRY_STATE_FILE_NAME`]. Disabled skills stay listed but never
+    /// activate.
+    disabled: HashSet<String>,
+    /// Activation counters keyed by skill name; persisted alongside
+    /// `disabled` in [`REGISTRY_STATE_FILE_NAME`].
+    usage: BTreeMap<String, SkillUsage>,
+    /// Whether `usage` has counts not yet written to disk.
+    usage_dirty: bool,
+    /// When `usage` was last written, for [`SKILL_USAGE_WRITE_INTERVAL`].
+    usage_written_at: Option<std::time::Instant>,
+    /// Problems reported by the last [`Self::discover_all`].
+    discovery_warnings: Vec<String>,
+    /// As-loaded copies of skills that declare `includes`, so they can be
+    /// recomposed when a skill they include is updated.
+    uncomposed: HashMap<String, LoadedSkill>,
+}
+
+/// A loaded skill together with its operator enablement state.
+#[derive(Debug, Clone, Copy)]
+pub struct SkillListEntry<'a> {
+    pub skill: &'a LoadedSkill,
+    pub enabled: bool,
+}
+
+/// Skill-level changes applied by [`SkillRegistry::apply_reload`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillReloadOutcome {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+    /// Skills rediscovered at a higher trust level than before; their previous
+    /// (lower) trust was kept.
+    pub trust_retained: Vec<String>,
+    /// Warnings from the rescan, see [`SkillRegistry::discovery_warnings`].
+    pub warnings: Vec<String>,
+}
+
+impl SkillReloadOutcome {
+    /// Whether the reload changed nothing.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.updated.is_empty()
+            && self.removed.is_empty()
+            && self.trust_retained.is_empty()
+            && self.warnings.is_empty()
+    }
 }
 
 /// Additional bundle file to materialize alongside `SKILL.md` during install.
@@ -253,6 +321,13 @@ impl SkillRegistry {
             workspace_dir: None,
             bundled_content: &[],
             max_scan_depth: DEFAULT_MAX_SCAN_DEPTH,
+            embedded_blob_min_len: Some(DEFAULT_EMBEDDED_BLOB_MIN_LEN),
+            disabled: HashSet::new(),
+            usage: BTreeMap::new(),
+            usage_dirty: false,
+            usage_written_at: None,
+            discovery_warnings: Vec::new(),
+            uncomposed: HashMap::new(),
         }
     }
 
@@ -289,6 +364,13 @@ impl SkillRegistry {
         self
     }
 
+    /// Set the minimum base64-looking run length that discovery reports as an
+    /// embedded blob warning, or `None` to disable the check.
+    pub fn with_embedded_blob_min_len(mut self, min_len: Option<usize>) -> Self {
+        self.embedded_blob_min_len = min_len;
+        self
+    }
+
     /// Build a fresh registry with the same shared overlays but different
     /// user-owned skill roots.
     pub fn clone_config_for_user_dirs(
@@ -298,7 +380,8 @@ impl SkillRegistry {
     ) -> Self {
         let mut registry = Self::new(user_dir)
             .with_bundled_content(self.bundled_content)
-            .with_max_scan_depth(self.max_scan_depth);
+            .with_max_scan_depth(self.max_scan_depth)
+            .with_embedded_blob_min_len(self.embedded_blob_min_len);
         if let Some(workspace_dir) = self.workspace_dir.clone() {
             registry = registry.with_workspace_dir(workspace_dir);
         }
@@ -350,6 +433,11 @@ impl SkillRegistry {
     /// 2. User skills directory -- Trusted
     /// 3. Installed skills directory (if set) -- Installed
     pub async fn discover_all(&mut self) -> Vec<String> {
+        let state = self.read_state().await;
+        self.disabled = state.disabled.into_iter().collect();
+        self.usage = state.usage;
+        self.usage_dirty = false;
+        let mut warnings = Vec::new();
         let mut loaded_names: Vec<String> = Vec::new();
         let mut seen: HashSet<String> = HashSet::new();
 
@@ -363,6 +451,7 @@ impl SkillRegistry {
                     &SkillSource::Workspace,
                     cap,
                     0,
+                    &mut warnings,
                 )
                 .await;
             self.absorb(skills, &mut seen, &mut loaded_names, "user");
@@ -373,7 +462,14 @@ impl SkillRegistry {
             let cap = MAX_DISCOVERED_SKILLS.saturating_sub(loaded_names.len());
             let user_dir = self.user_dir.clone();
             let skills = self
-                .discover_from_dir(&user_dir, SkillTrust::Trusted, &SkillSource::User, cap, 0)
+                .discover_from_dir(
+                    &user_dir,
+                    SkillTrust::Trusted,
+                    &SkillSource::User,
+                    cap,
+                    0,
+                    &mut warnings,
+                )
                 .await;
             self.absorb(skills, &mut seen, &mut loaded_names, "workspace");
         }
@@ -390,6 +486,7 @@ impl SkillRegistry {
                     &SkillSource::Installed,
                     cap,
                     0,
+                    &mut warnings,
                 )
                 .await;
             self.absorb(skills, &mut seen, &mut loaded_names, "installed");
@@ -405,6 +502,22 @@ impl SkillRegistry {
             }
         }
 
+        // Compose `includes` once every source is loaded. A skill whose
+        // includes are missing, cyclic, or nested too deep does not load.
+        self.uncomposed = self
+            .skills
+            .iter()
+            .filter(|skill| !skill.manifest.includes.is_empty())
+            .map(|skill| (skill.name().to_string(), skill.clone()))
+            .collect();
+        for (name, error) in includes::resolve_skill_includes(&mut self.skills) {
+            self.uncomposed.remove(&name);
+            let warning = format!("Skipping skill '{name}': {error}");
+            tracing::warn!("{}", warning);
+            warnings.push(warning);
+            loaded_names.retain(|loaded| loaded != &name);
+        }
+
         if loaded_names.len() >= MAX_DISCOVERED_SKILLS {
             tracing::warn!(
                 "Global skill discovery cap reached ({} skills)",
@@ -423,20 +536,44 @@ impl SkillRegistry {
         for skill in &self.skills {
             for companion in &skill.manifest.requires.skills {
                 if !loaded_set.contains(companion.as_str()) {
-                    tracing::warn!(
+                    let warning = format!(
                         "Skill '{}' declares companion '{}' in `requires.skills`, but it is not loaded. \
                          Install it via `skill_install` or place a SKILL.md for it in ~/.ironclaw/skills/ \
                          to avoid a degraded experience.",
-                        skill.manifest.name,
-                        companion
+                        skill.manifest.name, companion
+                    );
+                    tracing::warn!("{}", warning);
+                    warnings.push(warning);
+                }
+            }
+        }
+
+        // Embedded-blob scan. Long base64-looking runs in a prompt can smuggle
+        // instructions or payloads past a casual review, so surface them as
+        // load warnings (the skill still loads; trust decides what it can do).
+        if let Some(min_len) = self.embedded_blob_min_len {
+            for skill in &self.skills {
+                for blob in find_embedded_blobs(&skill.prompt_content, min_len) {
+                    let warning = format!(
+                        "Skill '{}' prompt contains a {}; review it before trusting this skill",
+                        skill.manifest.name, blob
                     );
+                    tracing::warn!(trust = %skill.trust, "{}", warning);
+                    warnings.push(warning);
                 }
             }
         }
 
+        self.discovery_warnings = warnings;
         loaded_names
     }
 
+    /// Problems the last [`Self::discover_all`] logged: skills that failed to
+    /// load or compose, missing companions, and embedded blobs.
+    pub fn discovery_warnings(&self) -> &[String] {
+        &self.discovery_warnings
+    }
+
     /// Dedup and absorb discovered skills into the registry.
     fn absorb(
         &mut self,
@@ -473,6 +610,7 @@ impl SkillRegistry {
         make_source: &F,
         remaining_cap: usize,
         current_depth: usize,
+        warnings: &mut Vec<String>,
     ) -> Vec<(String, LoadedSkill)>
     where
         F: Fn(PathBuf) -> SkillSource + Send + Sync,
@@ -537,11 +675,13 @@ impl SkillRegistry {
                             results.push((name, skill));
                         }
                         Err(e) => {
-                            tracing::warn!(
+                            let warning = format!(
                                 "Failed to load skill from {:?}: {}",
                                 path.file_name().unwrap_or_default(),
                                 e
                             );
+                            tracing::warn!("{}", warning);
+                            warnings.push(warning);
                         }
                     }
                 } else if current_depth < self.max_scan_depth {
@@ -556,6 +696,7 @@ impl SkillRegistry {
                         make_source,
                         remaining_cap.saturating_sub(count),
                         current_depth + 1,
+                        warnings,
                     ))
                     .await;
                     count += nested.len();
@@ -577,7 +718,9 @@ impl SkillRegistry {
                         results.push((name, skill));
                     }
                     Err(e) => {
-                        tracing::warn!("Failed to load skill from {:?}: {}", fname, e);
+                        let warning = format!("Failed to load skill from {fname:?}: {e}");
+                        tracing::warn!("{}", warning);
+                        warnings.push(warning);
                     }
                 }
             }
@@ -754,7 +897,7 @@ impl SkillRegistry {
     pub fn commit_install(
         &mut self,
         name: &str,
-        skill: LoadedSkill,
+        mut skill: LoadedSkill,
     ) -> Result<(), SkillRegistryError> {
         // Re-check for duplicates (another thread may have installed between prepare and commit)
         if self.has(name) {
@@ -762,6 +905,11 @@ impl SkillRegistry {
                 name: name.to_string(),
             });
         }
+        let uncomposed = (!skill.manifest.includes.is_empty()).then(|| skill.clone());
+        self.resolve_includes(name, &mut skill)?;
+        if let Some(uncomposed) = uncomposed {
+            self.uncomposed.insert(name.to_string(), uncomposed);
+        }
         self.skills.push(skill);
         tracing::debug!("Installed skill: {}", name);
         Ok(())
@@ -918,12 +1066,17 @@ impl SkillRegistry {
             .ok_or_else(|| SkillRegistryError::NotFound(name.to_string()))?;
 
         self.skills.remove(idx);
+        self.uncomposed.remove(name);
         tracing::debug!("Removed skill: {}", name);
         Ok(())
     }
 
     /// Replace an already-loaded skill after its on-disk file was validated
     /// and rewritten.
+    ///
+    /// Skills that include it are recomposed with its new prompt and trust.
+    /// The update is rejected, leaving the registry unchanged, when it would
+    /// leave it or any of them unresolvable.
     pub fn commit_update(
         &mut self,
         name: &str,
@@ -935,11 +1088,50 @@ impl SkillRegistry {
             .position(|s| s.manifest.name == name)
             .ok_or_else(|| SkillRegistryError::NotFound(name.to_string()))?;
 
-        self.skills[idx] = skill;
+        if self.uncomposed.is_empty() && skill.manifest.includes.is_empty() {
+            // Nothing includes anything, so only this skill changes.
+            self.skills[idx] = skill;
+        } else {
+            let skills = includes::recompose_with_update(&self.skills, &self.uncomposed, &skill)
+                .map_err(|(failed, source)| {
+                    if failed == name {
+                        SkillRegistryError::IncludeFailed {
+                            name: name.to_string(),
+                            source,
+                        }
+                    } else {
+                        SkillRegistryError::CannotUpdate {
+                            name: name.to_string(),
+                            reason: format!("skill '{failed}' includes it: {source}"),
+                        }
+                    }
+                })?;
+            if skill.manifest.includes.is_empty() {
+                self.uncomposed.remove(name);
+            } else {
+                self.uncomposed.insert(name.to_string(), skill);
+            }
+            self.skills = skills;
+        }
         tracing::debug!("Updated skill: {}", name);
         Ok(())
     }
 
+    /// Compose a runtime-installed skill's `includes` against the currently
+    /// loaded skills.
+    fn resolve_includes(
+        &self,
+        name: &str,
+        skill: &mut LoadedSkill,
+    ) -> Result<(), SkillRegistryError> {
+        includes::resolve_single_skill_includes(skill, &self.skills).map_err(|source| {
+            SkillRegistryError::IncludeFailed {
+                name: name.to_string(),
+                source,
+            }
+        })
+    }
+
     /// Remove a skill by name.
     ///
     /// Convenience method that combines validation, file deletion, and in-memory
@@ -957,6 +1149,224 @@ impl SkillRegistry {
         self.discover_all().await
     }
 
+    /// Build an empty registry with this registry's full configuration, for
+    /// discovering a replacement skill set off-lock before [`Self::apply_reload`].
+    pub fn clone_config_for_reload(&self) -> Self {
+        self.clone_config_for_user_dirs(self.user_dir.clone(), self.installed_dir.clone())
+    }
+
+    /// Swap in the skills discovered by `reloaded`, reporting what changed.
+    ///
+    /// Trust never escalates across a reload: a skill whose name was already
+    /// loaded keeps the lower of its previous and newly discovered trust.
+    pub fn apply_reload(&mut self, reloaded: SkillRegistry) -> SkillReloadOutcome {
+        let mut outcome = SkillReloadOutcome::default();
+        let mut skills = reloaded.skills;
+        let mut uncomposed = reloaded.uncomposed;
+        for skill in &mut skills {
+            match self.find_by_name(skill.name()) {
+                None => outcome.added.push(skill.name().to_string()),
+                Some(previous) => {
+                    if skill.trust > previous.trust {
+                        skill.trust = previous.trust;
+                        if let Some(uncomposed) = uncomposed.get_mut(skill.name()) {
+                            uncomposed.trust = uncomposed.trust.min(previous.trust);
+                        }
+                        outcome.trust_retained.push(skill.name().to_string());
+                    }
+                    if previous.content_hash != skill.content_hash
+                        || serde_json::to_value(&previous.manifest).ok()
+                            != serde_json::to_value(&skill.manifest).ok()
+                    {
+                        outcome.updated.push(skill.name().to_string());
+                    }
+                }
+            }
+        }
+        for previous in &self.skills {
+            if !skills.iter().any(|skill| skill.name() == previous.name()) {
+                outcome.removed.push(previous.name().to_string());
+            }
+        }
+        outcome.warnings = reloaded.discovery_warnings.clone();
+        self.skills = skills;
+        self.uncomposed = uncomposed;
+        self.disabled = reloaded.disabled;
+        self.discovery_warnings = reloaded.discovery_warnings;
+        // Keep the in-memory counters: they may hold a batch that has not
+        // been written yet, which the reloaded copy read from disk lacks.
+        outcome
+    }
+
+    /// Maximum recursion depth for bundle directory scanning.
+    pub fn max_scan_depth(&self) -> usize {
+        self.max_scan_depth
+    }
+
+    /// Whether `name` is enabled. Skills are enabled unless an operator
+    /// disabled them with [`Self::set_enabled`]; this is independent of trust.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.disabled.contains(name)
+    }
+
+    /// Names of disabled skills, for `SkillSelectionOptions::disabled_skills`.
+    pub fn disabled_skills(&self) -> &HashSet<String> {
+        &self.disabled
+    }
+
+    /// All loaded skills with their enablement state, in discovery order.
+    pub fn list(&self) -> Vec<SkillListEntry<'_>> {
+        self.skills
+            .iter()
+            .map(|skill| SkillListEntry {
+                skill,
+                enabled: self.is_enabled(skill.name()),
+            })
+            .collect()
+    }
+
+    /// Enable or disable a loaded skill without touching its files.
+    ///
+    /// The disabled set is persisted to [`REGISTRY_STATE_FILE_NAME`] in the
+    /// user skills directory so the choice survives restarts. On a write
+    /// failure the in-memory state is left unchanged.
+    pub async fn set_enabled(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), SkillRegistryError> {
+        if !self.has(name) {
+            return Err(SkillRegistryError::NotFound(name.to_string()));
+        }
+        let mut disabled = self.disabled.clone();
+        let changed = if enabled {
+            disabled.remove(name)
+        } else {
+            disabled.insert(name.to_string())
+        };
+        if !changed {
+            return Ok(());
+        }
+        self.write_state(&disabled, &self.usage).await?;
+        self.disabled = disabled;
+        self.mark_usage_written();
+        tracing::info!(
+            "Skill '{}' {}",
+            name,
+            if enabled { "enabled" } else { "disabled" }
+        );
+        Ok(())
+    }
+
+    /// Record that `names` were selected for a turn.
+    ///
+    /// Bumps each loaded skill's activation count and stamps
+    /// `last_activated_at`. Names that aren't loaded are ignored. Counters
+    /// are written to [`REGISTRY_STATE_FILE_NAME`] at most once per
+    /// [`SKILL_USAGE_WRITE_INTERVAL`]; call [`Self::flush_usage`] to write a
+    /// pending batch early (e.g. on shutdown). A failed write keeps the batch
+    /// pending for the next attempt.
+    pub async fn record_activations<'a>(
+        &mut self,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), SkillRegistryError> {
+        let now = chrono::Utc::now();
+        for name in names {
+            if self.has(name) {
+                record_usage(&mut self.usage, name, 1, now);
+                self.usage_dirty = true;
+            }
+        }
+        let write_due = self
+            .usage_written_at
+            .is_none_or(|written_at| written_at.elapsed() >= SKILL_USAGE_WRITE_INTERVAL);
+        if write_due {
+            self.flush_usage().await?;
+        }
+        Ok(())
+    }
+
+    /// Write any activation counts recorded since the last write.
+    pub async fn flush_usage(&mut self) -> Result<(), SkillRegistryError> {
+        if !self.usage_dirty {
+            return Ok(());
+        }
+        self.write_state(&self.disabled, &self.usage).await?;
+        self.mark_usage_written();
+        Ok(())
+    }
+
+    fn mark_usage_written(&mut self) {
+        self.usage_dirty = false;
+        self.usage_written_at = Some(std::time::Instant::now());
+    }
+
+    /// Activation counters for every skill that has been selected at least
+    /// once, keyed by name. Skills missing from the map have never activated.
+    pub fn usage_stats(&self) -> &BTreeMap<String, SkillUsage> {
+        &self.usage
+    }
+
+    async fn read_state(&self) -> SkillRegistryState {
+        let path = self.user_dir.join(REGISTRY_STATE_FILE_NAME);
+        let bytes = match tokio::fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return SkillRegistryState::default();
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to read skill registry state {}: {}",
+                    path.display(),
+                    e
+                );
+                return SkillRegistryState::default();
+            }
+        };
+        match SkillRegistryState::from_json(&bytes) {
+            Ok(state) => state,
+            Err(e) => {
+                tracing::warn!(
+                    "Ignoring malformed skill registry state {}: {}",
+                    path.display(),
+                    e
+                );
+                SkillRegistryState::default()
+            }
+        }
+    }
+
+    async fn write_state(
+        &self,
+        disabled: &HashSet<String>,
+        usage: &BTreeMap<String, SkillUsage>,
+    ) -> Result<(), SkillRegistryError> {
+        let path = self.user_dir.join(REGISTRY_STATE_FILE_NAME);
+        let write_error = |reason: String| SkillRegistryError::WriteError {
+            path: path.display().to_string(),
+            reason,
+        };
+        let mut names: Vec<String> = disabled.iter().cloned().collect();
+        names.sort();
+        let state = SkillRegistryState {
+            disabled: names,
+            usage: usage.clone(),
+        };
+        let json = serde_json::to_vec_pretty(&state)
+            .map_err(|e| write_error(format!("failed to serialize registry state: {e}")))?;
+        tokio::fs::create_dir_all(&self.user_dir)
+            .await
+            .map_err(|e| write_error(e.to_string()))?;
+        // Write-then-rename so a crash never leaves a truncated state file.
+        let tmp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp_path, json)
+            .await
+            .map_err(|e| write_error(e.to_string()))?;
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .map_err(|e| write_error(e.to_string()))
+    }
+
     /// Get the user skills directory path.
     pub fn user_dir(&self) -> &Path {
         &self.user_dir
@@ -1136,12 +1546,6 @@ async fn checked_file_content_hash(
     Ok(compute_hash_bytes(&bytes))
 }
 
-fn compute_hash_bytes(bytes: &[u8]) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(bytes);
-    format!("sha256:{}", hex::encode(hasher.finalize()))
-}
-
 // All call sites read file handles inside `#[cfg(unix)]` permission-check
 // paths, so the helper is genuinely unix-only; gate it to match and keep the
 // non-unix build free of a dead-code error under `-D warnings`.
@@ -1430,8 +1834,7 @@ async fn build_loaded_skill(
     }
 
     // Check token budget (reject if prompt is > 2x declared budget)
-    // ~4 bytes per token for English prose = ~0.25 tokens per byte
-    let approx_tokens = (prompt_content.len() as f64 * 0.25) as usize;
+    let approx_tokens = crate::selector::approx_prompt_tokens(&prompt_content);
     let declared = manifest.activation.max_context_tokens;
     if declared > 0 && approx_tokens > declared * 2 {
         return Err(SkillRegistryError::TokenBudgetExceeded {
@@ -1443,6 +1846,8 @@ async fn build_loaded_skill(
 
     let content_hash = compute_hash(&prompt_content);
     let compiled_patterns = LoadedSkill::compile_patterns(&manifest.activation.patterns);
+    let compiled_exclude_patterns =
+        LoadedSkill::compile_patterns(&manifest.activation.exclude_patterns);
     let lowercased_keywords = to_lowercase_vec(&manifest.activation.keywords);
     let lowercased_exclude_keywords = to_lowercase_vec(&manifest.activation.exclude_keywords);
     let lowercased_tags = to_lowercase_vec(&manifest.activation.tags);
@@ -1455,6 +1860,7 @@ async fn build_loaded_skill(
         source,
         content_hash,
         compiled_patterns,
+        compiled_exclude_patterns,
         lowercased_keywords,
         lowercased_exclude_keywords,
         lowercased_tags,
@@ -1463,11 +1869,6 @@ async fn build_loaded_skill(
     Ok((name, skill))
 }
 
-/// Compute SHA-256 hash of content in the format "sha256:hex...".
-pub fn compute_hash(content: &str) -> String {
-    compute_hash_bytes(content.as_bytes())
-}
-
 /// Helper to check gating for a `GatingRequirements`. Useful for callers that
 /// don't have the full skill loaded yet.
 pub async fn check_gating(requirements: &GatingRequirements) -> crate::gating::GatingResult {
@@ -1516,6 +1917,188 @@ mod tests {
         assert!(skill.prompt_content.contains("helpful test assistant"));
     }
 
+    #[tokio::test]
+    async fn test_discover_composes_includes_across_sources() {
+        let user_dir = tempfile::tempdir().unwrap();
+        let installed_dir = tempfile::tempdir().unwrap();
+        let write = |root: &Path, name: &str, extra: &str, body: &str| {
+            let dir = root.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {name}\n{extra}---\n\n{body}\n"),
+            )
+            .unwrap();
+        };
+        write(
+            user_dir.path(),
+            "release",
+            "includes: [\"house-style\"]\n",
+            "Cut the release.",
+        );
+        write(
+            user_dir.path(),
+            "broken",
+            "includes: [\"missing-skill\"]\n",
+            "Never loads.",
+        );
+        write(installed_dir.path(), "house-style", "", "Write tersely.");
+
+        let mut registry = SkillRegistry::new(user_dir.path().to_path_buf())
+            .with_installed_dir(installed_dir.path().to_path_buf());
+        let mut loaded = registry.discover_all().await;
+        loaded.sort();
+
+        assert_eq!(loaded, vec!["house-style", "release"]);
+        let release = registry.find_by_name("release").unwrap();
+        assert_eq!(
+            release.prompt_content,
+            "Write tersely.\n\nCut the release.\n"
+        );
+        assert_eq!(release.content_hash, compute_hash(&release.prompt_content));
+        assert_eq!(release.trust, SkillTrust::Installed);
+    }
+
+    #[tokio::test]
+    async fn test_apply_reload_reports_changes_and_never_escalates_trust() {
+        let user_dir = tempfile::tempdir().unwrap();
+        let installed_dir = tempfile::tempdir().unwrap();
+        let write = |root: &Path, name: &str, body: &str| {
+            let dir = root.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: d\nactivation:\n  keywords: [\"k\"]\n---\n\n{body}\n"),
+            )
+            .unwrap();
+        };
+        write(user_dir.path(), "edited", "before");
+        write(user_dir.path(), "deleted", "gone soon");
+        write(installed_dir.path(), "promoted", "from registry");
+
+        let mut registry = SkillRegistry::new(user_dir.path().to_path_buf())
+            .with_installed_dir(installed_dir.path().to_path_buf());
+        registry.discover_all().await;
+        assert_eq!(
+            registry.find_by_name("promoted").unwrap().trust,
+            SkillTrust::Installed
+        );
+
+        write(user_dir.path(), "edited", "after");
+        fs::remove_dir_all(user_dir.path().join("deleted")).unwrap();
+        write(user_dir.path(), "fresh", "new");
+        // Copying an installed skill into the trusted user dir must not
+        // promote it on reload.
+        write(user_dir.path(), "promoted", "from registry");
+
+        let mut reloaded = registry.clone_config_for_reload();
+        reloaded.discover_all().await;
+        let outcome = registry.apply_reload(reloaded);
+
+        assert_eq!(outcome.added, vec!["fresh"]);
+        assert_eq!(outcome.updated, vec!["edited"]);
+        assert_eq!(outcome.removed, vec!["deleted"]);
+        assert_eq!(outcome.trust_retained, vec!["promoted"]);
+        assert_eq!(
+            registry.find_by_name("promoted").unwrap().trust,
+            SkillTrust::Installed
+        );
+        assert!(
+            registry
+                .find_by_name("edited")
+                .unwrap()
+                .prompt_content
+                .contains("after")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_enabled_persists_and_lists_state() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["alpha", "beta"] {
+            let skill_dir = dir.path().join(name);
+            fs::create_dir(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: d\nactivation:\n  keywords: [\"k\"]\n---\n\nbody\n"),
+            )
+            .unwrap();
+        }
+        let mut registry = SkillRegistry::new(dir.path().to_path_buf());
+        registry.discover_all().await;
+        assert!(registry.is_enabled("alpha"));
+
+        registry.set_enabled("alpha", false).await.unwrap();
+        assert!(!registry.is_enabled("alpha"));
+        let listed: Vec<(&str, bool)> = registry
+            .list()
+            .iter()
+            .map(|entry| (entry.skill.name(), entry.enabled))
+            .collect();
+        assert!(listed.contains(&("alpha", false)));
+        assert!(listed.contains(&("beta", true)));
+        assert!(matches!(
+            registry.set_enabled("missing", false).await,
+            Err(SkillRegistryError::NotFound(_))
+        ));
+
+        // State survives a fresh registry and does not load as a skill.
+        let mut restarted = SkillRegistry::new(dir.path().to_path_buf());
+        assert_eq!(restarted.discover_all().await.len(), 2);
+        assert!(!restarted.is_enabled("alpha"));
+        assert!(restarted.disabled_skills().contains("alpha"));
+
+        restarted.set_enabled("alpha", true).await.unwrap();
+        let mut again = SkillRegistry::new(dir.path().to_path_buf());
+        again.discover_all().await;
+        assert!(again.is_enabled("alpha"));
+    }
+
+    #[tokio::test]
+    async fn test_record_activations_persists_usage_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["alpha", "beta"] {
+            let skill_dir = dir.path().join(name);
+            fs::create_dir(&skill_dir).unwrap();
+            fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {name}\ndescription: d\nactivation:\n  keywords: [\"k\"]\n---\n\nbody\n"),
+            )
+            .unwrap();
+        }
+        let mut registry = SkillRegistry::new(dir.path().to_path_buf());
+        registry.discover_all().await;
+        assert!(registry.usage_stats().is_empty());
+
+        registry
+            .record_activations(["alpha", "missing"])
+            .await
+            .unwrap();
+        let first = registry.usage_stats()["alpha"].last_activated_at;
+        registry.record_activations(["alpha"]).await.unwrap();
+
+        // The first activation is written at once; the second is batched
+        // until the write interval elapses or the batch is flushed.
+        let mut restarted = SkillRegistry::new(dir.path().to_path_buf());
+        restarted.discover_all().await;
+        assert_eq!(restarted.usage_stats()["alpha"].activation_count, 1);
+        registry.flush_usage().await.unwrap();
+        registry.set_enabled("beta", false).await.unwrap();
+
+        let alpha = &registry.usage_stats()["alpha"];
+        assert_eq!(alpha.activation_count, 2);
+        assert!(alpha.last_activated_at >= first);
+        assert!(!registry.usage_stats().contains_key("beta"));
+        assert!(!registry.usage_stats().contains_key("missing"));
+
+        // Usage and enablement share the state file without clobbering
+        // each other.
+        let mut restarted = SkillRegistry::new(dir.path().to_path_buf());
+        restarted.discover_all().await;
+        assert_eq!(restarted.usage_stats(), registry.usage_stats());
+        assert!(!restarted.is_enabled("beta"));
+    }
+
     #[tokio::test]
     async fn test_workspace_overrides_user() {
         let user_dir = tempfile::tempdir().unwrap();
@@ -1802,6 +2385,67 @@ mod tests {
         assert!(content.contains("After prompt"));
     }
 
+    #[tokio::test]
+    async fn test_update_included_skill_recomposes_dependents() {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, extra, body) in [
+            ("house-style", "", "Write tersely."),
+            (
+                "release",
+                "includes: [\"house-style\"]\n",
+                "Cut the release.",
+            ),
+        ] {
+            fs::create_dir(dir.path().join(name)).unwrap();
+            fs::write(
+                dir.path().join(name).join("SKILL.md"),
+                format!("---\nname: {name}\n{extra}---\n\n{body}\n"),
+            )
+            .unwrap();
+        }
+
+        let mut registry = SkillRegistry::new(dir.path().to_path_buf());
+        registry.discover_all().await;
+        let (path, trust, source) = registry.validate_update("house-style").unwrap();
+        let loaded = SkillRegistry::prepare_update_to_disk(
+            &path,
+            "house-style",
+            "---\nname: house-style\n---\n\nWrite warmly.\n",
+            trust,
+            source,
+        )
+        .await
+        .unwrap();
+        registry.commit_update("house-style", loaded).unwrap();
+
+        let release = registry.find_by_name("release").unwrap();
+        assert_eq!(
+            release.prompt_content,
+            "Write warmly.\n\nCut the release.\n"
+        );
+        assert_eq!(release.content_hash, compute_hash(&release.prompt_content));
+
+        // An update that would make a dependent unresolvable is rejected.
+        let (path, trust, source) = registry.validate_update("house-style").unwrap();
+        let cyclic = SkillRegistry::prepare_update_to_disk(
+            &path,
+            "house-style",
+            "---\nname: house-style\nincludes: [\"release\"]\n---\n\nLoop.\n",
+            trust,
+            source,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            registry.commit_update("house-style", cyclic),
+            Err(SkillRegistryError::IncludeFailed { .. })
+        ));
+        assert_eq!(
+            registry.find_by_name("release").unwrap().prompt_content,
+            "Write warmly.\n\nCut the release.\n"
+        );
+    }
+
     #[tokio::test]
     async fn test_update_skill_rejects_rename() {
         let dir = tempfile::tempdir().unwrap();
@@ -7,11 +7,16 @@
 //! Scoring:
 //! - Keyword exact match: 10 points (capped at 30 total)
 //! - Keyword substring match: 5 points (capped at 30 total)
+//! - Keyword fuzzy match (opt-in, see [`FuzzyKeywordMatch`]): 2 points
+//!   (capped at 30 total)
 //! - Tag match: 3 points (capped at 15 total)
 //! - Regex pattern match: 20 points (capped at 40 total)
+//!
+//! Any matching `exclude_keywords` entry or `exclude_patterns` regex vetoes
+//! the skill (score 0) regardless of positive matches.
 #![allow(dead_code)] // Scaffolding; some items kept for future use.
 
-use crate::types::LoadedSkill;
+use crate::types::{LoadedSkill, MIN_KEYWORD_TAG_LENGTH};
 
 /// Default maximum context tokens allocated to skills.
 pub const MAX_SKILL_CONTEXT_TOKENS: usize = 4000;
@@ -32,6 +37,82 @@ const MAX_REGEX_SCORE: u32 = 40;
 /// on a hot path (the regex crate is linear but the constant matters at scale).
 const MAX_REGEX_MATCH_MESSAGE_BYTES: usize = 64 * 1024;
 
+/// Keywords at least this many characters long use the "long word" fuzzy
+/// distance; shorter ones use the tighter "short word" distance.
+const FUZZY_LONG_KEYWORD_CHARS: usize = 8;
+
+/// Typo-tolerant keyword matching thresholds.
+///
+/// A message word fuzzy-matches a keyword when their Levenshtein distance is
+/// within the threshold for the keyword's length. Fuzzy hits score below
+/// substring hits so an exact mention of another skill still wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyKeywordMatch {
+    /// Maximum edit distance for keywords shorter than 8 characters.
+    pub short_word_max_distance: usize,
+    /// Maximum edit distance for keywords of 8 characters or more.
+    pub long_word_max_distance: usize,
+}
+
+impl Default for FuzzyKeywordMatch {
+    fn default() -> Self {
+        Self {
+            short_word_max_distance: 1,
+            long_word_max_distance: 2,
+        }
+    }
+}
+
+impl FuzzyKeywordMatch {
+    fn max_distance(&self, keyword_chars: usize) -> usize {
+        if keyword_chars >= FUZZY_LONG_KEYWORD_CHARS {
+            self.long_word_max_distance
+        } else {
+            self.short_word_max_distance
+        }
+    }
+
+    /// Whether `word` is within the allowed edit distance of `keyword`.
+    ///
+    /// Both sides must be at least `MIN_KEYWORD_TAG_LENGTH` characters so
+    /// tiny tokens ("is", "a") can't fuzz their way into a match.
+    fn matches(&self, word: &str, keyword: &str) -> bool {
+        let word_chars = word.chars().count();
+        let keyword_chars = keyword.chars().count();
+        if word_chars < MIN_KEYWORD_TAG_LENGTH || keyword_chars < MIN_KEYWORD_TAG_LENGTH {
+            return false;
+        }
+        let max_distance = self.max_distance(keyword_chars);
+        max_distance > 0 && within_edit_distance(word, keyword, max_distance)
+    }
+}
+
+/// Levenshtein distance check that bails out as soon as every cell of a row
+/// exceeds `max_distance`, so mismatched words cost O(max_distance) rows.
+fn within_edit_distance(a: &str, b: &str, max_distance: usize) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max_distance {
+        return false;
+    }
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        let mut row_min = current[0];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            row_min = row_min.min(current[j + 1]);
+        }
+        if row_min > max_distance {
+            return false;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()] <= max_distance
+}
+
 /// Result of prefiltering with score information.
 #[derive(Debug)]
 pub struct ScoredSkill<'a> {
@@ -55,18 +136,56 @@ pub struct SelectionOutcome<'a> {
 
 /// Selection policy for deterministic skill prefiltering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct SkillSelectionOptions {
+pub struct SkillSelectionOptions<'a> {
     pub regex_activation_enabled: bool,
+    /// Source channel of the message being scored (e.g. `slack`). Skills
+    /// that declare `activation.channels` are only eligible when this
+    /// matches one of them; `None` makes every channel-scoped skill
+    /// ineligible.
+    pub channel: Option<&'a str>,
+    /// Names of skills an operator has disabled (see
+    /// `SkillRegistry::set_enabled`). Disabled skills are never selected,
+    /// including as chain-loaded companions.
+    pub disabled_skills: Option<&'a std::collections::HashSet<String>>,
+    /// Typo-tolerant keyword matching. `None` (the default) keeps keyword
+    /// scoring to exact and substring hits.
+    pub fuzzy_keywords: Option<FuzzyKeywordMatch>,
 }
 
-impl Default for SkillSelectionOptions {
+impl Default for SkillSelectionOptions<'_> {
     fn default() -> Self {
         Self {
             regex_activation_enabled: true,
+            channel: None,
+            disabled_skills: None,
+            fuzzy_keywords: None,
         }
     }
 }
 
+impl SkillSelectionOptions<'_> {
+    fn is_disabled(&self, skill: &LoadedSkill) -> bool {
+        self.disabled_skills
+            .is_some_and(|disabled| disabled.contains(skill.name()))
+    }
+}
+
+/// Whether `skill` may activate on `channel` per `activation.channels`.
+///
+/// An empty channel list means any channel. Comparison is
+/// ASCII-case-insensitive so `Telegram` and `telegram` match.
+fn channel_allowed(skill: &LoadedSkill, channel: Option<&str>) -> bool {
+    let allowed = &skill.manifest.activation.channels;
+    if allowed.is_empty() {
+        return true;
+    }
+    channel.is_some_and(|channel| {
+        allowed
+            .iter()
+            .any(|allowed| allowed.trim().eq_ignore_ascii_case(channel.trim()))
+    })
+}
+
 /// Reason a `try_select` call didn't add a skill. Callers use this to
 /// render distinct notes (budget vs. marker vs. duplicate) rather than
 /// lumping them into one opaque "skipped".
@@ -76,9 +195,42 @@ enum TrySelectOutcome {
     AlreadySelected,
     CandidateLimit,
     MarkerSatisfied,
+    ChannelExcluded,
+    Disabled,
     BudgetFull,
 }
 
+/// Rough token estimate for prompt text: ~4 bytes per token for English
+/// prose. Shared by load-time validation, selection budgeting, and
+/// prompt-assembly truncation so all three agree on a skill's size.
+pub fn approx_prompt_tokens(text: &str) -> usize {
+    text.len() / 4
+}
+
+/// Notice appended to a skill prompt that was cut to fit its budget, so the
+/// model knows the instructions it sees are incomplete.
+const TRUNCATED_PROMPT_NOTICE: &str = "\n\n[Skill prompt truncated to fit its context budget.]";
+
+/// Cut `prompt` so its estimated size fits within `max_tokens`.
+///
+/// Returns `None` when the prompt already fits (or `max_tokens` is 0, which
+/// load-time validation also treats as "undeclared"). Otherwise returns the
+/// prompt truncated at a line break where possible, followed by a short
+/// truncation notice; the notice counts against the budget.
+pub fn truncate_prompt_to_tokens(prompt: &str, max_tokens: usize) -> Option<String> {
+    if max_tokens == 0 || approx_prompt_tokens(prompt) <= max_tokens {
+        return None;
+    }
+    let max_bytes = (max_tokens * 4).saturating_sub(TRUNCATED_PROMPT_NOTICE.len());
+    let mut cut = max_bytes.min(prompt.len());
+    while !prompt.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    let head = &prompt[..cut];
+    let head = head.rfind('\n').map_or(head, |newline| &head[..newline]);
+    Some(format!("{}{TRUNCATED_PROMPT_NOTICE}", head.trim_end()))
+}
+
 /// Estimate the token cost of loading a skill's prompt into the LLM
 /// context. Prefers the declared `max_context_tokens` but falls back
 /// to the actual length-based estimate (and warns) if the declaration
@@ -87,8 +239,7 @@ enum TrySelectOutcome {
 /// bypass budgeting.
 pub fn skill_token_cost(skill: &LoadedSkill) -> usize {
     let declared_tokens = skill.manifest.activation.max_context_tokens;
-    // Rough token estimate: ~0.25 tokens per byte (~4 bytes per token for English prose)
-    let approx_tokens = (skill.prompt_content.len() as f64 * 0.25) as usize;
+    let approx_tokens = approx_prompt_tokens(&skill.prompt_content);
     let raw_cost = if approx_tokens > declared_tokens * 2 {
         tracing::warn!(
             "Skill '{}' declares max_context_tokens={} but prompt is ~{} tokens; using actual estimate",
@@ -114,6 +265,7 @@ fn try_select<'a>(
     budget_remaining: &mut usize,
     max_candidates: usize,
     satisfied_setup_markers: &std::collections::HashSet<String>,
+    options: SkillSelectionOptions<'_>,
 ) -> TrySelectOutcome {
     if result.len() >= max_candidates {
         return TrySelectOutcome::CandidateLimit;
@@ -130,6 +282,14 @@ fn try_select<'a>(
     {
         return TrySelectOutcome::MarkerSatisfied;
     }
+    // Channel scoping also applies to chain-loaded companions: a
+    // Slack-only companion must not ride a parent onto Telegram.
+    if !channel_allowed(skill, options.channel) {
+        return TrySelectOutcome::ChannelExcluded;
+    }
+    if options.is_disabled(skill) {
+        return TrySelectOutcome::Disabled;
+    }
     let cost = skill_token_cost(skill);
     if cost > *budget_remaining {
         return TrySelectOutcome::BudgetFull;
@@ -174,13 +334,24 @@ fn try_select<'a>(
 ///
 /// Pass an empty set to disable marker filtering (the legacy behavior
 /// where every skill competes regardless of workspace state).
+///
+/// ## Channel scoping
+///
+/// Skills with a non-empty `activation.channels` list are only candidates
+/// when `options.channel` matches one of the entries (ASCII
+/// case-insensitive). Skills with an empty list activate on any channel.
+///
+/// ## Disabled skills
+///
+/// Skills named in `options.disabled_skills` are skipped before scoring and
+/// are never chain-loaded, regardless of how well they match.
 pub fn prefilter_skills_with_options<'a>(
     message: &str,
     available_skills: &'a [LoadedSkill],
     max_candidates: usize,
     max_context_tokens: usize,
     satisfied_setup_markers: &std::collections::HashSet<String>,
-    options: SkillSelectionOptions,
+    options: SkillSelectionOptions<'_>,
 ) -> SelectionOutcome<'a> {
     if available_skills.is_empty() || message.is_empty() {
         return SelectionOutcome::default();
@@ -205,6 +376,9 @@ pub fn prefilter_skills_with_options<'a>(
             {
                 return None;
             }
+            if !channel_allowed(skill, options.channel) || options.is_disabled(skill) {
+                return None;
+            }
             let score = score_skill(skill, &message_lower, message, options);
             if score > 0 {
                 Some(ScoredSkill { skill, score })
@@ -232,10 +406,19 @@ pub fn prefilter_skills_with_options<'a>(
             &mut budget_remaining,
             max_candidates,
             satisfied_setup_markers,
+            options,
         );
         match parent_outcome {
             TrySelectOutcome::Selected => {}
             TrySelectOutcome::BudgetFull => {
+                // Candidates are visited highest score first, so whatever
+                // lands here is the lowest-scoring remainder.
+                tracing::info!(
+                    skill = entry.skill.name(),
+                    score = entry.score,
+                    budget_remaining,
+                    "Skill dropped: skill context budget exhausted"
+                );
                 notes.push(format!(
                     "{}: skipped (skill context budget exhausted)",
                     entry.skill.name()
@@ -251,7 +434,10 @@ pub fn prefilter_skills_with_options<'a>(
             // Already-selected / marker-satisfied are silent here:
             // the scored loop shouldn't see dup names, and marker
             // filtering already happened at scoring time. No note.
-            TrySelectOutcome::AlreadySelected | TrySelectOutcome::MarkerSatisfied => continue,
+            TrySelectOutcome::AlreadySelected
+            | TrySelectOutcome::MarkerSatisfied
+            | TrySelectOutcome::ChannelExcluded
+            | TrySelectOutcome::Disabled => continue,
         }
 
         // Chain-load companions declared in requires.skills.
@@ -269,6 +455,7 @@ pub fn prefilter_skills_with_options<'a>(
                 &mut budget_remaining,
                 max_candidates,
                 satisfied_setup_markers,
+                options,
             );
             match outcome {
                 TrySelectOutcome::Selected => {
@@ -296,6 +483,15 @@ pub fn prefilter_skills_with_options<'a>(
                         companion_name
                     ));
                 }
+                TrySelectOutcome::ChannelExcluded => {
+                    notes.push(format!(
+                        "{}: chain-load skipped (not enabled on this channel)",
+                        companion_name
+                    ));
+                }
+                TrySelectOutcome::Disabled => {
+                    notes.push(format!("{}: chain-load skipped (disabled)", companion_name));
+                }
                 // Duplicate companion across parents is fine — no note.
                 TrySelectOutcome::AlreadySelected => {}
             }
@@ -313,7 +509,7 @@ fn score_skill(
     skill: &LoadedSkill,
     message_lower: &str,
     message_original: &str,
-    options: SkillSelectionOptions,
+    options: SkillSelectionOptions<'_>,
 ) -> u32 {
     // Exclusion veto: if any exclude_keyword is present in the message, score 0
     if skill
@@ -324,20 +520,38 @@ fn score_skill(
         return 0;
     }
 
+    // Exclusion veto by regex. Applied even when regex activation is
+    // disabled: a veto can only narrow selection, never widen it.
+    if message_original.len() <= MAX_REGEX_MATCH_MESSAGE_BYTES
+        && skill
+            .compiled_exclude_patterns
+            .iter()
+            .any(|re| re.is_match(message_original))
+    {
+        return 0;
+    }
+
     let mut score: u32 = 0;
 
     // Keyword scoring with cap to prevent gaming via keyword stuffing
     let mut keyword_score: u32 = 0;
+    let words = || {
+        message_lower
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+    };
     for kw_lower in &skill.lowercased_keywords {
         // Exact word match (surrounded by word boundaries)
-        if message_lower
-            .split_whitespace()
-            .any(|word| word.trim_matches(|c: char| !c.is_alphanumeric()) == kw_lower.as_str())
-        {
+        if words().any(|word| word == kw_lower.as_str()) {
             keyword_score += 10;
         } else if message_lower.contains(kw_lower.as_str()) {
             // Substring match
             keyword_score += 5;
+        } else if let Some(fuzzy) = options.fuzzy_keywords
+            && words().any(|word| fuzzy.matches(word, kw_lower))
+        {
+            // Near-miss spelling of a single-word keyword
+            keyword_score += 2;
         }
     }
     score += keyword_score.min(MAX_KEYWORD_SCORE);
@@ -534,18 +748,22 @@ mod tests {
                     keywords: kw_vec,
                     exclude_keywords: vec![],
                     patterns: pattern_strings,
+                    exclude_patterns: vec![],
                     tags: tag_vec,
+                    channels: vec![],
                     max_context_tokens: 1000,
                     setup_marker: None,
                 },
                 credentials: vec![],
                 requires: GatingRequirements::default(),
+                includes: vec![],
             },
             prompt_content: "Test prompt".to_string(),
             trust: SkillTrust::Trusted,
             source: SkillSource::User(PathBuf::from("/tmp/test")), // safety: dummy path in test, not used for I/O
             content_hash: "sha256:000".to_string(),
             compiled_patterns: compiled,
+            compiled_exclude_patterns: vec![],
             lowercased_keywords,
             lowercased_exclude_keywords: vec![],
             lowercased_tags,
@@ -600,6 +818,7 @@ mod tests {
             &HashSet::new(),
             super::SkillSelectionOptions {
                 regex_activation_enabled: false,
+                ..Default::default()
             },
         );
 
@@ -623,6 +842,7 @@ mod tests {
             &HashSet::new(),
             super::SkillSelectionOptions {
                 regex_activation_enabled: false,
+                ..Default::default()
             },
         );
 
@@ -642,6 +862,103 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    fn prefilter_fuzzy<'a>(
+        message: &str,
+        available: &'a [LoadedSkill],
+        fuzzy: FuzzyKeywordMatch,
+    ) -> Vec<&'a LoadedSkill> {
+        super::prefilter_skills_with_options(
+            message,
+            available,
+            3,
+            MAX_SKILL_CONTEXT_TOKENS,
+            &HashSet::new(),
+            super::SkillSelectionOptions {
+                fuzzy_keywords: Some(fuzzy),
+                ..Default::default()
+            },
+        )
+        .selected
+    }
+
+    #[test]
+    fn test_fuzzy_keyword_match_is_opt_in() {
+        let skills = vec![make_skill("proofreading", &["proofread"], &[], &[])];
+        assert!(
+            prefilter_no_markers("please proofred this", &skills, 3, MAX_SKILL_CONTEXT_TOKENS)
+                .is_empty()
+        );
+        let result = prefilter_fuzzy(
+            "please proofred this",
+            &skills,
+            FuzzyKeywordMatch::default(),
+        );
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name(), "proofreading");
+    }
+
+    #[test]
+    fn test_fuzzy_keyword_match_ranks_below_exact_match() {
+        let skills = vec![
+            make_skill("proofreading", &["proofread"], &[], &[]),
+            make_skill("profiling", &["proofed"], &[], &[]),
+        ];
+        let result = prefilter_fuzzy(
+            "proofread this, the draft was proofd",
+            &skills,
+            FuzzyKeywordMatch::default(),
+        );
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name(), "proofreading");
+        assert_eq!(result[1].name(), "profiling");
+    }
+
+    #[test]
+    fn test_fuzzy_keyword_distance_depends_on_keyword_length() {
+        let skills = vec![
+            make_skill("deploy", &["deploy"], &[], &[]),
+            make_skill("proofreading", &["proofread"], &[], &[]),
+        ];
+        // Two edits: too far for a short keyword, allowed for a long one.
+        assert!(prefilter_fuzzy("dploi now", &skills, FuzzyKeywordMatch::default()).is_empty());
+        let result = prefilter_fuzzy("prfread it", &skills, FuzzyKeywordMatch::default());
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name(), "proofreading");
+
+        let strict = FuzzyKeywordMatch {
+            short_word_max_distance: 1,
+            long_word_max_distance: 1,
+        };
+        assert!(prefilter_fuzzy("prfread it", &skills, strict).is_empty());
+        let loose = FuzzyKeywordMatch {
+            short_word_max_distance: 2,
+            long_word_max_distance: 2,
+        };
+        assert_eq!(prefilter_fuzzy("dploi now", &skills, loose).len(), 1);
+    }
+
+    #[test]
+    fn test_fuzzy_keyword_match_ignores_short_tokens() {
+        let skills = vec![make_skill("git", &["git"], &[], &[])];
+        let loose = FuzzyKeywordMatch {
+            short_word_max_distance: 2,
+            long_word_max_distance: 2,
+        };
+        // "it" is one edit from "git" but below MIN_KEYWORD_TAG_LENGTH.
+        assert!(prefilter_fuzzy("do it", &skills, loose).is_empty());
+        assert_eq!(prefilter_fuzzy("use gti", &skills, loose).len(), 1);
+    }
+
+    #[test]
+    fn test_within_edit_distance() {
+        assert!(within_edit_distance("proofread", "proofread", 0));
+        assert!(within_edit_distance("proofred", "proofread", 1));
+        assert!(within_edit_distance("kitten", "sitting", 3));
+        assert!(!within_edit_distance("kitten", "sitting", 2));
+        assert!(!within_edit_distance("abc", "abcdef", 2));
+        assert!(within_edit_distance("café", "cafe", 1));
+    }
+
     #[test]
     fn test_tag_match() {
         let skills = vec![make_skill("writing", &[], &["prose", "email"], &[])];
@@ -715,6 +1032,31 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    #[test]
+    fn test_truncate_prompt_to_tokens_leaves_fitting_prompt_alone() {
+        assert_eq!(truncate_prompt_to_tokens("short prompt", 10), None);
+        // 0 means "undeclared", matching load-time validation.
+        assert_eq!(truncate_prompt_to_tokens(&"x".repeat(1000), 0), None);
+    }
+
+    #[test]
+    fn test_truncate_prompt_to_tokens_cuts_at_line_break_within_budget() {
+        let prompt = format!("{}\n{}\n{}", "a".repeat(60), "b".repeat(60), "c".repeat(60));
+        let truncated = truncate_prompt_to_tokens(&prompt, 40).expect("over budget");
+        assert!(truncated.starts_with(&"a".repeat(60)));
+        assert!(!truncated.contains("bbb"));
+        assert!(truncated.ends_with(TRUNCATED_PROMPT_NOTICE));
+        assert!(approx_prompt_tokens(&truncated) <= 40);
+    }
+
+    #[test]
+    fn test_truncate_prompt_to_tokens_respects_char_boundaries() {
+        let prompt = "é".repeat(200);
+        let truncated = truncate_prompt_to_tokens(&prompt, 30).expect("over budget");
+        assert!(truncated.starts_with('é'));
+        assert!(approx_prompt_tokens(&truncated) <= 30);
+    }
+
     #[test]
     fn test_invalid_regex_handled_gracefully() {
         let skills = vec![make_skill("bad", &["test"], &[], &["[invalid regex"])];
@@ -891,6 +1233,70 @@ mod tests {
         );
     }
 
+    fn make_skill_with_exclude_patterns(
+        name: &str,
+        keywords: &[&str],
+        exclude_patterns: &[&str],
+    ) -> LoadedSkill {
+        let mut skill = make_skill(name, keywords, &[], &[]);
+        let excl_vec: Vec<String> = exclude_patterns.iter().map(|s| s.to_string()).collect();
+        skill.compiled_exclude_patterns = LoadedSkill::compile_patterns(&excl_vec);
+        skill.manifest.activation.exclude_patterns = excl_vec;
+        skill
+    }
+
+    #[test]
+    fn test_exclude_pattern_vetos_otherwise_matching_skill() {
+        let skills = vec![make_skill_with_exclude_patterns(
+            "cooking",
+            &["recipe"],
+            &[r"(?i)\brecipe\s+for\s+disaster\b"],
+        )];
+        let vetoed = prefilter_no_markers(
+            "That plan is a recipe for disaster",
+            &skills,
+            3,
+            MAX_SKILL_CONTEXT_TOKENS,
+        );
+        assert!(
+            vetoed.is_empty(),
+            "matching exclude_pattern must veto the keyword match"
+        );
+
+        let selected = prefilter_no_markers(
+            "Find me a recipe for lasagna",
+            &skills,
+            3,
+            MAX_SKILL_CONTEXT_TOKENS,
+        );
+        assert_eq!(
+            selected.len(),
+            1,
+            "non-matching exclude_pattern must not block activation"
+        );
+    }
+
+    #[test]
+    fn test_exclude_pattern_applies_when_regex_activation_disabled() {
+        let skills = vec![make_skill_with_exclude_patterns(
+            "cooking",
+            &["recipe"],
+            &[r"(?i)recipe for disaster"],
+        )];
+        let result = super::prefilter_skills_with_options(
+            "a recipe for disaster",
+            &skills,
+            3,
+            MAX_SKILL_CONTEXT_TOKENS,
+            &HashSet::new(),
+            super::SkillSelectionOptions {
+                regex_activation_enabled: false,
+                ..Default::default()
+            },
+        );
+        assert!(result.selected.is_empty());
+    }
+
     #[test]
     fn test_apply_confidence_factor_authored() {
         assert_eq!(apply_confidence_factor(100, 0.0, true), 100);
@@ -1124,11 +1530,133 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    // ───────────────────────────────────────────────────────────────────
+    // Channel-scoped activation via activation.channels
+    // ───────────────────────────────────────────────────────────────────
+
+    fn make_channel_skill(name: &str, keywords: &[&str], channels: &[&str]) -> LoadedSkill {
+        let mut skill = make_skill(name, keywords, &[], &[]);
+        skill.manifest.activation.channels = channels.iter().map(|c| c.to_string()).collect();
+        skill
+    }
+
+    fn prefilter_on_channel<'a>(
+        message: &str,
+        available: &'a [LoadedSkill],
+        channel: Option<&str>,
+    ) -> super::SelectionOutcome<'a> {
+        super::prefilter_skills_with_options(
+            message,
+            available,
+            5,
+            MAX_SKILL_CONTEXT_TOKENS,
+            &HashSet::new(),
+            super::SkillSelectionOptions {
+                channel,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_channel_scoped_skill_only_activates_on_listed_channel() {
+        let skills = vec![
+            make_channel_skill("slack-summary", &["summary"], &["slack"]),
+            make_channel_skill("any-summary", &["summary"], &[]),
+        ];
+
+        let on_slack: Vec<&str> = prefilter_on_channel("summary please", &skills, Some("slack"))
+            .selected
+            .iter()
+            .map(|s| s.name())
+            .collect();
+        assert!(on_slack.contains(&"slack-summary"), "got: {on_slack:?}");
+        assert!(on_slack.contains(&"any-summary"), "got: {on_slack:?}");
+
+        let on_telegram: Vec<&str> =
+            prefilter_on_channel("summary please", &skills, Some("telegram"))
+                .selected
+                .iter()
+                .map(|s| s.name())
+                .collect();
+        assert_eq!(on_telegram, vec!["any-summary"]);
+
+        let unknown_channel: Vec<&str> = prefilter_on_channel("summary please", &skills, None)
+            .selected
+            .iter()
+            .map(|s| s.name())
+            .collect();
+        assert_eq!(
+            unknown_channel,
+            vec!["any-summary"],
+            "channel-scoped skill must not activate when the channel is unknown"
+        );
+    }
+
+    #[test]
+    fn test_channel_scope_match_is_case_insensitive() {
+        let skills = vec![make_channel_skill("tg-helper", &["helper"], &["Telegram"])];
+        let outcome = prefilter_on_channel("need a helper", &skills, Some("telegram"));
+        assert_eq!(outcome.selected.len(), 1);
+    }
+
+    #[test]
+    fn test_chain_load_skips_companion_scoped_to_other_channel() {
+        let parent = make_skill_with_requires("parent", &["setup"], &["slack-only"]);
+        let companion = make_channel_skill("slack-only", &["nomatch"], &["slack"]);
+        let skills = vec![parent, companion];
+
+        let outcome = prefilter_on_channel("setup", &skills, Some("telegram"));
+        let names: Vec<&str> = outcome.selected.iter().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["parent"]);
+        assert!(
+            outcome
+                .notes
+                .iter()
+                .any(|n| n.contains("slack-only") && n.contains("not enabled on this channel")),
+            "channel-excluded companion must surface a feedback note, got: {:?}",
+            outcome.notes
+        );
+    }
+
     // ───────────────────────────────────────────────────────────────────
     // Chain-loading via requires.skills — companions ride on parent
     // selection, bypassing their own score filter.
     // ───────────────────────────────────────────────────────────────────
 
+    #[test]
+    fn test_disabled_skill_is_never_selected_or_chain_loaded() {
+        let skills = vec![
+            make_skill_with_requires("parent", &["deploy"], &["companion"]),
+            make_skill("companion", &["deploy"], &[], &[]),
+            make_skill("off", &["deploy"], &[], &[]),
+        ];
+        let disabled: HashSet<String> = ["companion".to_string(), "off".to_string()].into();
+
+        let outcome = super::prefilter_skills_with_options(
+            "deploy now",
+            &skills,
+            5,
+            MAX_SKILL_CONTEXT_TOKENS,
+            &HashSet::new(),
+            super::SkillSelectionOptions {
+                disabled_skills: Some(&disabled),
+                ..Default::default()
+            },
+        );
+
+        let names: Vec<&str> = outcome.selected.iter().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["parent"]);
+        assert!(
+            outcome
+                .notes
+                .iter()
+                .any(|note| note == "companion: chain-load skipped (disabled)"),
+            "notes: {:?}",
+            outcome.notes
+        );
+    }
+
     fn make_skill_with_requires(name: &str, keywords: &[&str], required: &[&str]) -> LoadedSkill {
         let mut skill = make_skill(name, keywords, &[], &[]);
         skill.manifest.requires.skills = required.iter().map(|s| s.to_string()).collect();
@@ -0,0 +1,104 @@
+//! Operator-controlled skill state persisted in the user skills directory.
+//!
+//! Lives outside the feature-gated `registry` module so skill management can
+//! read and write the same file the registry and the runtime selector honor.
+
+use std::collections::BTreeMap;
+
+/// Registry state file in the user skills directory. Hidden, so discovery
+/// never mistakes it for skill content.
+pub const REGISTRY_STATE_FILE_NAME: &str = ".ironclaw-skill-state.json";
+
+/// Operator-controlled registry state persisted across restarts in
+/// [`REGISTRY_STATE_FILE_NAME`]. Public so hosts that load skills without a
+/// `SkillRegistry` can honor the same file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SkillRegistryState {
+    /// Skills turned off by `SkillRegistry::set_enabled`, sorted.
+    #[serde(default)]
+    pub disabled: Vec<String>,
+    /// Per-skill activation counters recorded by
+    /// `SkillRegistry::record_activations`.
+    #[serde(default)]
+    pub usage: BTreeMap<String, SkillUsage>,
+}
+
+impl SkillRegistryState {
+    /// Parse the contents of a [`REGISTRY_STATE_FILE_NAME`] file.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+
+    /// Serialize for writing back to [`REGISTRY_STATE_FILE_NAME`].
+    pub fn to_json(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec_pretty(self)
+    }
+
+    /// Whether `name` is absent from the disabled list.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        !self.disabled.iter().any(|disabled| disabled == name)
+    }
+
+    /// Add `name` to or remove it from the disabled list, keeping it sorted.
+    /// Returns whether the list changed.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        if enabled == self.is_enabled(name) {
+            return false;
+        }
+        if enabled {
+            self.disabled.retain(|disabled| disabled != name);
+        } else {
+            self.disabled.push(name.to_string());
+            self.disabled.sort();
+        }
+        true
+    }
+
+    /// Count one activation of each of `names`, stamped now.
+    pub fn record_activations<'a>(&mut self, names: impl IntoIterator<Item = &'a str>) {
+        let now = chrono::Utc::now();
+        for name in names {
+            record_usage(&mut self.usage, name, 1, now);
+        }
+    }
+
+    /// Fold counters recorded elsewhere (e.g. a batch not yet written) into
+    /// this state.
+    pub fn merge_usage(&mut self, usage: BTreeMap<String, SkillUsage>) {
+        for (name, entry) in usage {
+            record_usage(
+                &mut self.usage,
+                &name,
+                entry.activation_count,
+                entry.last_activated_at,
+            );
+        }
+    }
+}
+
+pub(crate) fn record_usage(
+    usage: &mut BTreeMap<String, SkillUsage>,
+    name: &str,
+    count: u64,
+    at: chrono::DateTime<chrono::Utc>,
+) {
+    usage
+        .entry(name.to_string())
+        .and_modify(|entry| {
+            entry.activation_count = entry.activation_count.saturating_add(count);
+            entry.last_activated_at = entry.last_activated_at.max(at);
+        })
+        .or_insert(SkillUsage {
+            activation_count: count,
+            last_activated_at: at,
+        });
+}
+
+/// How often a skill has been selected, for spotting skills nobody uses.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SkillUsage {
+    /// Number of turns the skill was selected for.
+    pub activation_count: u64,
+    /// When the skill was last selected.
+    pub last_activated_at: chrono::DateTime<chrono::Utc>,
+}
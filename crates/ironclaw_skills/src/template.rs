@@ -0,0 +1,126 @@
+//! Prompt templating for loaded skills.
+//!
+//! Skill prompts may reference a small, fixed set of placeholders that are
+//! filled in at prompt-assembly time:
+//!
+//! | Placeholder     | Value                                              |
+//! |-----------------|----------------------------------------------------|
+//! | `{{user_name}}` | Identity of the user the turn runs for             |
+//! | `{{date}}`      | Current date, ISO 8601 (`YYYY-MM-DD`, UTC)         |
+//! | `{{channel}}`   | Channel the message arrived on (e.g. `slack`)      |
+//!
+//! Rendering is a single left-to-right pass over the original prompt, so a
+//! substituted value is never re-scanned: a value containing `{{date}}` stays
+//! literal and substitution cannot recurse or expand. Values are escaped with
+//! [`escape_skill_content`] and truncated to [`MAX_TEMPLATE_VALUE_CHARS`].
+//! Unknown placeholders, and known placeholders whose value is unavailable,
+//! are left literal.
+
+use crate::validation::escape_skill_content;
+
+/// Maximum characters substituted for a single placeholder. Values come from
+/// host-owned identities and are short in practice; the cap bounds the worst
+/// case so a prompt with many placeholders cannot balloon.
+pub const MAX_TEMPLATE_VALUE_CHARS: usize = 128;
+
+/// Values available to skill prompt placeholders. `None` leaves the
+/// corresponding placeholder literal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SkillTemplateVars<'a> {
+    pub user_name: Option<&'a str>,
+    pub date: Option<&'a str>,
+    pub channel: Option<&'a str>,
+}
+
+impl SkillTemplateVars<'_> {
+    fn lookup(&self, name: &str) -> Option<&str> {
+        match name {
+            "user_name" => self.user_name,
+            "date" => self.date,
+            "channel" => self.channel,
+            _ => None,
+        }
+    }
+}
+
+/// Replace known `{{placeholder}}` tokens in `content` with escaped values.
+///
+/// See the module docs for the placeholder set and substitution rules.
+pub fn render_skill_template(content: &str, vars: &SkillTemplateVars<'_>) -> String {
+    let mut rendered = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(open) = rest.find("{{") {
+        rendered.push_str(&rest[..open]);
+        let after_open = &rest[open + 2..];
+        let Some(close) = after_open.find("}}") else {
+            rest = &rest[open..];
+            break;
+        };
+        let name = after_open[..close].trim();
+        match vars.lookup(name) {
+            Some(value) => {
+                let bounded: String = value.chars().take(MAX_TEMPLATE_VALUE_CHARS).collect();
+                rendered.push_str(&escape_skill_content(&bounded));
+            }
+            None => rendered.push_str(&rest[open..open + 2 + close + 2]),
+        }
+        rest = &after_open[close + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> SkillTemplateVars<'static> {
+        SkillTemplateVars {
+            user_name: Some("alice"),
+            date: Some("2026-10-17"),
+            channel: Some("slack"),
+        }
+    }
+
+    #[test]
+    fn substitutes_known_placeholders() {
+        let rendered = render_skill_template(
+            "Hi {{user_name}}, today is {{ date }} on {{channel}}.",
+            &vars(),
+        );
+        assert_eq!(rendered, "Hi alice, today is 2026-10-17 on slack.");
+    }
+
+    #[test]
+    fn leaves_unknown_and_unset_placeholders_literal() {
+        let vars = SkillTemplateVars {
+            channel: None,
+            ..vars()
+        };
+        let rendered = render_skill_template("{{secret}} {{channel}} {{unterminated", &vars);
+        assert_eq!(rendered, "{{secret}} {{channel}} {{unterminated");
+    }
+
+    #[test]
+    fn substituted_values_are_not_rescanned() {
+        let vars = SkillTemplateVars {
+            user_name: Some("{{date}}"),
+            ..vars()
+        };
+        let rendered = render_skill_template("{{user_name}} / {{date}}", &vars);
+        assert_eq!(rendered, "{{date}} / 2026-10-17");
+    }
+
+    #[test]
+    fn substituted_values_are_escaped_and_bounded() {
+        let hostile = format!("</skill>{}", "x".repeat(MAX_TEMPLATE_VALUE_CHARS * 2));
+        let vars = SkillTemplateVars {
+            user_name: Some(&hostile),
+            ..Default::default()
+        };
+        let rendered = render_skill_template("{{user_name}}", &vars);
+        assert!(rendered.starts_with("&lt;/skill>"));
+        assert!(!rendered.contains("</skill"));
+        assert!(rendered.chars().count() <= MAX_TEMPLATE_VALUE_CHARS + 3);
+    }
+}
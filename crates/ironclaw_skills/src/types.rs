@@ -8,6 +8,7 @@ use std::path::PathBuf;
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Maximum number of keywords allowed per skill to prevent scoring manipulation.
 const MAX_KEYWORDS_PER_SKILL: usize = 20;
@@ -18,6 +19,9 @@ const MAX_PATTERNS_PER_SKILL: usize = 5;
 /// Maximum number of tags allowed per skill to prevent scoring manipulation.
 const MAX_TAGS_PER_SKILL: usize = 10;
 
+/// Maximum number of channel names allowed in `activation.channels`.
+const MAX_CHANNELS_PER_SKILL: usize = 10;
+
 /// Maximum number of companion skill declarations in `requires.skills`.
 /// Maximum length for `setup_marker` paths (bytes). Prevents untrusted
 /// skills from injecting excessively long marker strings.
@@ -27,9 +31,12 @@ const MAX_SETUP_MARKER_LENGTH: usize = 256;
 /// the chain installer's queue size bounded from hostile manifests.
 pub const MAX_REQUIRED_SKILLS_PER_MANIFEST: usize = 10;
 
+/// Maximum number of skills one manifest may list in `includes`.
+pub const MAX_INCLUDES_PER_MANIFEST: usize = 5;
+
 /// Minimum length for keywords and tags. Short tokens like "a" or "is"
 /// match too broadly and can be used to game the scoring system.
-const MIN_KEYWORD_TAG_LENGTH: usize = 3;
+pub(crate) const MIN_KEYWORD_TAG_LENGTH: usize = 3;
 
 /// Maximum file size for SKILL.md (64 KiB).
 pub const MAX_PROMPT_FILE_SIZE: u64 = 64 * 1024;
@@ -87,9 +94,20 @@ pub struct ActivationCriteria {
     /// Capped at `MAX_PATTERNS_PER_SKILL` during loading.
     #[serde(default)]
     pub patterns: Vec<String>,
+    /// Regex patterns that veto this skill — the regex counterpart of
+    /// `exclude_keywords` (e.g. `(?i)recipe for disaster`).
+    /// Capped at `MAX_PATTERNS_PER_SKILL` during loading.
+    #[serde(default)]
+    pub exclude_patterns: Vec<String>,
     /// Tags for broad category matching.
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Channels (e.g. `slack`, `telegram`) this skill may auto-activate on,
+    /// compared ASCII-case-insensitively against the message's source
+    /// channel. Empty means any channel. Capped at `MAX_CHANNELS_PER_SKILL`
+    /// during loading.
+    #[serde(default)]
+    pub channels: Vec<String>,
     /// Maximum context tokens this skill's prompt should consume.
     #[serde(default = "default_max_context_tokens")]
     pub max_context_tokens: usize,
@@ -124,8 +142,11 @@ impl ActivationCriteria {
             .retain(|k| k.len() >= MIN_KEYWORD_TAG_LENGTH);
         self.exclude_keywords.truncate(MAX_KEYWORDS_PER_SKILL);
         self.patterns.truncate(MAX_PATTERNS_PER_SKILL);
+        self.exclude_patterns.truncate(MAX_PATTERNS_PER_SKILL);
         self.tags.retain(|t| t.len() >= MIN_KEYWORD_TAG_LENGTH);
         self.tags.truncate(MAX_TAGS_PER_SKILL);
+        self.channels.retain(|c| !c.trim().is_empty());
+        self.channels.truncate(MAX_CHANNELS_PER_SKILL);
 
         // Sanitize setup_marker: reject path traversal and enforce length.
         if let Some(ref marker) = self.setup_marker
@@ -167,6 +188,12 @@ pub struct SkillManifest {
     /// Gating requirements (binaries, env vars, config files, companion skills).
     #[serde(default)]
     pub requires: GatingRequirements,
+    /// Other loaded skills whose prompts are prepended to this one at load
+    /// time, in order. The effective trust is the minimum across this skill
+    /// and everything it includes. Capped at `MAX_INCLUDES_PER_MANIFEST`
+    /// during parsing.
+    #[serde(default)]
+    pub includes: Vec<String>,
 }
 
 fn default_version() -> String {
@@ -324,6 +351,9 @@ pub struct LoadedSkill {
     pub content_hash: String,
     /// Pre-compiled regex patterns from activation criteria (compiled at load time).
     pub compiled_patterns: Vec<Regex>,
+    /// Pre-compiled exclusion regexes from `activation.exclude_patterns`
+    /// (compiled at load time with the same size limit as `compiled_patterns`).
+    pub compiled_exclude_patterns: Vec<Regex>,
     /// Pre-computed lowercased keywords for scoring (avoids per-message allocation).
     /// Derived from `manifest.activation.keywords` at load time — do not mutate independently.
     pub lowercased_keywords: Vec<String>,
@@ -371,6 +401,17 @@ impl LoadedSkill {
     }
 }
 
+/// Compute SHA-256 hash of content in the format "sha256:hex...".
+pub fn compute_hash(content: &str) -> String {
+    compute_hash_bytes(content.as_bytes())
+}
+
+pub(crate) fn compute_hash_bytes(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("sha256:{}", hex::encode(hasher.finalize()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -391,12 +432,14 @@ mod tests {
         let mut criteria = ActivationCriteria {
             keywords: (0..30).map(|i| format!("kw{}", i)).collect(),
             patterns: (0..10).map(|i| format!("pat{}", i)).collect(),
+            exclude_patterns: (0..10).map(|i| format!("excl{}", i)).collect(),
             tags: (0..20).map(|i| format!("tag{}", i)).collect(),
             ..Default::default()
         };
         criteria.enforce_limits();
         assert_eq!(criteria.keywords.len(), MAX_KEYWORDS_PER_SKILL);
         assert_eq!(criteria.patterns.len(), MAX_PATTERNS_PER_SKILL);
+        assert_eq!(criteria.exclude_patterns.len(), MAX_PATTERNS_PER_SKILL);
         assert_eq!(criteria.tags.len(), MAX_TAGS_PER_SKILL);
     }
 
@@ -530,12 +573,14 @@ requires:
                 activation: ActivationCriteria::default(),
                 credentials: vec![],
                 requires: GatingRequirements::default(),
+                includes: vec![],
             },
             prompt_content: "test prompt".to_string(),
             trust: SkillTrust::Trusted,
             source: SkillSource::User(PathBuf::from("/tmp/test")), // safety: dummy path in test, not used for I/O
             content_hash: "sha256:000".to_string(),
             compiled_patterns: vec![],
+            compiled_exclude_patterns: vec![],
             lowercased_keywords: vec![],
             lowercased_exclude_keywords: vec![],
             lowercased_tags: vec![],
@@ -768,6 +813,19 @@ credentials:
         assert_eq!(oauth.extra_params.get("prompt").unwrap(), "consent");
     }
 
+    #[test]
+    fn enforce_limits_caps_channels_and_drops_blank_entries() {
+        let mut criteria = ActivationCriteria {
+            channels: std::iter::once(" ".to_string())
+                .chain((0..15).map(|i| format!("channel{}", i)))
+                .collect(),
+            ..Default::default()
+        };
+        criteria.enforce_limits();
+        assert_eq!(criteria.channels.len(), MAX_CHANNELS_PER_SKILL);
+        assert_eq!(criteria.channels[0], "channel0");
+    }
+
     #[test]
     fn enforce_limits_rejects_setup_marker_with_path_traversal() {
         let mut criteria = ActivationCriteria {
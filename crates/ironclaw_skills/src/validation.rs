@@ -294,6 +294,92 @@ pub fn normalize_line_endings(content: &str) -> String {
     content.replace("\r\n", "\n").replace('\r', "\n")
 }
 
+/// Default minimum run length for [`find_embedded_blobs`]. Long enough that
+/// hashes, UUIDs, and typical identifiers never qualify.
+pub const DEFAULT_EMBEDDED_BLOB_MIN_LEN: usize = 200;
+
+/// Minimum Shannon entropy (bits per character) for a run to count as a blob.
+/// Random base64 sits near 6; repetitive filler such as `=====` or `aaaa`
+/// stays well below.
+const EMBEDDED_BLOB_MIN_ENTROPY: f64 = 4.5;
+
+/// A suspiciously long base64-looking run found in skill content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedBlob {
+    /// Byte offset of the run within the scanned content.
+    pub offset: usize,
+    /// Length of the run in bytes.
+    pub len: usize,
+}
+
+impl std::fmt::Display for EmbeddedBlob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "base64-like blob of {} chars at byte offset {}",
+            self.len, self.offset
+        )
+    }
+}
+
+/// Find base64-looking runs that could smuggle instructions or payloads.
+///
+/// A run is a maximal sequence of base64 / base64url characters
+/// (`A-Z a-z 0-9 + / - _ =`). It is reported when it is at least `min_len`
+/// bytes, mixes upper case, lower case, and digits, and has high character
+/// entropy. Prose never qualifies because whitespace and punctuation break
+/// runs, so incidental `=` or `+` in text is harmless.
+pub fn find_embedded_blobs(content: &str, min_len: usize) -> Vec<EmbeddedBlob> {
+    fn is_blob_byte(b: u8) -> bool {
+        b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_' | b'=')
+    }
+
+    let bytes = content.as_bytes();
+    let mut blobs = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_blob_byte(bytes[i]) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && is_blob_byte(bytes[i]) {
+            i += 1;
+        }
+        let run = &bytes[start..i];
+        if run.len() >= min_len.max(1) && looks_like_encoded_blob(run) {
+            blobs.push(EmbeddedBlob {
+                offset: start,
+                len: run.len(),
+            });
+        }
+    }
+    blobs
+}
+
+fn looks_like_encoded_blob(run: &[u8]) -> bool {
+    let has_upper = run.iter().any(u8::is_ascii_uppercase);
+    let has_lower = run.iter().any(u8::is_ascii_lowercase);
+    let has_digit = run.iter().any(u8::is_ascii_digit);
+    if !(has_upper && has_lower && has_digit) {
+        return false;
+    }
+    let mut counts = [0usize; 256];
+    for &b in run {
+        counts[usize::from(b)] += 1;
+    }
+    let len = run.len() as f64;
+    let entropy: f64 = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum();
+    entropy >= EMBEDDED_BLOB_MIN_ENTROPY
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -685,4 +771,53 @@ mod tests {
         };
         assert!(validate_credential_spec(&spec).is_empty());
     }
+
+    /// Deterministic pseudo-random base64 so the test does not need a
+    /// base64 dependency.
+    fn pseudo_base64(len: usize) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut state: u32 = 0x2545_f491;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                ALPHABET[(state % 64) as usize] as char
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_embedded_blobs_flags_long_base64_run() {
+        let blob = pseudo_base64(300);
+        let content = format!("Follow these steps.\n\n{blob}==\n\nThanks.");
+        let found = find_embedded_blobs(&content, DEFAULT_EMBEDDED_BLOB_MIN_LEN);
+        assert_eq!(
+            found,
+            vec![EmbeddedBlob {
+                offset: "Follow these steps.\n\n".len(),
+                len: 302,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_embedded_blobs_respects_threshold() {
+        let content = pseudo_base64(150);
+        assert!(find_embedded_blobs(&content, DEFAULT_EMBEDDED_BLOB_MIN_LEN).is_empty());
+        assert_eq!(find_embedded_blobs(&content, 100).len(), 1);
+    }
+
+    #[test]
+    fn test_find_embedded_blobs_ignores_prose_and_low_entropy_runs() {
+        let prose = "Use a+b=c when x/y is small, set FOO=bar and retry. ".repeat(20);
+        assert!(find_embedded_blobs(&prose, DEFAULT_EMBEDDED_BLOB_MIN_LEN).is_empty());
+
+        let filler = format!("{}{}", "=".repeat(300), "Ab1".repeat(100));
+        assert!(find_embedded_blobs(&filler, DEFAULT_EMBEDDED_BLOB_MIN_LEN).is_empty());
+
+        // Long lowercase identifiers / hex digests lack the mixed charset.
+        let hex = "deadbeef0123456789".repeat(20);
+        assert!(find_embedded_blobs(&hex, DEFAULT_EMBEDDED_BLOB_MIN_LEN).is_empty());
+    }
 }
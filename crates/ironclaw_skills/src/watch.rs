@@ -0,0 +1,296 @@
+//! Opt-in hot reload for the user skills directory.
+//!
+//! This is a library-only API for embedders that keep a long-lived
+//! [`SkillRegistry`]. The agent runtime does not use it: its filesystem
+//! bundle source re-lists user skill bundles on every turn, so edits are
+//! already visible on the next turn without a watcher.
+//!
+//! An embedder's registry otherwise only sees edits to `~/.ironclaw/skills/`
+//! after a restart. [`load_skill_registry`] runs the initial
+//! discovery and, when given a [`SkillWatchConfig`], starts the watcher.
+//! [`spawn_skill_watcher`] polls a cheap
+//! fingerprint of the user directory (relative path, size and mtime of every
+//! file within the registry's scan depth), waits for it to settle so a burst
+//! of editor saves triggers a single reload, then re-discovers skills into a
+//! fresh registry outside the lock and swaps the result in under one write
+//! lock via [`reload_skill_registry`].
+//!
+//! Reloads never escalate trust: a skill that was loaded as `Installed` stays
+//! `Installed` until the process restarts, even if a copy now appears in a
+//! trusted directory.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+use crate::registry::{SkillRegistry, SkillReloadOutcome};
+
+/// Default interval between fingerprint polls.
+pub const DEFAULT_SKILL_WATCH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default quiet period a change must survive before it is reloaded.
+pub const DEFAULT_SKILL_WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Upper bound on files fingerprinted per poll. Directories beyond this are
+/// still reloaded when a fingerprinted file changes; the cap only bounds the
+/// cost of a single poll.
+const MAX_FINGERPRINT_ENTRIES: usize = 4096;
+
+/// Timing knobs for [`spawn_skill_watcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillWatchConfig {
+    pub poll_interval: Duration,
+    pub debounce: Duration,
+}
+
+impl Default for SkillWatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_SKILL_WATCH_POLL_INTERVAL,
+            debounce: DEFAULT_SKILL_WATCH_DEBOUNCE,
+        }
+    }
+}
+
+type Fingerprint = Vec<(PathBuf, u64, Option<SystemTime>)>;
+
+/// A registry shared with its hot-reload watcher, if one was started.
+///
+/// Dropping it stops the watcher.
+pub struct WatchedSkillRegistry {
+    registry: Arc<RwLock<SkillRegistry>>,
+    watcher: Option<JoinHandle<()>>,
+}
+
+impl WatchedSkillRegistry {
+    /// The shared registry readers should hold.
+    pub fn registry(&self) -> &Arc<RwLock<SkillRegistry>> {
+        &self.registry
+    }
+
+    /// Whether a watcher is reloading the registry.
+    pub fn is_watching(&self) -> bool {
+        self.watcher.is_some()
+    }
+}
+
+impl Drop for WatchedSkillRegistry {
+    fn drop(&mut self) {
+        if let Some(watcher) = self.watcher.take() {
+            watcher.abort();
+        }
+    }
+}
+
+/// Discover `registry`'s skills and share it, watching the user skills
+/// directory for edits when `watch` is set.
+pub async fn load_skill_registry(
+    mut registry: SkillRegistry,
+    watch: Option<SkillWatchConfig>,
+) -> WatchedSkillRegistry {
+    registry.discover_all().await;
+    let registry = Arc::new(RwLock::new(registry));
+    let watcher = watch.map(|config| spawn_skill_watcher(Arc::clone(&registry), config));
+    WatchedSkillRegistry { registry, watcher }
+}
+
+/// Re-discover skills into a fresh registry outside the lock, then swap the
+/// result into `registry` under one write lock.
+pub async fn reload_skill_registry(registry: &RwLock<SkillRegistry>) -> SkillReloadOutcome {
+    let mut reloaded = registry.read().await.clone_config_for_reload();
+    reloaded.discover_all().await;
+    registry.write().await.apply_reload(reloaded)
+}
+
+/// Spawn a background task that hot-reloads `registry` when files under its
+/// user skills directory change.
+///
+/// The task runs until the returned handle is aborted or the runtime shuts
+/// down. Discovery happens on a fresh registry built from the same
+/// configuration, so readers never observe a partially reloaded skill set.
+pub fn spawn_skill_watcher(
+    registry: Arc<RwLock<SkillRegistry>>,
+    config: SkillWatchConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let (user_dir, max_depth) = {
+            let registry = registry.read().await;
+            (registry.user_dir().to_path_buf(), registry.max_scan_depth())
+        };
+        let mut last = fingerprint_async(&user_dir, max_depth).await;
+        loop {
+            tokio::time::sleep(config.poll_interval).await;
+            let mut current = fingerprint_async(&user_dir, max_depth).await;
+            if current == last {
+                continue;
+            }
+            // Debounce: wait until the directory stops changing.
+            loop {
+                tokio::time::sleep(config.debounce).await;
+                let settled = fingerprint_async(&user_dir, max_depth).await;
+                if settled == current {
+                    break;
+                }
+                current = settled;
+            }
+            last = current;
+
+            let outcome = reload_skill_registry(&registry).await;
+            log_reload_outcome(&user_dir, &outcome);
+        }
+    })
+}
+
+fn log_reload_outcome(user_dir: &Path, outcome: &SkillReloadOutcome) {
+    if outcome.is_empty() {
+        tracing::debug!(
+            "Skill directory {} changed; no skill content changed",
+            user_dir.display()
+        );
+        return;
+    }
+    tracing::info!(
+        added = ?outcome.added,
+        updated = ?outcome.updated,
+        removed = ?outcome.removed,
+        warnings = ?outcome.warnings,
+        "Hot-reloaded skills from {}",
+        user_dir.display()
+    );
+    for name in &outcome.trust_retained {
+        tracing::warn!(
+            "Skill '{}' reloaded from a more trusted location; keeping its previous trust until restart",
+            name
+        );
+    }
+}
+
+async fn fingerprint_async(dir: &Path, max_depth: usize) -> Fingerprint {
+    let dir = dir.to_path_buf();
+    tokio::task::spawn_blocking(move || fingerprint(&dir, max_depth))
+        .await
+        .unwrap_or_default()
+}
+
+/// Collect `(relative path, size, mtime)` for files under `dir`, sorted so
+/// two fingerprints compare equal exactly when nothing observable changed.
+/// Symlinks are not followed, matching discovery.
+fn fingerprint(dir: &Path, max_depth: usize) -> Fingerprint {
+    let mut entries = Vec::new();
+    let mut stack = vec![(dir.to_path_buf(), 0usize)];
+    while let Some((current, depth)) = stack.pop() {
+        let Ok(read_dir) = std::fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in read_dir.flatten() {
+            if entries.len() >= MAX_FINGERPRINT_ENTRIES {
+                break;
+            }
+            let path = entry.path();
+            if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with('.'))
+            {
+                continue;
+            }
+            let Ok(metadata) = std::fs::symlink_metadata(&path) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if depth < max_depth {
+                    stack.push((path, depth + 1));
+                }
+            } else if metadata.is_file() {
+                let relative = path.strip_prefix(dir).unwrap_or(&path).to_path_buf();
+                entries.push((relative, metadata.len(), metadata.modified().ok()));
+            }
+        }
+    }
+    entries.sort();
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const SKILL_MD: &str = "---\nname: watched\ndescription: Watched skill\nactivation:\n  keywords: [\"watch\"]\n---\n\n";
+
+    fn write_watched(dir: &Path, body: &str) {
+        let skill_dir = dir.join("watched");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), format!("{SKILL_MD}{body}\n")).unwrap();
+    }
+
+    #[test]
+    fn fingerprint_skips_hidden_entries_and_respects_depth() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("a/b")).unwrap();
+        fs::create_dir(dir.path().join(".users")).unwrap();
+        fs::write(dir.path().join("a/SKILL.md"), "x").unwrap();
+        fs::write(dir.path().join("a/b/SKILL.md"), "x").unwrap();
+        fs::write(dir.path().join(".users/SKILL.md"), "x").unwrap();
+
+        let shallow = fingerprint(dir.path(), 1);
+        let paths: Vec<_> = shallow.iter().map(|(path, _, _)| path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("a/SKILL.md")]);
+        assert_eq!(fingerprint(dir.path(), 2).len(), 2);
+    }
+
+    #[test]
+    fn fingerprint_changes_when_a_skill_is_edited() {
+        let dir = tempfile::tempdir().unwrap();
+        write_watched(dir.path(), "first body");
+        let before = fingerprint(dir.path(), 1);
+
+        write_watched(dir.path(), "second, longer body");
+
+        assert_ne!(fingerprint(dir.path(), 1), before);
+    }
+
+    #[tokio::test]
+    async fn reload_picks_up_edited_skill() {
+        let dir = tempfile::tempdir().unwrap();
+        write_watched(dir.path(), "first body");
+        let loaded = load_skill_registry(SkillRegistry::new(dir.path().to_path_buf()), None).await;
+        assert!(!loaded.is_watching());
+
+        write_watched(dir.path(), "second, longer body");
+        let outcome = reload_skill_registry(loaded.registry()).await;
+
+        assert_eq!(outcome.updated, vec!["watched".to_string()]);
+        let guard = loaded.registry().read().await;
+        let skill = guard.find_by_name("watched").unwrap();
+        assert!(skill.prompt_content.contains("second, longer body"));
+        assert_eq!(
+            skill.content_hash,
+            crate::registry::compute_hash(&skill.prompt_content)
+        );
+    }
+
+    #[tokio::test]
+    async fn reload_outcome_reports_scan_warnings() {
+        let dir = tempfile::tempdir().unwrap();
+        write_watched(dir.path(), "body");
+        let loaded = load_skill_registry(
+            SkillRegistry::new(dir.path().to_path_buf()),
+            Some(SkillWatchConfig::default()),
+        )
+        .await;
+        assert!(loaded.is_watching());
+
+        fs::create_dir(dir.path().join("broken")).unwrap();
+        fs::write(dir.path().join("broken/SKILL.md"), "no frontmatter").unwrap();
+        let outcome = reload_skill_registry(loaded.registry()).await;
+
+        assert!(outcome.added.is_empty());
+        assert_eq!(outcome.warnings.len(), 1, "{:?}", outcome.warnings);
+        assert!(outcome.warnings[0].contains("broken"));
+        assert!(!outcome.is_empty());
+    }
+}
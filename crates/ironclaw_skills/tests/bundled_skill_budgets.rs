@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use ironclaw_skills::{approx_prompt_tokens, parse_skill_md};
+
+/// The loop host cuts any prompt over its declared `max_context_tokens`, so a
+/// bundled skill that outgrows its budget silently loses its closing
+/// instructions on every turn.
+#[test]
+fn bundled_skills_fit_their_declared_context_budget() {
+    let skills_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../skills");
+    let mut checked = 0;
+    let mut over_budget = Vec::new();
+    for entry in std::fs::read_dir(&skills_root).expect("bundled skills root must be readable") {
+        let skill_md = entry
+            .expect("skill directory entry must be readable")
+            .path()
+            .join("SKILL.md");
+        if !skill_md.is_file() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&skill_md).expect("SKILL.md must be readable");
+        let parsed = parse_skill_md(&content)
+            .unwrap_or_else(|error| panic!("{} must parse: {error}", skill_md.display()));
+        let approx_tokens = approx_prompt_tokens(&parsed.prompt_content);
+        let budget = parsed.manifest.activation.max_context_tokens;
+        if approx_tokens > budget {
+            over_budget.push(format!(
+                "{}: ~{approx_tokens} tokens, max_context_tokens {budget}",
+                parsed.manifest.name
+            ));
+        }
+        checked += 1;
+    }
+
+    assert!(checked > 0, "no bundled skills found");
+    assert!(
+        over_budget.is_empty(),
+        "bundled skills exceed their budget:\n{}",
+        over_budget.join("\n")
+    );
+}
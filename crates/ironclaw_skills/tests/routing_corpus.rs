@@ -180,6 +180,8 @@ fn load_bundled_skill(path: &Path) -> LoadedSkill {
         path.display()
     );
     let compiled_patterns = LoadedSkill::compile_patterns(&parsed.manifest.activation.patterns);
+    let compiled_exclude_patterns =
+        LoadedSkill::compile_patterns(&parsed.manifest.activation.exclude_patterns);
     let lowercased_keywords = parsed
         .manifest
         .activation
@@ -209,6 +211,7 @@ fn load_bundled_skill(path: &Path) -> LoadedSkill {
         source: SkillSource::Bundled(PathBuf::from(path)),
         content_hash: String::new(),
         compiled_patterns,
+        compiled_exclude_patterns,
         lowercased_keywords,
         lowercased_exclude_keywords,
         lowercased_tags,
@@ -1,10 +1,11 @@
 //! The Slack [`ChannelAdapter`] (generic ingress cutover P4; delivery
 //! coordinator cutover P5).
 //!
-//! `inbound` parses one HOST-VERIFIED Slack Events API request into a
-//! normalized outcome (signature verification lives in the host's generic
-//! recipe verifier; this adapter never sees signing secrets). `deliver`
-//! renders one coordinator envelope to Slack mrkdwn, splits oversized text,
+//! `inbound` parses one HOST-VERIFIED Slack Events API or interactivity
+//! (button click) request into a normalized outcome (signature verification
+//! lives in the host's generic recipe verifier; this adapter never sees
+//! signing secrets). `deliver` renders one coordinator envelope to Slack
+//! mrkdwn, offers choices as Block Kit buttons, splits oversized text,
 //! posts each message via `chat.postMessage` over restricted egress (the
 //! host injects the bot token by declared handle), and maps vendor errors to
 //! structured per-part outcomes — the adapter has no store and cannot mark
@@ -12,9 +13,10 @@
 
 use async_trait::async_trait;
 use ironclaw_host_api::product_adapter::{
-    AdapterInstallationId, ChannelAdapter, ChannelError, DeliveryReport, ExternalConversationRef,
-    ImmediateResponse, InboundOutcome, OutboundEnvelope, OutboundPart, PartDeliveryOutcome,
-    TargetCandidate, TargetQuery, VerifiedInbound, render_channel_auth_prompt,
+    AdapterInstallationId, AuthPromptView, ChannelAdapter, ChannelError, DeliveryReport,
+    ExternalConversationRef, ImmediateResponse, InboundOutcome, NormalizedInboundMessage,
+    OutboundChoice, OutboundEnvelope, OutboundPart, PartDeliveryOutcome, TargetCandidate,
+    TargetQuery, VerifiedInbound, render_channel_auth_prompt, render_channel_choices,
 };
 use ironclaw_host_api::{
     NetworkMethod, RestrictedEgress, RestrictedEgressError, RestrictedEgressRequest, SecretHandle,
@@ -24,13 +26,27 @@ use serde::Deserialize;
 use crate::delivery::{SlackDeliveryFailureKind, slack_error_kind};
 use crate::mrkdwn::{render_slack_mrkdwn, slack_text_chunks};
 use crate::payload::{
-    SLACK_API_HOST, SlackInboundEvent, SlackPayloadParseError, normalize_slack_event,
+    SLACK_API_HOST, SLACK_CHOICE_ACTION_ID_PREFIX, SlackInboundEvent, SlackPayloadParseError,
+    SlackReplyContext, normalize_slack_event, normalize_slack_interaction,
 };
+use crate::thread_context::fetch_thread_context;
 
 /// The administrator-configuration handle carrying the bot token (manifest data; the
 /// host injects the secret at egress time).
 const SLACK_BOT_TOKEN_HANDLE: &str = "slack_bot_token";
 
+/// `chat.postEphemeral` errors meaning the requester can't see an ephemeral
+/// message in this channel; the reply falls back to a DM instead.
+const EPHEMERAL_UNREACHABLE_ERRORS: &[&str] =
+    &["user_not_in_channel", "not_in_channel", "channel_not_found"];
+
+/// Block Kit limits for a choices message: `section` text, button label and
+/// value, and elements per `actions` block.
+const SLACK_SECTION_TEXT_LIMIT_CHARS: usize = 3_000;
+const SLACK_BUTTON_LABEL_LIMIT_CHARS: usize = 75;
+const SLACK_BUTTON_VALUE_LIMIT_CHARS: usize = 2_000;
+const SLACK_ACTIONS_ELEMENT_LIMIT: usize = 25;
+
 /// Stateless Slack channel adapter: pure protocol parsing for the generic
 /// ingress router.
 #[derive(Debug, Default, Clone, Copy)]
@@ -45,7 +61,21 @@ impl ChannelAdapter for SlackChannelAdapter {
                     reason: format!("invalid installation id: {error}"),
                 }
             })?;
-        match normalize_slack_event(request.body, &installation_id).map_err(parse_error)? {
+        // Button clicks arrive form-encoded (`payload=<json>`); everything
+        // else is an Events API JSON body.
+        let interactive = request.headers.iter().any(|(name, value)| {
+            name.eq_ignore_ascii_case("content-type")
+                && value
+                    .trim_start()
+                    .to_ascii_lowercase()
+                    .starts_with("application/x-www-form-urlencoded")
+        });
+        let event = if interactive {
+            normalize_slack_interaction(request.body, &installation_id)
+        } else {
+            normalize_slack_event(request.body, &installation_id)
+        };
+        match event.map_err(parse_error)? {
             SlackInboundEvent::UrlVerification { challenge } => {
                 Ok(InboundOutcome::Respond(ImmediateResponse {
                     status: 200,
@@ -80,6 +110,11 @@ impl ChannelAdapter for SlackChannelAdapter {
             .thread_anchor
             .clone()
             .or_else(|| envelope.target.conversation.topic_id().map(str::to_string));
+        // Who asked, when the inbound message came from a shared channel.
+        let requester = envelope
+            .reply_context
+            .as_deref()
+            .and_then(SlackReplyContext::decode);
 
         let mut parts = Vec::new();
         'parts: for part in &envelope.parts {
@@ -109,6 +144,27 @@ impl ChannelAdapter for SlackChannelAdapter {
                     view,
                     direct_message,
                 } => {
+                    // Auth prompts carry pairing codes and setup links; in a
+                    // shared channel only the requester should see them.
+                    if let (false, Some(requester)) = (*direct_message, requester.as_ref()) {
+                        let outcomes = deliver_private_auth_prompt(
+                            egress,
+                            &credential,
+                            &channel,
+                            thread_ts.as_deref(),
+                            &requester.user,
+                            view,
+                        )
+                        .await;
+                        let sent = outcomes
+                            .iter()
+                            .all(|outcome| matches!(outcome, PartDeliveryOutcome::Sent { .. }));
+                        parts.extend(outcomes);
+                        if !sent {
+                            break 'parts;
+                        }
+                        continue;
+                    }
                     let markdown = render_channel_auth_prompt(view, *direct_message);
                     let rendered = render_slack_mrkdwn(&markdown);
                     for chunk in slack_text_chunks(&rendered) {
@@ -127,6 +183,45 @@ impl ChannelAdapter for SlackChannelAdapter {
                         }
                     }
                 }
+                OutboundPart::Choices { text, choices } => {
+                    // Buttons when Block Kit can carry them (a click comes
+                    // back through `inbound` as the choice's reply);
+                    // otherwise the choices as replies the user can type.
+                    let rendered = render_slack_mrkdwn(text);
+                    if let Some(blocks) = slack_choice_blocks(&rendered, choices) {
+                        let outcome = post_slack_message(
+                            egress,
+                            &credential,
+                            &channel,
+                            thread_ts.as_deref(),
+                            &rendered,
+                            Some(blocks),
+                        )
+                        .await;
+                        let sent = matches!(outcome, PartDeliveryOutcome::Sent { .. });
+                        parts.push(outcome);
+                        if !sent {
+                            break 'parts;
+                        }
+                        continue;
+                    }
+                    let rendered = render_slack_mrkdwn(&render_channel_choices(text, choices));
+                    for chunk in slack_text_chunks(&rendered) {
+                        let outcome = post_slack_chunk(
+                            egress,
+                            &credential,
+                            &channel,
+                            thread_ts.as_deref(),
+                            &chunk,
+                        )
+                        .await;
+                        let sent = matches!(outcome, PartDeliveryOutcome::Sent { .. });
+                        parts.push(outcome);
+                        if !sent {
+                            break 'parts;
+                        }
+                    }
+                }
                 OutboundPart::Retract { vendor_message_ref } => {
                     let outcome =
                         delete_slack_message(egress, &credential, &channel, vendor_message_ref)
@@ -163,51 +258,7 @@ impl ChannelAdapter for SlackChannelAdapter {
                 reason: format!("invalid bot token handle: {error}"),
             }
         })?;
-        let body = serde_json::to_vec(&serde_json::json!({ "users": slack_user_id })).map_err(
-            |error| ChannelError::VendorWiring {
-                reason: format!("conversations.open body did not serialize: {error}"),
-            },
-        )?;
-        let response = egress
-            .send(RestrictedEgressRequest {
-                method: NetworkMethod::Post,
-                url: format!("https://{SLACK_API_HOST}/api/conversations.open"),
-                headers: vec![(
-                    "content-type".to_string(),
-                    "application/json; charset=utf-8".to_string(),
-                )],
-                body: Some(body),
-                credential: Some(credential),
-                body_credentials: Vec::new(),
-            })
-            .await
-            .map_err(|error| ChannelError::VendorWiring {
-                reason: format!("conversations.open egress failed: {error}"),
-            })?;
-        if !(200..300).contains(&response.status) {
-            return Err(ChannelError::VendorWiring {
-                reason: format!("slack web api returned status {}", response.status),
-            });
-        }
-        let parsed: SlackConversationsOpenResponse = serde_json::from_slice(&response.body)
-            .map_err(|error| ChannelError::VendorWiring {
-                reason: format!("conversations.open response was not valid JSON: {error}"),
-            })?;
-        if !parsed.ok {
-            return Err(ChannelError::VendorWiring {
-                reason: format!(
-                    "slack rejected conversations.open ({})",
-                    parsed.error.unwrap_or_else(|| "unknown_error".to_string())
-                ),
-            });
-        }
-        let channel_id = parsed
-            .channel
-            .map(|channel| channel.id)
-            .filter(|id| !id.is_empty())
-            .ok_or_else(|| ChannelError::VendorWiring {
-                reason: "conversations.open response missing channel id".to_string(),
-            })?;
+        let channel_id = open_direct_message(egress, &credential, slack_user_id).await?;
         let conversation =
             ExternalConversationRef::new(None, &channel_id, None, None).map_err(|error| {
                 ChannelError::VendorWiring {
@@ -219,6 +270,72 @@ impl ChannelAdapter for SlackChannelAdapter {
             display_name: "Direct message".to_string(),
         }])
     }
+
+    /// A mid-thread message carries the thread above it: the parent plus the
+    /// newest replies, without this app's own.
+    async fn inbound_context(
+        &self,
+        message: &NormalizedInboundMessage,
+        egress: &dyn RestrictedEgress,
+    ) -> Option<String> {
+        let credential = SecretHandle::new(SLACK_BOT_TOKEN_HANDLE).ok()?;
+        fetch_thread_context(message, egress, &credential).await
+    }
+}
+
+/// Provision (or reuse) the 1:1 DM conversation with `slack_user_id` via
+/// `conversations.open`, returning its channel id.
+async fn open_direct_message(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    slack_user_id: &str,
+) -> Result<String, ChannelError> {
+    let body =
+        serde_json::to_vec(&serde_json::json!({ "users": slack_user_id })).map_err(|error| {
+            ChannelError::VendorWiring {
+                reason: format!("conversations.open body did not serialize: {error}"),
+            }
+        })?;
+    let response = egress
+        .send(RestrictedEgressRequest {
+            method: NetworkMethod::Post,
+            url: format!("https://{SLACK_API_HOST}/api/conversations.open"),
+            headers: vec![(
+                "content-type".to_string(),
+                "application/json; charset=utf-8".to_string(),
+            )],
+            body: Some(body),
+            credential: Some(credential.clone()),
+            body_credentials: Vec::new(),
+        })
+        .await
+        .map_err(|error| ChannelError::VendorWiring {
+            reason: format!("conversations.open egress failed: {error}"),
+        })?;
+    if !(200..300).contains(&response.status) {
+        return Err(ChannelError::VendorWiring {
+            reason: format!("slack web api returned status {}", response.status),
+        });
+    }
+    let parsed: SlackConversationsOpenResponse =
+        serde_json::from_slice(&response.body).map_err(|error| ChannelError::VendorWiring {
+            reason: format!("conversations.open response was not valid JSON: {error}"),
+        })?;
+    if !parsed.ok {
+        return Err(ChannelError::VendorWiring {
+            reason: format!(
+                "slack rejected conversations.open ({})",
+                parsed.error.unwrap_or_else(|| "unknown_error".to_string())
+            ),
+        });
+    }
+    parsed
+        .channel
+        .map(|channel| channel.id)
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| ChannelError::VendorWiring {
+            reason: "conversations.open response missing channel id".to_string(),
+        })
 }
 
 #[derive(Debug, Deserialize)]
@@ -240,17 +357,68 @@ struct SlackChatPostMessageResponse {
     ts: Option<String>,
 }
 
+/// A `section` with the message text over one `actions` block holding a
+/// button per choice (each button's `value` is the choice's reply). `None`
+/// when any limit would be exceeded; the caller then falls back to typed
+/// replies.
+fn slack_choice_blocks(text: &str, choices: &[OutboundChoice]) -> Option<serde_json::Value> {
+    if text.trim().is_empty()
+        || text.chars().count() > SLACK_SECTION_TEXT_LIMIT_CHARS
+        || choices.is_empty()
+        || choices.len() > SLACK_ACTIONS_ELEMENT_LIMIT
+        || choices.iter().any(|choice| {
+            choice.label.is_empty()
+                || choice.reply.is_empty()
+                || choice.label.chars().count() > SLACK_BUTTON_LABEL_LIMIT_CHARS
+                || choice.reply.chars().count() > SLACK_BUTTON_VALUE_LIMIT_CHARS
+        })
+    {
+        return None;
+    }
+    let buttons = choices
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            serde_json::json!({
+                "type": "button",
+                "action_id": format!("{SLACK_CHOICE_ACTION_ID_PREFIX}{index}"),
+                "text": { "type": "plain_text", "text": choice.label },
+                "value": choice.reply,
+            })
+        })
+        .collect::<Vec<_>>();
+    Some(serde_json::json!([
+        { "type": "section", "text": { "type": "mrkdwn", "text": text } },
+        { "type": "actions", "elements": buttons },
+    ]))
+}
+
 async fn post_slack_chunk(
     egress: &dyn RestrictedEgress,
     credential: &SecretHandle,
     channel: &str,
     thread_ts: Option<&str>,
     text: &str,
+) -> PartDeliveryOutcome {
+    post_slack_message(egress, credential, channel, thread_ts, text, None).await
+}
+
+/// `chat.postMessage`; with `blocks`, `text` is the notification fallback.
+async fn post_slack_message(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    channel: &str,
+    thread_ts: Option<&str>,
+    text: &str,
+    blocks: Option<serde_json::Value>,
 ) -> PartDeliveryOutcome {
     let mut body = serde_json::json!({ "channel": channel, "text": text });
     if let Some(thread_ts) = thread_ts {
         body["thread_ts"] = serde_json::Value::String(thread_ts.to_string());
     }
+    if let Some(blocks) = blocks {
+        body["blocks"] = blocks;
+    }
     let body = match serde_json::to_vec(&body) {
         Ok(body) => body,
         Err(error) => {
@@ -303,6 +471,145 @@ async fn post_slack_chunk(
     )
 }
 
+/// Outcome of one `chat.postEphemeral` attempt.
+enum EphemeralPost {
+    Delivered(PartDeliveryOutcome),
+    /// The requester can't receive ephemeral messages in this channel.
+    RequesterUnreachable,
+}
+
+/// Deliver an auth prompt privately to `user`: ephemerally in the shared
+/// channel, or — when Slack can't show it there (e.g. the user left the
+/// channel) — as a DM rendered with DM wording.
+///
+/// Ephemeral messages can't be retracted, so their outcomes carry no
+/// vendor message reference.
+async fn deliver_private_auth_prompt(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    channel: &str,
+    thread_ts: Option<&str>,
+    user: &str,
+    view: &AuthPromptView,
+) -> Vec<PartDeliveryOutcome> {
+    let mut outcomes = Vec::new();
+    let rendered = render_slack_mrkdwn(&render_channel_auth_prompt(view, false));
+    for chunk in slack_text_chunks(&rendered) {
+        match post_slack_ephemeral(egress, credential, channel, user, thread_ts, &chunk).await {
+            EphemeralPost::Delivered(outcome) => {
+                let sent = matches!(outcome, PartDeliveryOutcome::Sent { .. });
+                outcomes.push(outcome);
+                if !sent {
+                    return outcomes;
+                }
+            }
+            EphemeralPost::RequesterUnreachable if outcomes.is_empty() => {
+                return deliver_auth_prompt_by_direct_message(egress, credential, user, view).await;
+            }
+            EphemeralPost::RequesterUnreachable => {
+                outcomes.push(PartDeliveryOutcome::Permanent {
+                    reason: "requester left the channel mid-delivery".to_string(),
+                });
+                return outcomes;
+            }
+        }
+    }
+    outcomes
+}
+
+async fn deliver_auth_prompt_by_direct_message(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    user: &str,
+    view: &AuthPromptView,
+) -> Vec<PartDeliveryOutcome> {
+    let dm_channel = match open_direct_message(egress, credential, user).await {
+        Ok(dm_channel) => dm_channel,
+        Err(error) => {
+            return vec![PartDeliveryOutcome::Permanent {
+                reason: error.to_string(),
+            }];
+        }
+    };
+    let mut outcomes = Vec::new();
+    let rendered = render_slack_mrkdwn(&render_channel_auth_prompt(view, true));
+    for chunk in slack_text_chunks(&rendered) {
+        let outcome = post_slack_chunk(egress, credential, &dm_channel, None, &chunk).await;
+        let sent = matches!(outcome, PartDeliveryOutcome::Sent { .. });
+        outcomes.push(outcome);
+        if !sent {
+            break;
+        }
+    }
+    outcomes
+}
+
+async fn post_slack_ephemeral(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    channel: &str,
+    user: &str,
+    thread_ts: Option<&str>,
+    text: &str,
+) -> EphemeralPost {
+    let mut body = serde_json::json!({ "channel": channel, "user": user, "text": text });
+    if let Some(thread_ts) = thread_ts {
+        body["thread_ts"] = serde_json::Value::String(thread_ts.to_string());
+    }
+    let body = match serde_json::to_vec(&body) {
+        Ok(body) => body,
+        Err(error) => {
+            return EphemeralPost::Delivered(PartDeliveryOutcome::Permanent {
+                reason: format!("chat.postEphemeral body did not serialize: {error}"),
+            });
+        }
+    };
+    let response = egress
+        .send(RestrictedEgressRequest {
+            method: NetworkMethod::Post,
+            url: format!("https://{SLACK_API_HOST}/api/chat.postEphemeral"),
+            headers: vec![(
+                "content-type".to_string(),
+                "application/json; charset=utf-8".to_string(),
+            )],
+            body: Some(body),
+            credential: Some(credential.clone()),
+            body_credentials: Vec::new(),
+        })
+        .await;
+    let response = match response {
+        Ok(response) => response,
+        Err(error) => return EphemeralPost::Delivered(part_outcome_for_egress_error(&error)),
+    };
+    if !(200..300).contains(&response.status) {
+        return EphemeralPost::Delivered(part_outcome_for_kind(
+            SlackDeliveryFailureKind::from_http_status(response.status),
+            format!("slack web api returned status {}", response.status),
+        ));
+    }
+    let parsed: SlackChatPostMessageResponse = match serde_json::from_slice(&response.body) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return EphemeralPost::Delivered(PartDeliveryOutcome::Retryable {
+                reason: format!("chat.postEphemeral response was not valid JSON: {error}"),
+            });
+        }
+    };
+    if parsed.ok {
+        return EphemeralPost::Delivered(PartDeliveryOutcome::Sent {
+            vendor_message_ref: None,
+        });
+    }
+    let error = parsed.error.unwrap_or_else(|| "unknown_error".to_string());
+    if EPHEMERAL_UNREACHABLE_ERRORS.contains(&error.as_str()) {
+        return EphemeralPost::RequesterUnreachable;
+    }
+    EphemeralPost::Delivered(part_outcome_for_kind(
+        slack_error_kind(&error),
+        format!("slack rejected chat.postEphemeral ({error})"),
+    ))
+}
+
 /// Retract an earlier post (`chat.delete`). The `vendor_message_ref` is the
 /// `ts` a previous `Sent` outcome returned; the channel comes from the
 /// envelope's target conversation.
@@ -411,6 +718,128 @@ mod tests {
         })
     }
 
+    fn interaction(payload: serde_json::Value) -> Result<InboundOutcome, ChannelError> {
+        let encoded: String = payload
+            .to_string()
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {
+                    (byte as char).to_string()
+                }
+                b' ' => "+".to_string(),
+                other => format!("%{other:02X}"),
+            })
+            .collect();
+        SlackChannelAdapter.inbound(VerifiedInbound {
+            extension_id: "slack",
+            installation_id: "install_alpha",
+            body: format!("payload={encoded}").as_bytes(),
+            headers: &[(
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+        })
+    }
+
+    /// A click delivered to app `A-IRONCLAW` on a message posted by `app_id`.
+    fn button_click(channel: &str, app_id: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "type": "block_actions",
+            "api_app_id": "A-IRONCLAW",
+            "team": {"id": "T-A"},
+            "user": {"id": "U-CLICKER"},
+            "channel": {"id": channel},
+            "message": {"ts": "1710000000.000200", "bot_id": "B-BOT", "app_id": app_id},
+            "actions": [{
+                "type": "button",
+                "action_id": "choice_0",
+                "value": "approve gate:abc",
+                "action_ts": "1710000005.123456"
+            }]
+        })
+    }
+
+    #[test]
+    fn button_clicks_normalize_to_the_button_reply() {
+        let InboundOutcome::Messages(messages) =
+            interaction(button_click("C123", Some("A-IRONCLAW"))).expect("click parses")
+        else {
+            panic!("expected Messages");
+        };
+        let message = &messages[0];
+        assert_eq!(message.text, "approve gate:abc");
+        assert_eq!(message.trigger, ProductTriggerReason::ReplyToBot);
+        assert_eq!(
+            message.actor.id(),
+            "U-CLICKER",
+            "the clicking user is the actor the host admits"
+        );
+        assert_eq!(message.conversation.conversation_id(), "C123");
+        assert_eq!(message.conversation.topic_id(), Some("1710000000.000200"));
+        assert_eq!(
+            message
+                .reply_context
+                .as_deref()
+                .and_then(SlackReplyContext::decode)
+                .map(|context| context.user),
+            Some("U-CLICKER".to_string())
+        );
+
+        let InboundOutcome::Messages(messages) =
+            interaction(button_click("D123", Some("A-IRONCLAW"))).expect("dm click parses")
+        else {
+            panic!("expected Messages");
+        };
+        assert_eq!(messages[0].trigger, ProductTriggerReason::DirectChat);
+        assert!(messages[0].reply_context.is_none());
+    }
+
+    #[test]
+    fn other_interactions_are_authenticated_noops() {
+        // A click on a message this app did not post, including another
+        // bot's buttons.
+        assert!(matches!(
+            interaction(button_click("C123", None)),
+            Ok(InboundOutcome::Ignore)
+        ));
+        assert!(matches!(
+            interaction(button_click("C123", Some("A-OTHER"))),
+            Ok(InboundOutcome::Ignore)
+        ));
+        let mut user_message = button_click("C123", Some("A-IRONCLAW"));
+        user_message["message"]["bot_id"] = serde_json::Value::Null;
+        assert!(matches!(
+            interaction(user_message),
+            Ok(InboundOutcome::Ignore)
+        ));
+        // A button on our message that is not one of the posted choices.
+        let mut foreign_action = button_click("C123", Some("A-IRONCLAW"));
+        foreign_action["actions"][0]["action_id"] = serde_json::json!("approve_btn");
+        assert!(matches!(
+            interaction(foreign_action),
+            Ok(InboundOutcome::Ignore)
+        ));
+        let mut modal = button_click("C123", Some("A-IRONCLAW"));
+        modal["type"] = serde_json::json!("view_submission");
+        assert!(matches!(interaction(modal), Ok(InboundOutcome::Ignore)));
+        let mut select = button_click("C123", Some("A-IRONCLAW"));
+        select["actions"][0]["type"] = serde_json::json!("static_select");
+        assert!(matches!(interaction(select), Ok(InboundOutcome::Ignore)));
+        // A form body without the payload field is malformed.
+        assert!(matches!(
+            SlackChannelAdapter.inbound(VerifiedInbound {
+                extension_id: "slack",
+                installation_id: "install_alpha",
+                body: b"token=abc",
+                headers: &[(
+                    "content-type".to_string(),
+                    "application/x-www-form-urlencoded; charset=utf-8".to_string(),
+                )],
+            }),
+            Err(ChannelError::Parse { .. })
+        ));
+    }
+
     #[test]
     fn url_verification_challenge_becomes_an_immediate_response() {
         let outcome = inbound(br#"{"type":"url_verification","challenge":"challenge-token"}"#)
@@ -475,6 +904,15 @@ mod tests {
         };
         assert_eq!(messages[0].text, "summarize this");
         assert_eq!(messages[0].trigger, ProductTriggerReason::BotMention);
+        assert_eq!(
+            messages[0]
+                .reply_context
+                .as_deref()
+                .and_then(SlackReplyContext::decode)
+                .map(|context| context.user),
+            Some("U123".to_string()),
+            "shared-channel messages name the requester for private replies"
+        );
         assert_eq!(
             messages[0].conversation.topic_id(),
             Some("1710000000.000200"),
@@ -671,6 +1109,63 @@ mod tests {
         assert_eq!(body["text"], "*bold* reply", "markdown renders to mrkdwn");
     }
 
+    #[tokio::test]
+    async fn deliver_offers_choices_as_block_kit_buttons() {
+        let egress = ScriptedEgress::new(vec![
+            ScriptedEgress::ok(r#"{"ok":true,"ts":"1710000001.000001"}"#),
+            ScriptedEgress::ok(r#"{"ok":true,"ts":"1710000001.000002"}"#),
+        ]);
+        let choices = vec![
+            OutboundChoice {
+                label: "Approve".to_string(),
+                reply: "approve".to_string(),
+            },
+            OutboundChoice {
+                label: "Deny".to_string(),
+                reply: "deny".to_string(),
+            },
+        ];
+        let too_long = vec![OutboundChoice {
+            label: "x".repeat(SLACK_BUTTON_LABEL_LIMIT_CHARS + 1),
+            reply: "approve".to_string(),
+        }];
+        SlackChannelAdapter
+            .deliver(
+                envelope(
+                    vec![
+                        OutboundPart::Choices {
+                            text: "**Run** the deploy?".to_string(),
+                            choices,
+                        },
+                        OutboundPart::Choices {
+                            text: "Run it?".to_string(),
+                            choices: too_long,
+                        },
+                    ],
+                    None,
+                ),
+                &egress,
+            )
+            .await
+            .expect("deliver drives");
+
+        let requests = egress.requests();
+        assert_eq!(requests.len(), 2);
+        let body = body_json(&requests[0]);
+        assert_eq!(body["text"], "*Run* the deploy?");
+        assert_eq!(body["blocks"][0]["text"]["text"], "*Run* the deploy?");
+        let buttons = body["blocks"][1]["elements"].as_array().expect("buttons");
+        assert_eq!(buttons.len(), 2);
+        assert_eq!(buttons[0]["text"]["text"], "Approve");
+        assert_eq!(buttons[0]["value"], "approve");
+        assert_eq!(buttons[1]["value"], "deny");
+
+        // A label over Block Kit's limit falls back to typed replies.
+        let body = body_json(&requests[1]);
+        assert!(body.get("blocks").is_none());
+        assert!(body["text"].as_str().unwrap().contains("approve"));
+    }
+
     #[tokio::test]
     async fn list_targets_im_query_opens_the_dm_conversation() {
         let egress = ScriptedEgress::new(vec![ScriptedEgress::ok(
@@ -915,6 +1410,112 @@ mod tests {
         ));
     }
 
+    fn auth_prompt_part() -> OutboundPart {
+        OutboundPart::AuthPrompt {
+            view: Box::new(AuthPromptView {
+                turn_run_id: ironclaw_host_api::TurnRunId::new(),
+                auth_request_ref: "auth-1".to_string(),
+                invocation_id: None,
+                headline: "Connect GitHub".to_string(),
+                body: "Finish setup to continue.".to_string(),
+                challenge_kind: None,
+                provider: None,
+                account_label: None,
+                authorization_url: Some("https://example.com/setup".to_string()),
+                expires_at: None,
+                connection: None,
+                pairing: None,
+            }),
+            direct_message: false,
+        }
+    }
+
+    fn requester_context(user: &str) -> Option<Vec<u8>> {
+        Some(
+            serde_json::to_vec(&SlackReplyContext {
+                user: user.to_string(),
+            })
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn deliver_shared_channel_auth_prompt_is_ephemeral_to_the_requester() {
+        let egress = ScriptedEgress::new(vec![ScriptedEgress::ok(
+            r#"{"ok":true,"message_ts":"1710000001.000001"}"#,
+        )]);
+        let mut envelope = envelope(vec![auth_prompt_part()], None);
+        envelope.reply_context = requester_context("U123");
+        let report = SlackChannelAdapter
+            .deliver(envelope, &egress)
+            .await
+            .expect("deliver drives");
+
+        assert!(matches!(
+            &report.parts[..],
+            [PartDeliveryOutcome::Sent {
+                vendor_message_ref: None
+            }]
+        ));
+        let requests = egress.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://slack.com/api/chat.postEphemeral");
+        let body = body_json(&requests[0]);
+        assert_eq!(body["channel"], "D123");
+        assert_eq!(body["user"], "U123");
+        assert_eq!(body["thread_ts"], "1710000000.000100");
+        assert!(body["text"].as_str().unwrap().contains("Setup link"));
+    }
+
+    #[tokio::test]
+    async fn deliver_auth_prompt_falls_back_to_a_dm_when_requester_left_the_channel() {
+        let egress = ScriptedEgress::new(vec![
+            ScriptedEgress::ok(r#"{"ok":false,"error":"user_not_in_channel"}"#),
+            ScriptedEgress::ok(r#"{"ok":true,"channel":{"id":"D777"}}"#),
+            ScriptedEgress::ok(r#"{"ok":true,"ts":"1710000002.000001"}"#),
+        ]);
+        let mut envelope = envelope(vec![auth_prompt_part()], None);
+        envelope.reply_context = requester_context("U123");
+        let report = SlackChannelAdapter
+            .deliver(envelope, &egress)
+            .await
+            .expect("deliver drives");
+
+        assert!(matches!(
+            &report.parts[..],
+            [PartDeliveryOutcome::Sent { vendor_message_ref: Some(ts) }] if ts == "1710000002.000001"
+        ));
+        let requests = egress.requests();
+        let urls: Vec<&str> = requests.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://slack.com/api/chat.postEphemeral",
+                "https://slack.com/api/conversations.open",
+                "https://slack.com/api/chat.postMessage",
+            ]
+        );
+        let body = body_json(&requests[2]);
+        assert_eq!(body["channel"], "D777");
+        assert!(body.get("thread_ts").is_none());
+        assert!(
+            body["text"].as_str().unwrap().contains("here to cancel"),
+            "the DM fallback uses DM wording"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliver_auth_prompt_without_requester_posts_to_the_channel() {
+        let egress = ScriptedEgress::new(vec![ScriptedEgress::ok(r#"{"ok":true,"ts":"1"}"#)]);
+        SlackChannelAdapter
+            .deliver(envelope(vec![auth_prompt_part()], None), &egress)
+            .await
+            .expect("deliver drives");
+        let requests = egress.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url, "https://slack.com/api/chat.postMessage");
+    }
+
     #[tokio::test]
     async fn deliver_rejects_empty_envelopes() {
         let egress = ScriptedEgress::new(Vec::new());
@@ -924,4 +1525,124 @@ mod tests {
             .expect_err("empty envelope is a render error");
         assert!(matches!(error, ChannelError::Render { .. }));
     }
+
+    // ── inbound_context() ───────────────────────────────────────────────────
+
+    fn thread_message(ts: &str) -> NormalizedInboundMessage {
+        let InboundOutcome::Messages(mut messages) = inbound(
+            format!(
+                r#"{{
+                    "type": "event_callback",
+                    "event_id": "Ev200",
+                    "team_id": "T-A",
+                    "event": {{
+                        "type": "app_mention",
+                        "user": "U123",
+                        "channel": "C123",
+                        "text": "<@UBOT> what do you think?",
+                        "thread_ts": "1710000000.000100",
+                        "ts": "{ts}"
+                    }}
+                }}"#
+            )
+            .as_bytes(),
+        )
+        .expect("mention parses") else {
+            panic!("expected Messages");
+        };
+        messages.remove(0)
+    }
+
+    fn form_fields(request: &RestrictedEgressRequest) -> Vec<(String, String)> {
+        String::from_utf8(request.body.clone().unwrap_or_default())
+            .expect("utf-8 body")
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn mid_thread_message_carries_the_thread_without_our_replies() {
+        let egress = ScriptedEgress::new(vec![
+            ScriptedEgress::ok(r#"{"ok":true,"user_id":"UBOT","bot_id":"B-SELF"}"#),
+            ScriptedEgress::ok(
+                r#"{"ok":true,"messages":[
+                    {"ts":"1710000000.000100","user":"U1","text":"deploy failed on staging"},
+                    {"ts":"1710000000.000110","user":"UBOT","bot_id":"B-SELF","text":"our reply"},
+                    {"ts":"1710000000.000120","bot_id":"B-CI","text":"build #42 red"}
+                ],"response_metadata":{"next_cursor":"page-2"}}"#,
+            ),
+            ScriptedEgress::ok(
+                r#"{"ok":true,"messages":[
+                    {"ts":"1710000000.000130","user":"U2","text":"same on prod"}
+                ],"response_metadata":{"next_cursor":""}}"#,
+            ),
+        ]);
+
+        let context = SlackChannelAdapter
+            .inbound_context(&thread_message("1710000000.000200"), &egress)
+            .await
+            .expect("thread context");
+
+        assert_eq!(
+            context.lines().skip(1).collect::<Vec<_>>(),
+            vec![
+                "<@U1>: deploy failed on staging",
+                "bot B-CI: build #42 red",
+                "<@U2>: same on prod",
+            ]
+        );
+        assert!(context.lines().next().unwrap().contains("B-SELF"));
+        let requests = egress.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].url, "https://slack.com/api/auth.test");
+        assert_eq!(
+            requests[1].url,
+            "https://slack.com/api/conversations.replies"
+        );
+        assert_eq!(
+            requests[1].credential.as_ref().map(|h| h.as_str()),
+            Some("slack_bot_token")
+        );
+        let fields = form_fields(&requests[1]);
+        for expected in [
+            ("channel", "C123"),
+            ("ts", "1710000000.000100"),
+            ("latest", "1710000000.000200"),
+            ("inclusive", "false"),
+        ] {
+            assert!(
+                fields.contains(&(expected.0.to_string(), expected.1.to_string())),
+                "missing {expected:?} in {fields:?}"
+            );
+        }
+        assert!(form_fields(&requests[2]).contains(&("cursor".to_string(), "page-2".to_string())));
+    }
+
+    #[tokio::test]
+    async fn top_level_messages_and_vendor_failures_carry_no_context() {
+        let egress = ScriptedEgress::new(Vec::new());
+        assert!(
+            SlackChannelAdapter
+                .inbound_context(&thread_message("1710000000.000100"), &egress)
+                .await
+                .is_none()
+        );
+        assert!(
+            egress.requests().is_empty(),
+            "no thread above, no vendor call"
+        );
+
+        let egress = ScriptedEgress::new(vec![
+            ScriptedEgress::ok(r#"{"ok":true,"bot_id":"B-SELF"}"#),
+            ScriptedEgress::ok(r#"{"ok":false,"error":"missing_scope"}"#),
+        ]);
+        assert!(
+            SlackChannelAdapter
+                .inbound_context(&thread_message("1710000000.000200"), &egress)
+                .await
+                .is_none()
+        );
+    }
 }
@@ -9,9 +9,11 @@
 //!   incl. `chat.postMessage` egress + mrkdwn rendering).
 //! * [`delivery`] — Slack Web API response classification and status mapping.
 //! * [`mrkdwn`] — Slack mrkdwn rendering and message chunking.
-//! * [`payload`] — Slack Events API payload normalization.
+//! * [`payload`] — Slack Events API and interactivity payload normalization.
 //! * [`preference_targets`] — reply-target binding-ref grammar + the
 //!   preference-target codec for the generic triggered-delivery driver.
+//! * [`thread_context`] — the thread above a mid-thread inbound message, via
+//!   `conversations.replies`.
 
 #![forbid(unsafe_code)]
 
@@ -20,6 +22,7 @@ mod delivery;
 mod mrkdwn;
 mod payload;
 mod preference_targets;
+mod thread_context;
 
 pub const SLACK_V2_ADAPTER_ID: &str = "slack_v2";
 
@@ -27,8 +30,8 @@ pub use channel::SlackChannelAdapter;
 pub use payload::{
     SLACK_API_HOST, SLACK_USER_ACTOR_KIND, SlackInboundEvent, SlackPayloadParseError,
     SlackUrlVerificationChallenge, classify_channel_interaction_resolution,
-    classify_interaction_resolution, normalize_slack_event, parse_slack_event,
-    parse_slack_url_verification_challenge,
+    classify_interaction_resolution, normalize_slack_event, normalize_slack_interaction,
+    parse_slack_event, parse_slack_url_verification_challenge,
 };
 pub use preference_targets::{
     SlackPreferenceTargetCodec, SlackReplyTargetError,
@@ -1,4 +1,4 @@
-//! Slack Events API payload normalization.
+//! Slack Events API and interactivity payload normalization.
 //!
 //! Inputs are raw Slack webhook event bytes. Event callbacks become
 //! [`ParsedProductInbound`] values; URL-verification payloads are exposed for
@@ -11,7 +11,7 @@ use ironclaw_host_api::product_adapter::{
     ProductAdapterError, ProductAttachmentDescriptor, ProductAttachmentKind, ProductInboundPayload,
     ProductTriggerReason, ProtocolAuthEvidence, UserMessagePayload,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub const SLACK_API_HOST: &str = "slack.com";
@@ -20,6 +20,25 @@ const SLACK_SYSTEM_ACTOR_KIND: &str = "slack_system";
 const SLACK_IGNORED_ACTOR_ID: &str = "slack_ignored_actor";
 const SLACK_IGNORED_CONVERSATION_ID: &str = "slack_ignored_conversation";
 const SLACK_FILE_SHARE_SUBTYPE: &str = "file_share";
+/// Prefix of the `action_id` on every choice button the delivery path posts.
+pub(crate) const SLACK_CHOICE_ACTION_ID_PREFIX: &str = "choice_";
+
+/// Opaque `reply_context` attached to shared-channel messages: the Slack
+/// user who asked, so the delivery path can reply privately
+/// (`chat.postEphemeral`) when the reply must not be broadcast.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SlackReplyContext {
+    pub(crate) user: String,
+}
+
+impl SlackReplyContext {
+    /// Decode a stored context; anything unreadable is treated as absent.
+    pub(crate) fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(bytes)
+            .ok()
+            .filter(|context| !context.user.is_empty())
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SlackUrlVerificationChallenge {
@@ -186,6 +205,19 @@ pub fn normalize_slack_event(
     match parsed.payload {
         ProductInboundPayload::UserMessage(message) => {
             let trigger = message.trigger;
+            // DMs are already private; only shared-channel messages carry
+            // the requester for private replies.
+            let reply_context = (!is_dm_channel(
+                event.channel.as_deref().unwrap_or_default(),
+                event.channel_type.as_deref(),
+            ))
+            .then(|| {
+                serde_json::to_vec(&SlackReplyContext {
+                    user: parsed.external_actor_ref.id().to_string(),
+                })
+                .ok()
+            })
+            .flatten();
             let attachments = message
                 .attachments
                 .into_iter()
@@ -203,10 +235,9 @@ pub fn normalize_slack_event(
                     text: message.text,
                     trigger,
                     attachments,
-                    // Reply routing rides the conversation ref's thread anchors
-                    // (pre-coordinator delivery path); adopted when the P5
-                    // delivery coordinator consumes stored contexts.
-                    reply_context: None,
+                    // Reply routing rides the conversation ref's thread
+                    // anchors; the stored context only names the requester.
+                    reply_context,
                 },
             )))
         }
@@ -216,6 +247,148 @@ pub fn normalize_slack_event(
     }
 }
 
+/// Parse one host-verified Slack interactivity request — the form-encoded
+/// `payload=<json>` body Slack POSTs when a user clicks a Block Kit button —
+/// into its normalized channel form.
+///
+/// A `block_actions` click on one of this app's choice buttons becomes a user
+/// message carrying the button's `value`, so a choice reaches the agent
+/// exactly as if the user had typed it. The clicked message must have been
+/// posted by the app the interaction was delivered to (`message.app_id` ==
+/// `api_app_id`), and the button must carry the `choice_` action id the
+/// delivery path writes; buttons on other bots' messages are not ours to
+/// answer. The actor is the user who CLICKED, so the host's actor admission
+/// applies to them like any other sender. Every other interaction is an
+/// authenticated no-op.
+pub fn normalize_slack_interaction(
+    raw_payload: &[u8],
+    installation_id: &AdapterInstallationId,
+) -> Result<SlackInboundEvent, SlackPayloadParseError> {
+    if raw_payload.len() > MAX_SLACK_PAYLOAD_BYTES {
+        return Err(SlackPayloadParseError::InvalidJson {
+            reason: "payload exceeds size limit".into(),
+        });
+    }
+    let payload =
+        form_field(raw_payload, "payload")?.ok_or_else(|| SlackPayloadParseError::InvalidJson {
+            reason: "interactivity request has no payload field".to_string(),
+        })?;
+    let interaction: SlackInteraction =
+        serde_json::from_str(&payload).map_err(|err| SlackPayloadParseError::InvalidJson {
+            reason: err.to_string(),
+        })?;
+    if interaction.interaction_type != "block_actions" {
+        return Ok(SlackInboundEvent::Ignore);
+    }
+    let (Some(user), Some(channel), Some(message)) = (
+        interaction.user.as_ref(),
+        interaction.channel.as_ref(),
+        interaction.message.as_ref(),
+    ) else {
+        return Ok(SlackInboundEvent::Ignore);
+    };
+    let posted_by_this_app = message.bot_id.is_some()
+        && message.app_id.is_some()
+        && message.app_id == interaction.api_app_id;
+    if !posted_by_this_app {
+        return Ok(SlackInboundEvent::Ignore);
+    }
+    let Some(action) = interaction.actions.iter().find(|action| {
+        action.action_type == "button"
+            && action.action_id.starts_with(SLACK_CHOICE_ACTION_ID_PREFIX)
+    }) else {
+        return Ok(SlackInboundEvent::Ignore);
+    };
+    let Some(value) = action.value.as_deref().filter(|value| !value.is_empty()) else {
+        return Ok(SlackInboundEvent::Ignore);
+    };
+
+    let event_id = ExternalEventId::new(format!(
+        "slack-{}-action-{}-{}",
+        installation_id.as_str(),
+        channel.id,
+        action.action_ts
+    ))
+    .map_err(|err| SlackPayloadParseError::InvalidExternalRef {
+        kind: "external_event_id",
+        reason: err.to_string(),
+    })?;
+    let direct = is_dm_channel(&channel.id, None);
+    // In a shared channel the reply threads under the clicked message, the
+    // same anchor an app mention gets.
+    let thread_ts = if direct {
+        message.thread_ts.as_deref()
+    } else {
+        message.thread_ts.as_deref().or(Some(message.ts.as_str()))
+    };
+    let reply_context = (!direct)
+        .then(|| {
+            serde_json::to_vec(&SlackReplyContext {
+                user: user.id.clone(),
+            })
+            .ok()
+        })
+        .flatten();
+    Ok(SlackInboundEvent::Message(Box::new(
+        NormalizedInboundMessage {
+            actor: build_actor_ref(Some(&user.id))?,
+            conversation: build_conversation_ref(
+                interaction.team.as_ref().map(|team| team.id.as_str()),
+                Some(&channel.id),
+                thread_ts,
+                Some(&message.ts),
+            )?,
+            event_id,
+            text: value.to_string(),
+            trigger: if direct {
+                ProductTriggerReason::DirectChat
+            } else {
+                ProductTriggerReason::ReplyToBot
+            },
+            attachments: Vec::new(),
+            reply_context,
+        },
+    )))
+}
+
+/// Decode the first `name` field of an `application/x-www-form-urlencoded`
+/// body.
+fn form_field(body: &[u8], name: &str) -> Result<Option<String>, SlackPayloadParseError> {
+    let body = std::str::from_utf8(body).map_err(|err| SlackPayloadParseError::InvalidJson {
+        reason: err.to_string(),
+    })?;
+    for pair in body.split('&') {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        if form_decode(key)? == name {
+            return form_decode(value).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+fn form_decode(encoded: &str) -> Result<String, SlackPayloadParseError> {
+    let malformed = || SlackPayloadParseError::InvalidJson {
+        reason: "malformed form encoding".to_string(),
+    };
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut input = encoded.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [
+                    input.next().ok_or_else(malformed)?,
+                    input.next().ok_or_else(malformed)?,
+                ];
+                let hex = std::str::from_utf8(&hex).map_err(|_| malformed())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| malformed())?);
+            }
+            other => bytes.push(other),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| malformed())
+}
+
 /// Classify a normalized message's text as a gate-resolution interaction
 /// (`approve [gate:<ref>]` / `deny [gate:<ref>]` / `auth deny <ref>`),
 /// exactly as the event parse path used to. Malformed interaction phrasings
@@ -709,6 +882,45 @@ struct SlackEvent {
     files: Option<Vec<SlackFile>>,
 }
 
+/// A Slack interactivity payload (the JSON inside the form's `payload`).
+#[derive(Debug, Clone, Deserialize)]
+struct SlackInteraction {
+    #[serde(rename = "type")]
+    interaction_type: String,
+    /// The app the interaction was delivered to.
+    api_app_id: Option<String>,
+    team: Option<SlackIdRef>,
+    user: Option<SlackIdRef>,
+    channel: Option<SlackIdRef>,
+    message: Option<SlackInteractionMessage>,
+    #[serde(default)]
+    actions: Vec<SlackBlockAction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SlackIdRef {
+    id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SlackInteractionMessage {
+    ts: String,
+    thread_ts: Option<String>,
+    bot_id: Option<String>,
+    /// The app that posted the message, for bot messages.
+    app_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SlackBlockAction {
+    #[serde(rename = "type")]
+    action_type: String,
+    #[serde(default)]
+    action_id: String,
+    value: Option<String>,
+    action_ts: String,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct SlackFile {
     id: String,
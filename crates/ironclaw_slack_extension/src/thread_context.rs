@@ -0,0 +1,273 @@
+//! Thread context for mid-thread inbound messages.
+//!
+//! A mention (or reply) inside an existing thread arrives as that one
+//! message. [`fetch_thread_context`] reads the thread above it through
+//! `conversations.replies` with the bot token, drops this app's own earlier
+//! replies (its `bot_id`, from `auth.test`), and renders the parent plus the
+//! newest messages as a bounded block the host prepends to the inbound text.
+//! Every failure yields no context; the message is admitted as parsed.
+
+use ironclaw_host_api::product_adapter::{MAX_INBOUND_CONTEXT_BYTES, NormalizedInboundMessage};
+use ironclaw_host_api::{NetworkMethod, RestrictedEgress, RestrictedEgressRequest, SecretHandle};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::payload::SLACK_API_HOST;
+
+/// Messages kept from the thread above the inbound message: the parent plus
+/// the newest replies.
+const THREAD_CONTEXT_MESSAGES: usize = 20;
+
+/// Replies fetched per `conversations.replies` page. The API pages
+/// oldest-first, so the tail is reached by walking the cursor.
+const THREAD_PAGE_SIZE: u32 = 200;
+
+/// Pages walked per thread; longer threads are read from their first
+/// `THREAD_PAGE_SIZE * MAX_THREAD_PAGES` messages.
+const MAX_THREAD_PAGES: usize = 3;
+
+/// Longest message text carried into the context, in characters.
+const MAX_CONTEXT_MESSAGE_CHARS: usize = 600;
+
+/// The thread above `message`, or `None` when it is not a mid-thread message
+/// or the thread cannot be read.
+pub(crate) async fn fetch_thread_context(
+    message: &NormalizedInboundMessage,
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+) -> Option<String> {
+    let conversation = &message.conversation;
+    let thread_ts = conversation.topic_id()?;
+    let message_ts = conversation.reply_target_message_id()?;
+    if thread_ts == message_ts {
+        // A top-level message anchors its own thread; nothing is above it.
+        return None;
+    }
+    let own_bot_id = slack_call::<SlackAuthTestResponse>(egress, credential, "auth.test", &[])
+        .await
+        .filter(|response| response.ok)?
+        .bot_id
+        .filter(|bot_id| !bot_id.is_empty())?;
+
+    let channel = conversation.conversation_id();
+    let limit = THREAD_PAGE_SIZE.to_string();
+    let mut messages = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_THREAD_PAGES {
+        let mut params = vec![
+            ("channel", channel),
+            ("ts", thread_ts),
+            ("latest", message_ts),
+            ("inclusive", "false"),
+            ("limit", limit.as_str()),
+        ];
+        if let Some(cursor) = cursor.as_deref() {
+            params.push(("cursor", cursor));
+        }
+        let page = slack_call::<SlackRepliesResponse>(
+            egress,
+            credential,
+            "conversations.replies",
+            &params,
+        )
+        .await
+        .filter(|response| response.ok)?;
+        messages.extend(page.messages);
+        cursor = page
+            .response_metadata
+            .and_then(|metadata| metadata.next_cursor)
+            .filter(|next| !next.is_empty());
+        if cursor.is_none() {
+            break;
+        }
+    }
+    strip_own_messages(&mut messages, &own_bot_id);
+    render_thread_context(&messages, &own_bot_id)
+}
+
+/// Drop the messages this app's bot posted; other bots stay.
+fn strip_own_messages(messages: &mut Vec<SlackThreadMessage>, own_bot_id: &str) {
+    messages.retain(|message| message.bot_id.as_deref() != Some(own_bot_id));
+}
+
+/// Render the parent plus the newest replies, dropping the oldest replies
+/// until the block fits [`MAX_INBOUND_CONTEXT_BYTES`].
+fn render_thread_context(messages: &[SlackThreadMessage], own_bot_id: &str) -> Option<String> {
+    let (parent, replies) = messages.split_first()?;
+    let mut replies = &replies[replies
+        .len()
+        .saturating_sub(THREAD_CONTEXT_MESSAGES.saturating_sub(1))..];
+    let mut omitted = replies.len() + 1 < messages.len();
+    loop {
+        let mut lines = vec![format!(
+            "Earlier messages in this Slack thread, oldest first (replies from this bot, \
+             {own_bot_id}, left out):"
+        )];
+        lines.push(parent.context_line());
+        if omitted {
+            lines.push("…".to_string());
+        }
+        lines.extend(replies.iter().map(SlackThreadMessage::context_line));
+        let rendered = lines.join("\n");
+        if rendered.len() <= MAX_INBOUND_CONTEXT_BYTES {
+            return Some(rendered);
+        }
+        let (_, rest) = replies.split_first()?;
+        replies = rest;
+        omitted = true;
+    }
+}
+
+/// One Slack Web API call over restricted egress, form-encoded (the read
+/// methods do not accept JSON bodies). `None` on any egress, HTTP, or
+/// decoding failure; callers check `ok`.
+async fn slack_call<T: DeserializeOwned>(
+    egress: &dyn RestrictedEgress,
+    credential: &SecretHandle,
+    method: &str,
+    params: &[(&str, &str)],
+) -> Option<T> {
+    let body = params
+        .iter()
+        .map(|(name, value)| format!("{name}={}", form_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let response = egress
+        .send(RestrictedEgressRequest {
+            method: NetworkMethod::Post,
+            url: format!("https://{SLACK_API_HOST}/api/{method}"),
+            headers: vec![(
+                "content-type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+            body: Some(body.into_bytes()),
+            credential: Some(credential.clone()),
+            body_credentials: Vec::new(),
+        })
+        .await
+        .ok()?;
+    if !(200..300).contains(&response.status) {
+        return None;
+    }
+    serde_json::from_slice(&response.body).ok()
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            other => format!("%{other:02X}"),
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackAuthTestResponse {
+    ok: bool,
+    bot_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackRepliesResponse {
+    ok: bool,
+    #[serde(default)]
+    messages: Vec<SlackThreadMessage>,
+    response_metadata: Option<SlackResponseMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackResponseMetadata {
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackThreadMessage {
+    user: Option<String>,
+    bot_id: Option<String>,
+    #[serde(default)]
+    text: String,
+}
+
+impl SlackThreadMessage {
+    fn context_line(&self) -> String {
+        let author = match (&self.user, &self.bot_id) {
+            (Some(user), _) => format!("<@{user}>"),
+            (None, Some(bot_id)) => format!("bot {bot_id}"),
+            (None, None) => "unknown".to_string(),
+        };
+        let mut text: String = self
+            .text
+            .chars()
+            .take(MAX_CONTEXT_MESSAGE_CHARS)
+            .collect::<String>()
+            .replace('\n', " ");
+        if self.text.chars().count() > MAX_CONTEXT_MESSAGE_CHARS {
+            text.push('…');
+        }
+        format!("{author}: {text}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(user: Option<&str>, bot_id: Option<&str>, text: &str) -> SlackThreadMessage {
+        SlackThreadMessage {
+            user: user.map(str::to_string),
+            bot_id: bot_id.map(str::to_string),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn only_this_apps_bot_messages_are_dropped() {
+        let mut messages = vec![
+            message(Some("U1"), None, "question"),
+            message(Some("UBOT"), Some("B-SELF"), "our earlier answer"),
+            message(None, Some("B-OTHER"), "another bot"),
+            message(Some("U2"), None, "follow-up"),
+        ];
+
+        strip_own_messages(&mut messages, "B-SELF");
+
+        let texts: Vec<_> = messages
+            .iter()
+            .map(|message| message.text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["question", "another bot", "follow-up"]);
+    }
+
+    #[test]
+    fn context_keeps_the_parent_and_the_newest_replies() {
+        let messages: Vec<_> = (0..30)
+            .map(|index| message(Some("U1"), None, &format!("m{index}")))
+            .collect();
+
+        let context = render_thread_context(&messages, "B-SELF").expect("context");
+        let lines: Vec<_> = context.lines().collect();
+
+        assert_eq!(lines.len(), 1 + 1 + 1 + (THREAD_CONTEXT_MESSAGES - 1));
+        assert_eq!(lines[1], "<@U1>: m0");
+        assert_eq!(lines[2], "…");
+        assert_eq!(lines[3], "<@U1>: m11");
+        assert_eq!(lines.last().copied(), Some("<@U1>: m29"));
+    }
+
+    #[test]
+    fn context_is_bounded() {
+        let long = "x".repeat(MAX_CONTEXT_MESSAGE_CHARS * 2);
+        let messages: Vec<_> = (0..THREAD_CONTEXT_MESSAGES)
+            .map(|_| message(Some("U1"), None, &long))
+            .collect();
+
+        let context = render_thread_context(&messages, "B-SELF").expect("context");
+
+        assert!(context.len() <= MAX_INBOUND_CONTEXT_BYTES);
+        assert!(context.lines().nth(2) == Some("…"));
+        assert!(render_thread_context(&[], "B-SELF").is_none());
+    }
+}
@@ -11,8 +11,8 @@
 use async_trait::async_trait;
 use ironclaw_host_api::product_adapter::{
     AdapterInstallationId, ChannelAdapter, ChannelContext, ChannelError, DeliveryReport,
-    InboundOutcome, OutboundEnvelope, OutboundPart, PartDeliveryOutcome, VerifiedInbound,
-    render_channel_auth_prompt,
+    ImmediateResponse, InboundOutcome, OutboundChoice, OutboundEnvelope, OutboundPart,
+    PartDeliveryOutcome, VerifiedInbound, render_channel_auth_prompt, render_channel_choices,
 };
 use ironclaw_host_api::{NetworkMethod, RestrictedEgress, RestrictedEgressRequest, SecretHandle};
 
@@ -35,6 +35,8 @@ pub const TELEGRAM_TOKEN_PLACEHOLDER: &str = "telegram_bot_token";
 
 /// Telegram sendMessage hard limit (characters).
 const TELEGRAM_TEXT_LIMIT_CHARS: usize = 4096;
+/// Telegram inline-keyboard `callback_data` limit (bytes).
+const TELEGRAM_CALLBACK_DATA_LIMIT_BYTES: usize = 64;
 
 /// The Telegram channel adapter. Group-forwarding triggers are non-secret
 /// installation config, supplied at construction (bind-time).
@@ -136,6 +138,19 @@ impl ChannelAdapter for TelegramChannelAdapter {
             })? {
             TelegramInboundEvent::Ignore => Ok(InboundOutcome::Ignore),
             TelegramInboundEvent::Message(message) => Ok(InboundOutcome::Messages(vec![*message])),
+            TelegramInboundEvent::CallbackQuery {
+                callback_query_id,
+                message,
+            } => {
+                let response = answer_callback_query_response(&callback_query_id);
+                Ok(match message {
+                    Some(message) => InboundOutcome::MessagesWithResponse {
+                        messages: vec![*message],
+                        response,
+                    },
+                    None => InboundOutcome::Respond(response),
+                })
+            }
         }
     }
 
@@ -199,6 +214,32 @@ impl ChannelAdapter for TelegramChannelAdapter {
                         }
                     }
                 }
+                OutboundPart::Choices { text, choices } => {
+                    // The keyboard rides the last chunk so the buttons sit
+                    // under the end of the message.
+                    let keyboard = telegram_inline_keyboard(choices);
+                    let text = match keyboard {
+                        Some(_) => text.clone(),
+                        None => render_channel_choices(text, choices),
+                    };
+                    let chunks = telegram_text_chunks(&text);
+                    let last = chunks.len() - 1;
+                    for (index, chunk) in chunks.into_iter().enumerate() {
+                        let mut body = serde_json::json!({ "chat_id": chat_id, "text": chunk });
+                        if let Some(thread_id) = message_thread_id {
+                            body["message_thread_id"] = thread_id.into();
+                        }
+                        if let Some(keyboard) = keyboard.as_ref().filter(|_| index == last) {
+                            body["reply_markup"] = keyboard.clone();
+                        }
+                        let outcome = send_telegram_message(egress, body).await;
+                        let sent = matches!(outcome, PartDeliveryOutcome::Sent { .. });
+                        parts.push(outcome);
+                        if !sent {
+                            break 'parts;
+                        }
+                    }
+                }
                 OutboundPart::Retract { vendor_message_ref } => {
                     let outcome = match vendor_message_ref.parse::<i64>() {
                         Ok(message_id) => {
@@ -229,6 +270,42 @@ impl ChannelAdapter for TelegramChannelAdapter {
     }
 }
 
+/// Acknowledge a button tap through Telegram's webhook reply: the 2xx body
+/// names the Bot API method, so the client's spinner stops without an
+/// egress call from the (pure) inbound path.
+fn answer_callback_query_response(callback_query_id: &str) -> ImmediateResponse {
+    ImmediateResponse {
+        status: 200,
+        content_type: Some("application/json".to_string()),
+        body: serde_json::json!({
+            "method": "answerCallbackQuery",
+            "callback_query_id": callback_query_id,
+        })
+        .to_string()
+        .into_bytes(),
+    }
+}
+
+/// One button per row, each sending its choice's reply back as
+/// `callback_data`. `None` when any reply can't be carried (empty or over
+/// Telegram's 64-byte limit); the caller then falls back to typed replies.
+fn telegram_inline_keyboard(choices: &[OutboundChoice]) -> Option<serde_json::Value> {
+    if choices.is_empty()
+        || choices.iter().any(|choice| {
+            choice.label.is_empty()
+                || choice.reply.is_empty()
+                || choice.reply.len() > TELEGRAM_CALLBACK_DATA_LIMIT_BYTES
+        })
+    {
+        return None;
+    }
+    let rows = choices
+        .iter()
+        .map(|choice| serde_json::json!([{ "text": choice.label, "callback_data": choice.reply }]))
+        .collect::<Vec<_>>();
+    Some(serde_json::json!({ "inline_keyboard": rows }))
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct TelegramSendMessageResponse {
     ok: bool,
@@ -373,39 +450,112 @@ fn telegram_outcome_for_egress_error(
     }
 }
 
-/// Split text at the vendor's 4096-char message limit, preferring newline
-/// boundaries within each window.
+/// Split text at the vendor's 4096-char message limit, preferring paragraph
+/// then line boundaries. A chunk that ends inside a ``` code fence is closed
+/// and the fence reopened at the top of the next chunk, so every message
+/// renders as balanced markdown on its own.
 fn telegram_text_chunks(text: &str) -> Vec<String> {
     if text.chars().count() <= TELEGRAM_TEXT_LIMIT_CHARS {
         return vec![text.to_string()];
     }
-    let mut chunks = Vec::new();
-    let mut current = String::new();
-    let mut current_chars = 0usize;
-    for segment in text.split_inclusive('\n') {
-        let segment_chars = segment.chars().count();
-        if current_chars + segment_chars > TELEGRAM_TEXT_LIMIT_CHARS && !current.is_empty() {
-            chunks.push(std::mem::take(&mut current));
-            current_chars = 0;
+    let mut chunker = TelegramChunker::default();
+    for paragraph in text.split_inclusive("\n\n") {
+        chunker.push_paragraph(paragraph);
+    }
+    chunker.finish()
+}
+
+/// Characters reserved at the end of every chunk for closing a fence that
+/// is still open (`\n` + ```).
+const TELEGRAM_FENCE_CLOSE_CHARS: usize = 4;
+/// Longest fence opener (e.g. ```` ```rust ````) repeated on a continuation
+/// chunk; longer info strings reopen as a bare fence.
+const TELEGRAM_FENCE_REOPEN_MAX_CHARS: usize = 32;
+
+#[derive(Default)]
+struct TelegramChunker {
+    chunks: Vec<String>,
+    current: String,
+    current_chars: usize,
+    /// Characters of `current` that are a reopened fence, not message body.
+    prefix_chars: usize,
+    /// The opener line of the code fence `current` is inside, if any.
+    open_fence: Option<String>,
+}
+
+impl TelegramChunker {
+    const BUDGET: usize = TELEGRAM_TEXT_LIMIT_CHARS - TELEGRAM_FENCE_CLOSE_CHARS;
+
+    fn has_body(&self) -> bool {
+        self.current_chars > self.prefix_chars
+    }
+
+    fn push_paragraph(&mut self, paragraph: &str) {
+        if self.has_body() && self.current_chars + paragraph.chars().count() > Self::BUDGET {
+            self.flush();
+        }
+        for line in paragraph.split_inclusive('\n') {
+            self.push_line(line);
+        }
+    }
+
+    fn push_line(&mut self, line: &str) {
+        let line_chars = line.chars().count();
+        if self.has_body() && self.current_chars + line_chars > Self::BUDGET {
+            self.flush();
         }
-        if segment_chars > TELEGRAM_TEXT_LIMIT_CHARS {
-            for ch in segment.chars() {
-                if current_chars == TELEGRAM_TEXT_LIMIT_CHARS {
-                    chunks.push(std::mem::take(&mut current));
-                    current_chars = 0;
+        if self.current_chars + line_chars > Self::BUDGET {
+            for ch in line.chars() {
+                if self.current_chars >= Self::BUDGET {
+                    self.flush();
                 }
-                current.push(ch);
-                current_chars += 1;
+                self.current.push(ch);
+                self.current_chars += 1;
             }
         } else {
-            current.push_str(segment);
-            current_chars += segment_chars;
+            self.current.push_str(line);
+            self.current_chars += line_chars;
+        }
+        if line.trim_start().starts_with("```") {
+            self.open_fence = match self.open_fence {
+                Some(_) => None,
+                None => Some(line.trim().to_string()),
+            };
+        }
+    }
+
+    fn flush(&mut self) {
+        if !self.has_body() {
+            return;
+        }
+        let mut chunk = std::mem::take(&mut self.current);
+        if self.open_fence.is_some() {
+            if !chunk.ends_with('\n') {
+                chunk.push('\n');
+            }
+            chunk.push_str("```");
+        }
+        self.chunks.push(chunk);
+        self.current_chars = 0;
+        self.prefix_chars = 0;
+        if let Some(opener) = &self.open_fence {
+            if opener.chars().count() <= TELEGRAM_FENCE_REOPEN_MAX_CHARS {
+                self.current.push_str(opener);
+            } else {
+                self.current.push_str("```");
+            }
+            self.current.push('\n');
+            self.current_chars = self.current.chars().count();
+            self.prefix_chars = self.current_chars;
         }
     }
-    if !current.is_empty() {
-        chunks.push(current);
+
+    fn finish(mut self) -> Vec<String> {
+        if self.has_body() {
+            self.chunks.push(self.current);
+        }
+        self.chunks
     }
-    chunks
 }
 
 /// A Bot API request against the declared vendor host, naming the bot-token
@@ -617,6 +767,116 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn callback_query_taps_normalize_to_the_button_reply() {
+        let outcome = inbound(
+            br#"{
+                "update_id": 45,
+                "callback_query": {
+                    "id": "cb-1",
+                    "from": {"id": 1001, "is_bot": false, "first_name": "Alice"},
+                    "message": {
+                        "message_id": 70,
+                        "date": 1710000000,
+                        "text": "Approval needed",
+                        "from": {"id": 0, "is_bot": true, "first_name": "Bot"},
+                        "chat": {"id": 555, "type": "private"}
+                    },
+                    "data": "approve"
+                }
+            }"#,
+        )
+        .expect("callback parses");
+        let InboundOutcome::MessagesWithResponse { messages, response } = outcome else {
+            panic!("expected MessagesWithResponse");
+        };
+        let ack: serde_json::Value =
+            serde_json::from_slice(&response.body).expect("acknowledgement is JSON");
+        assert_eq!(
+            ack,
+            serde_json::json!({"method": "answerCallbackQuery", "callback_query_id": "cb-1"}),
+            "the tap is acknowledged so the client's spinner stops"
+        );
+        assert_eq!(messages[0].text, "approve");
+        assert_eq!(messages[0].trigger, ProductTriggerReason::DirectChat);
+        assert_eq!(
+            messages[0].actor.id(),
+            "1001",
+            "the tapping user is the actor the host admits"
+        );
+        assert_eq!(messages[0].conversation.conversation_id(), "555");
+
+        // Taps on someone else's keyboard in a group, and data-less taps,
+        // forward nothing but are still acknowledged.
+        assert!(matches!(
+            inbound(
+                br#"{
+                    "update_id": 46,
+                    "callback_query": {
+                        "id": "cb-2",
+                        "from": {"id": 1002, "is_bot": false, "first_name": "Bob"},
+                        "message": {
+                            "message_id": 71,
+                            "date": 1710000000,
+                            "from": {"id": 999, "is_bot": true, "first_name": "OtherBot"},
+                            "chat": {"id": -100200, "type": "supergroup"}
+                        },
+                        "data": "approve"
+                    }
+                }"#,
+            ),
+            Ok(InboundOutcome::Respond(_))
+        ));
+        assert!(matches!(
+            inbound(
+                br#"{
+                    "update_id": 47,
+                    "callback_query": {
+                        "id": "cb-3",
+                        "from": {"id": 1001, "is_bot": false, "first_name": "Alice"},
+                        "message": {
+                            "message_id": 72,
+                            "date": 1710000000,
+                            "chat": {"id": 555, "type": "private"}
+                        }
+                    }
+                }"#,
+            ),
+            Ok(InboundOutcome::Respond(_))
+        ));
+    }
+
+    #[test]
+    fn text_chunks_prefer_paragraph_boundaries() {
+        let first = format!("{}\n\n", "a".repeat(3_000));
+        let second = "b".repeat(2_000);
+        let chunks = telegram_text_chunks(&format!("{first}{second}"));
+        assert_eq!(chunks, vec![first, second]);
+    }
+
+    #[test]
+    fn text_chunks_keep_code_fences_balanced() {
+        let code = "let x = 1;\n".repeat(600); // 6600 chars
+        let text = format!("Here you go:\n\n```rust\n{code}```\n\nDone.");
+        let chunks = telegram_text_chunks(&text);
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= TELEGRAM_TEXT_LIMIT_CHARS);
+            assert_eq!(
+                chunk.matches("```").count() % 2,
+                0,
+                "every chunk renders balanced fences: {chunk:?}"
+            );
+        }
+        // The intro paragraph stays whole; the code block spans the rest.
+        assert_eq!(chunks[0], "Here you go:\n\n");
+        assert!(chunks[1].ends_with("\n```"));
+        assert!(chunks[2].starts_with("```rust\n"));
+        assert!(chunks.last().is_some_and(|chunk| chunk.ends_with("Done.")));
+        let body: String = chunks.concat();
+        assert_eq!(body.matches("let x = 1;").count(), 600, "no code is lost");
+    }
+
     #[test]
     fn malformed_updates_are_typed_parse_errors() {
         assert!(matches!(
@@ -811,6 +1071,61 @@ mod deliver_tests {
         assert_eq!(body["message_thread_id"], 77, "numeric topic threads");
     }
 
+    #[tokio::test]
+    async fn deliver_choices_attach_an_inline_keyboard() {
+        let choice = |label: &str, reply: &str| OutboundChoice {
+            label: label.to_string(),
+            reply: reply.to_string(),
+        };
+        let egress = ScriptedEgress::new(vec![
+            ScriptedEgress::ok(r#"{"ok":true,"result":{"message_id":1}}"#),
+            ScriptedEgress::ok(r#"{"ok":true,"result":{"message_id":2}}"#),
+        ]);
+        let report = TelegramChannelAdapter::default()
+            .deliver(
+                envelope(
+                    vec![
+                        OutboundPart::Choices {
+                            text: "Pick one".to_string(),
+                            choices: vec![choice("Approve", "approve"), choice("Deny", "deny")],
+                        },
+                        OutboundPart::Choices {
+                            text: "Pick one".to_string(),
+                            choices: vec![choice("Too long", &"x".repeat(65))],
+                        },
+                    ],
+                    None,
+                ),
+                &egress,
+            )
+            .await
+            .expect("deliver drives");
+        assert_eq!(report.parts.len(), 2);
+
+        let requests = egress.requests.lock().unwrap();
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[0].body.as_deref().unwrap_or_default()).unwrap();
+        assert_eq!(body["text"], "Pick one");
+        assert_eq!(
+            body["reply_markup"],
+            serde_json::json!({"inline_keyboard": [
+                [{"text": "Approve", "callback_data": "approve"}],
+                [{"text": "Deny", "callback_data": "deny"}]
+            ]})
+        );
+
+        // callback_data over Telegram's 64-byte limit degrades to typed
+        // replies instead of a request the vendor would reject.
+        let body: serde_json::Value =
+            serde_json::from_slice(requests[1].body.as_deref().unwrap_or_default()).unwrap();
+        assert!(body.get("reply_markup").is_none());
+        assert!(
+            body["text"]
+                .as_str()
+                .is_some_and(|text| text.starts_with("Pick one\n\nReply with one of:"))
+        );
+    }
+
     #[tokio::test]
     async fn deliver_send_requires_message_id_evidence() {
         let egress = ScriptedEgress::new(vec![ScriptedEgress::ok(r#"{"ok":true}"#)]);
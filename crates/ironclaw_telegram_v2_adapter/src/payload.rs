@@ -381,13 +381,21 @@ fn slice_text_by_offset(text: &str, offset: u32, length: u32) -> Option<&str> {
 // ── Channel-normalized parsing (generic ingress router, extension-runtime P4) ──
 
 /// One host-verified Telegram webhook update, normalized for the generic
-/// channel-adapter contract: an ignored (but authenticated) update or one
-/// plain user message. Bot-command reclassification is a host-sink concern;
-/// the message text carries the command verbatim.
+/// channel-adapter contract: an ignored (but authenticated) update, one
+/// plain user message, or an inline-keyboard tap. Bot-command
+/// reclassification is a host-sink concern; the message text carries the
+/// command verbatim.
 #[derive(Debug)]
 pub enum TelegramInboundEvent {
     Ignore,
     Message(Box<NormalizedInboundMessage>),
+    /// A button tap. Telegram clients show a spinner until the bot answers
+    /// `callback_query_id`, so the tap must be acknowledged even when it
+    /// forwards no message.
+    CallbackQuery {
+        callback_query_id: String,
+        message: Option<Box<NormalizedInboundMessage>>,
+    },
 }
 
 /// Parse one HOST-VERIFIED Telegram update into its normalized channel form.
@@ -411,6 +419,9 @@ pub fn normalize_telegram_update(
     }
     let event_id = build_event_id(installation_id, update_id)?;
 
+    if let Some(callback) = update.callback_query {
+        return normalize_callback_query(callback, event_id, group_trigger_policy);
+    }
     let Some(message) = update.message else {
         return Ok(TelegramInboundEvent::Ignore);
     };
@@ -456,6 +467,65 @@ pub fn normalize_telegram_update(
     )))
 }
 
+/// Normalize an inline-keyboard tap into a user message carrying the
+/// button's `callback_data`, so a choice reaches the agent exactly as if the
+/// user had typed it. The actor is the user who TAPPED (not whoever the
+/// keyboard was addressed to), so the host's actor admission applies to
+/// callback senders like any other message. Only taps on the bot's own
+/// messages are forwarded from groups; channels stay non-interactive.
+fn normalize_callback_query(
+    callback: TelegramCallbackQuery,
+    event_id: ExternalEventId,
+    group_trigger_policy: &GroupTriggerPolicy,
+) -> Result<TelegramInboundEvent, PayloadParseError> {
+    if callback.id.is_empty() {
+        return Ok(TelegramInboundEvent::Ignore);
+    }
+    let message = callback_query_message(&callback, event_id, group_trigger_policy)?;
+    Ok(TelegramInboundEvent::CallbackQuery {
+        callback_query_id: callback.id,
+        message: message.map(Box::new),
+    })
+}
+
+fn callback_query_message(
+    callback: &TelegramCallbackQuery,
+    event_id: ExternalEventId,
+    group_trigger_policy: &GroupTriggerPolicy,
+) -> Result<Option<NormalizedInboundMessage>, PayloadParseError> {
+    let (Some(message), Some(data)) = (&callback.message, &callback.data) else {
+        return Ok(None);
+    };
+    if data.is_empty() {
+        return Ok(None);
+    }
+    let trigger = match TelegramChatKind::from_str(message.chat.kind.as_str()) {
+        TelegramChatKind::Private => ProductTriggerReason::DirectChat,
+        TelegramChatKind::Channel => return Ok(None),
+        _ => {
+            let from_bot = message
+                .from
+                .as_ref()
+                .is_some_and(|from| from.is_bot && from.id == group_trigger_policy.bot_user_id);
+            if !from_bot {
+                return Ok(None);
+            }
+            ProductTriggerReason::ReplyToBot
+        }
+    };
+    let actor = build_actor_ref(Some(&callback.from))?;
+    let conversation = build_conversation_ref(message)?;
+    Ok(Some(NormalizedInboundMessage {
+        actor,
+        conversation,
+        event_id,
+        text: data.clone(),
+        trigger,
+        attachments: Vec::new(),
+        reply_context: None,
+    }))
+}
+
 #[cfg(test)]
 mod slice_tests {
     use super::*;
@@ -783,6 +853,23 @@ struct TelegramUpdate {
     edited_message: Option<TelegramMessage>,
     #[serde(default)]
     channel_post: Option<TelegramMessage>,
+    #[serde(default)]
+    callback_query: Option<TelegramCallbackQuery>,
+}
+
+/// An inline-keyboard button tap. `message` is the bot message the keyboard
+/// was attached to (absent for inline-mode messages); `data` is the
+/// button's `callback_data` (absent for game buttons); `id` is what
+/// `answerCallbackQuery` acknowledges.
+#[derive(Debug, Clone, Deserialize)]
+struct TelegramCallbackQuery {
+    #[serde(default)]
+    id: String,
+    from: TelegramUser,
+    #[serde(default)]
+    message: Option<TelegramMessage>,
+    #[serde(default)]
+    data: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
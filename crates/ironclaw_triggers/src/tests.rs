@@ -1045,6 +1045,42 @@ fn cron_schedule_dst_spring_forward_does_not_panic() {
         .expect("no error during DST gap");
 }
 
+#[test]
+fn cron_schedule_keeps_local_wall_clock_across_dst_transitions() {
+    // "9am daily" in New York stays at 9am local on both sides of each
+    // transition: 14:00 UTC under EST (UTC-5), 13:00 UTC under EDT (UTC-4).
+    let schedule = TriggerSchedule::cron_with_timezone("0 9 * * *", "America/New_York")
+        .expect("valid schedule");
+    let slot_after = |after| {
+        schedule
+            .next_slot_after(after)
+            .expect("next slot")
+            .expect("future slot")
+    };
+
+    // Spring forward on 2026-03-08.
+    let saturday = slot_after(Utc.with_ymd_and_hms(2026, 3, 7, 0, 0, 0).unwrap());
+    assert_eq!(
+        saturday,
+        Utc.with_ymd_and_hms(2026, 3, 7, 14, 0, 0).unwrap()
+    );
+    assert_eq!(
+        slot_after(saturday),
+        Utc.with_ymd_and_hms(2026, 3, 8, 13, 0, 0).unwrap()
+    );
+
+    // Fall back on 2026-11-01.
+    let saturday = slot_after(Utc.with_ymd_and_hms(2026, 10, 31, 0, 0, 0).unwrap());
+    assert_eq!(
+        saturday,
+        Utc.with_ymd_and_hms(2026, 10, 31, 13, 0, 0).unwrap()
+    );
+    assert_eq!(
+        slot_after(saturday),
+        Utc.with_ymd_and_hms(2026, 11, 1, 14, 0, 0).unwrap()
+    );
+}
+
 #[test]
 fn once_from_local_valid_time_converts_to_utc() {
     // 2026-01-15 09:00:00 America/New_York = EST = UTC-5 => 14:00:00 UTC
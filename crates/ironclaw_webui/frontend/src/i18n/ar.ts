@@ -594,6 +594,12 @@ registerPack("ar", {
     "صريح فقط — يعمل فقط عند كتابة /name. انقر لتفعيل التشغيل التلقائي.",
   "skills.autoActivateOnLabel": "التفعيل التلقائي: مفعّل",
   "skills.autoActivateOffLabel": "التفعيل التلقائي: معطّل",
+  "skills.enabledOnTitle":
+    "مفعّلة — انقر للتعطيل. المهارة المعطّلة لا تعمل أبدًا، حتى مع /name.",
+  "skills.enabledOffTitle":
+    "معطّلة — لا تعمل أبدًا، حتى مع /name. انقر للتفعيل.",
+  "skills.enabledOnLabel": "مفعّلة",
+  "skills.enabledOffLabel": "معطّلة",
 
   // Settings — users tab
   "users.title": "المستخدمون ({count})",
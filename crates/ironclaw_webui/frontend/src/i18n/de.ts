@@ -594,6 +594,12 @@ registerPack("de", {
     "Nur explizit — läuft nur, wenn du /name eingibst. Klicken, um automatische Aktivierung zu aktivieren.",
   "skills.autoActivateOnLabel": "Auto-Aktivierung: Ein",
   "skills.autoActivateOffLabel": "Auto-Aktivierung: Aus",
+  "skills.enabledOnTitle":
+    "Aktiviert — klicken zum Deaktivieren. Ein deaktivierter Skill läuft nie, auch nicht mit /name.",
+  "skills.enabledOffTitle":
+    "Deaktiviert — läuft nie, auch nicht mit /name. Klicken zum Aktivieren.",
+  "skills.enabledOnLabel": "Aktiviert",
+  "skills.enabledOffLabel": "Deaktiviert",
 
   // Settings — users tab
   "users.title": "Benutzer ({count})",
@@ -631,6 +631,12 @@ registerPack("en", {
     "Explicit-only — runs only when you type /name. Click to enable auto-activation.",
   "skills.autoActivateOnLabel": "Auto-activate: On",
   "skills.autoActivateOffLabel": "Auto-activate: Off",
+  "skills.enabledOnTitle":
+    "Enabled — click to disable. A disabled skill never runs, not even with /name.",
+  "skills.enabledOffTitle":
+    "Disabled — never runs, not even with /name. Click to enable.",
+  "skills.enabledOnLabel": "Enabled",
+  "skills.enabledOffLabel": "Disabled",
 
   // Settings — users tab
   "users.title": "Users ({count})",
@@ -594,6 +594,12 @@ registerPack("es", {
     "Solo explícito: se ejecuta solo cuando escribes /name. Haz clic para activar la autoactivación.",
   "skills.autoActivateOnLabel": "Autoactivar: Sí",
   "skills.autoActivateOffLabel": "Autoactivar: No",
+  "skills.enabledOnTitle":
+    "Habilitada: haz clic para deshabilitarla. Una skill deshabilitada nunca se ejecuta, ni siquiera con /name.",
+  "skills.enabledOffTitle":
+    "Deshabilitada: nunca se ejecuta, ni siquiera con /name. Haz clic para habilitarla.",
+  "skills.enabledOnLabel": "Habilitada",
+  "skills.enabledOffLabel": "Deshabilitada",
 
   // Settings — users tab
   "users.title": "Usuarios ({count})",
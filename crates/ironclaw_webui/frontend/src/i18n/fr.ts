@@ -594,6 +594,12 @@ registerPack("fr", {
     "Explicite uniquement — s’exécute seulement lorsque vous tapez /name. Cliquez pour activer l’activation automatique.",
   "skills.autoActivateOnLabel": "Auto-activation : activée",
   "skills.autoActivateOffLabel": "Auto-activation : désactivée",
+  "skills.enabledOnTitle":
+    "Activée — cliquez pour désactiver. Une compétence désactivée ne s’exécute jamais, même avec /name.",
+  "skills.enabledOffTitle":
+    "Désactivée — ne s’exécute jamais, même avec /name. Cliquez pour activer.",
+  "skills.enabledOnLabel": "Activée",
+  "skills.enabledOffLabel": "Désactivée",
 
   // Settings — users tab
   "users.title": "Utilisateurs ({count})",
@@ -594,6 +594,12 @@ registerPack("hi", {
     "केवल स्पष्ट — सिर्फ़ /name लिखने पर चलेगी। ऑटो-एक्टिवेशन चालू करने के लिए क्लिक करें।",
   "skills.autoActivateOnLabel": "ऑटो-एक्टिवेट: चालू",
   "skills.autoActivateOffLabel": "ऑटो-एक्टिवेट: बंद",
+  "skills.enabledOnTitle":
+    "सक्षम — अक्षम करने के लिए क्लिक करें। अक्षम स्किल कभी नहीं चलती, /name से भी नहीं।",
+  "skills.enabledOffTitle":
+    "अक्षम — कभी नहीं चलती, /name से भी नहीं। सक्षम करने के लिए क्लिक करें।",
+  "skills.enabledOnLabel": "सक्षम",
+  "skills.enabledOffLabel": "अक्षम",
 
   // Settings — users tab
   "users.title": "उपयोगकर्ता ({count})",
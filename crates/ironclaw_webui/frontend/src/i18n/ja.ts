@@ -594,6 +594,12 @@ registerPack("ja", {
     "明示実行のみ — /name を入力したときだけ実行されます。クリックすると自動有効化します。",
   "skills.autoActivateOnLabel": "自動有効化: オン",
   "skills.autoActivateOffLabel": "自動有効化: オフ",
+  "skills.enabledOnTitle":
+    "有効 — クリックで無効化します。無効なスキルは /name でも実行されません。",
+  "skills.enabledOffTitle":
+    "無効 — /name でも実行されません。クリックで有効化します。",
+  "skills.enabledOnLabel": "有効",
+  "skills.enabledOffLabel": "無効",
 
   // Settings — users tab
   "users.title": "ユーザー ({count})",
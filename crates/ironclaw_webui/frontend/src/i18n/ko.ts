@@ -594,6 +594,12 @@ registerPack("ko", {
     "명시 실행만 — /name을 입력할 때만 실행됩니다. 클릭하면 자동 활성화를 켭니다.",
   "skills.autoActivateOnLabel": "자동 활성화: 켜짐",
   "skills.autoActivateOffLabel": "자동 활성화: 꺼짐",
+  "skills.enabledOnTitle":
+    "사용 중 — 클릭하면 사용 중지합니다. 사용 중지된 스킬은 /name으로도 실행되지 않습니다.",
+  "skills.enabledOffTitle":
+    "사용 중지됨 — /name으로도 실행되지 않습니다. 클릭하면 사용합니다.",
+  "skills.enabledOnLabel": "사용 중",
+  "skills.enabledOffLabel": "사용 중지됨",
 
   // Settings — users tab
   "users.title": "사용자 ({count})",
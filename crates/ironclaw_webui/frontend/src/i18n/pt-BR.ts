@@ -594,6 +594,12 @@ registerPack("pt-BR", {
     "Somente explícito — roda apenas quando você digita /name. Clique para ativar a autoativação.",
   "skills.autoActivateOnLabel": "Autoativar: Ligado",
   "skills.autoActivateOffLabel": "Autoativar: Desligado",
+  "skills.enabledOnTitle":
+    "Habilitada — clique para desabilitar. Uma skill desabilitada nunca roda, nem com /name.",
+  "skills.enabledOffTitle":
+    "Desabilitada — nunca roda, nem com /name. Clique para habilitar.",
+  "skills.enabledOnLabel": "Habilitada",
+  "skills.enabledOffLabel": "Desabilitada",
 
   // Settings — users tab
   "users.title": "Usuários ({count})",
@@ -594,6 +594,12 @@ registerPack("uk", {
     "Лише явно — запускається тільки коли ви вводите /name. Натисніть, щоб увімкнути автоактивацію.",
   "skills.autoActivateOnLabel": "Автоактивація: увімкнено",
   "skills.autoActivateOffLabel": "Автоактивація: вимкнено",
+  "skills.enabledOnTitle":
+    "Увімкнено — натисніть, щоб вимкнути. Вимкнена навичка ніколи не запускається, навіть з /name.",
+  "skills.enabledOffTitle":
+    "Вимкнено — ніколи не запускається, навіть з /name. Натисніть, щоб увімкнути.",
+  "skills.enabledOnLabel": "Увімкнено",
+  "skills.enabledOffLabel": "Вимкнено",
 
   // Settings — users tab
   "users.title": "Користувачі ({count})",
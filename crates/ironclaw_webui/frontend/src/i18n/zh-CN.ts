@@ -594,6 +594,12 @@ registerPack("zh-CN", {
     "仅显式运行 — 只在你输入 /name 时运行。点击可启用自动激活。",
   "skills.autoActivateOnLabel": "自动激活：开",
   "skills.autoActivateOffLabel": "自动激活：关",
+  "skills.enabledOnTitle":
+    "已启用 — 点击可停用。停用的技能永远不会运行，即使输入 /name 也不会。",
+  "skills.enabledOffTitle":
+    "已停用 — 永远不会运行，即使输入 /name 也不会。点击可启用。",
+  "skills.enabledOnLabel": "已启用",
+  "skills.enabledOffLabel": "已停用",
 
   // Settings — users tab
   "users.title": "用户 ({count})",
@@ -219,6 +219,10 @@ test("locale packs include skill auto-activation controls", () => {
     "skills.autoActivateOffTitle",
     "skills.autoActivateOnLabel",
     "skills.autoActivateOffLabel",
+    "skills.enabledOnTitle",
+    "skills.enabledOffTitle",
+    "skills.enabledOnLabel",
+    "skills.enabledOffLabel",
   ];
 
   for (const locale of LOCALES) {
@@ -11,9 +11,11 @@ export function SkillCard({
   onRemove,
   onUpdate,
   onSetAutoActivate,
+  onSetEnabled,
   isRemoving,
   isUpdating,
   isSettingAutoActivate,
+  isSettingEnabled,
 }) {
   const t = useT();
   const name = skill.name || skill.id;
@@ -23,6 +25,8 @@ export function SkillCard({
   const canDelete = Boolean(skill.can_delete);
   // Defaults true: a skill without the field auto-activates.
   const autoActivate = skill.auto_activate !== false;
+  // Defaults true: older backends do not report enablement.
+  const enabled = skill.enabled !== false;
   const [isEditing, setIsEditing] = React.useState(false);
   const [draft, setDraft] = React.useState("");
   const [editError, setEditError] = React.useState("");
@@ -152,6 +156,20 @@ export function SkillCard({
                 : t("skills.autoActivateOffLabel")}
             </Button>
           )}
+          {!isEditing &&
+          (
+            <Button
+              type="button"
+              variant={enabled ? "secondary" : "ghost"}
+              size="sm"
+              disabled={isSettingEnabled}
+              title={enabled ? t("skills.enabledOnTitle") : t("skills.enabledOffTitle")}
+              onClick={() => onSetEnabled(name, !enabled)}
+            >
+              <Icon name={enabled ? "check" : "close"} className="h-4 w-4" />
+              {enabled ? t("skills.enabledOnLabel") : t("skills.enabledOffLabel")}
+            </Button>
+          )}
           {canDelete && !isEditing &&
           (
             <Button
@@ -20,11 +20,13 @@ export function SkillsTab({ searchQuery = "" }) {
     removeSkill,
     updateSkill,
     setSkillAutoActivate,
+    setSkillEnabled,
     setAutoActivateLearned,
     isInstalling,
     isRemoving,
     isUpdating,
     isSettingAutoActivate,
+    isSettingEnabled,
     isSettingAutoActivateLearned,
   } = useSkills();
   const [actionError, setActionError] = React.useState("");
@@ -84,6 +86,21 @@ export function SkillsTab({ searchQuery = "" }) {
     }
   }, [setSkillAutoActivate, t]);
 
+  const handleSetEnabled = React.useCallback(async (name, enabled) => {
+    setActionError("");
+    setActionResult("");
+    try {
+      const response = await setSkillEnabled({ name, enabled });
+      if (!response?.success) {
+        setActionError(response?.message || t("skills.updateFailed"));
+        return;
+      }
+      setActionResult(response.message);
+    } catch (err) {
+      setActionError(err.message || t("skills.updateFailed"));
+    }
+  }, [setSkillEnabled, t]);
+
   const handleSetAutoActivateLearned = React.useCallback(async (enabled) => {
     setActionError("");
     setActionResult("");
@@ -160,9 +177,11 @@ export function SkillsTab({ searchQuery = "" }) {
                 onRemove={handleRemove}
                 onUpdate={handleUpdate}
                 onSetAutoActivate={handleSetAutoActivate}
+                onSetEnabled={handleSetEnabled}
                 isRemoving={isRemoving}
                 isUpdating={isUpdating}
                 isSettingAutoActivate={isSettingAutoActivate}
+                isSettingEnabled={isSettingEnabled}
               />
             )
           )}
@@ -236,9 +255,11 @@ function SkillGroup({
   onRemove,
   onUpdate,
   onSetAutoActivate,
+  onSetEnabled,
   isRemoving,
   isUpdating,
   isSettingAutoActivate,
+  isSettingEnabled,
 }) {
   if (skills.length === 0) return null;
   return (
@@ -255,9 +276,11 @@ function SkillGroup({
             onRemove={onRemove}
             onUpdate={onUpdate}
             onSetAutoActivate={onSetAutoActivate}
+            onSetEnabled={onSetEnabled}
             isRemoving={isRemoving}
             isUpdating={isUpdating}
             isSettingAutoActivate={isSettingAutoActivate}
+            isSettingEnabled={isSettingEnabled}
           />
         )
       )}
@@ -11,6 +11,7 @@ const settingsApi = vi.hoisted(() => ({
   removeSkill: vi.fn(),
   setAutoActivateLearned: vi.fn(),
   setSkillAutoActivate: vi.fn(),
+  setSkillEnabled: vi.fn(),
   updateSkill: vi.fn(),
 }));
 
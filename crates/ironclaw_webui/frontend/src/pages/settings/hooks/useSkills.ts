@@ -7,6 +7,7 @@ import {
   removeSkill as removeSkillRequest,
   setAutoActivateLearned as setAutoActivateLearnedRequest,
   setSkillAutoActivate as setSkillAutoActivateRequest,
+  setSkillEnabled as setSkillEnabledRequest,
   updateSkill as updateSkillRequest,
 } from "../lib/settings-api";
 
@@ -45,6 +46,13 @@ export function useSkills() {
     },
   });
 
+  const enabledMutation = useMutation({
+    mutationFn: ({ name, enabled }) => setSkillEnabledRequest(name, enabled),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ["skills"] });
+    },
+  });
+
   const learnedAutoActivateMutation = useMutation({
     mutationFn: (enabled) => setAutoActivateLearnedRequest(enabled),
     onSuccess: (_response, enabled) => {
@@ -75,11 +83,13 @@ export function useSkills() {
     removeSkill: removeMutation.mutateAsync,
     updateSkill: updateMutation.mutateAsync,
     setSkillAutoActivate: autoActivateMutation.mutateAsync,
+    setSkillEnabled: enabledMutation.mutateAsync,
     setAutoActivateLearned: learnedAutoActivateMutation.mutateAsync,
     isInstalling: installMutation.isPending,
     isRemoving: removeMutation.isPending,
     isUpdating: updateMutation.isPending,
     isSettingAutoActivate: autoActivateMutation.isPending,
+    isSettingEnabled: enabledMutation.isPending,
     // The card renders before the initial skills request settles. Keep its
     // toggle disabled until that authoritative value arrives so an older GET
     // cannot overwrite a mutation-confirmed cache update.
@@ -286,6 +286,15 @@ export function setSkillAutoActivate(name, enabled) {
     body: JSON.stringify({ enabled }),
   });
 }
+// Operator on/off switch. A disabled skill never activates, not even by an
+// explicit /name mention.
+export function setSkillEnabled(name, enabled) {
+  return apiFetch(`/api/webchat/v2/skills/${encodeURIComponent(name)}/enabled`, {
+    method: "POST",
+    headers: { "X-Confirm-Action": "true" },
+    body: JSON.stringify({ enabled }),
+  });
+}
 // Global "auto-activate learned skills" master switch. When disabled, learned
 // skills activate only via an explicit /name mention.
 export function setAutoActivateLearned(enabled) {
@@ -60,6 +60,7 @@ pub const WEBUI_V2_ROUTE_GET_SKILL: &str = "webui.v2.get_skill";
 pub const WEBUI_V2_ROUTE_UPDATE_SKILL: &str = "webui.v2.update_skill";
 pub const WEBUI_V2_ROUTE_REMOVE_SKILL: &str = "webui.v2.remove_skill";
 pub const WEBUI_V2_ROUTE_SET_SKILL_AUTO_ACTIVATE: &str = "webui.v2.set_skill_auto_activate";
+pub const WEBUI_V2_ROUTE_SET_SKILL_ENABLED: &str = "webui.v2.set_skill_enabled";
 pub const WEBUI_V2_ROUTE_SET_AUTO_ACTIVATE_LEARNED: &str = "webui.v2.set_auto_activate_learned";
 pub const WEBUI_V2_ROUTE_LIST_SETTINGS_TOOLS: &str = "webui.v2.settings.list_tools";
 pub const WEBUI_V2_ROUTE_SET_SETTINGS_TOOLS_AUTO_APPROVE: &str =
@@ -168,6 +169,7 @@ pub const WEBUI_V2_PATTERN_INSTALL_SKILL: &str = "/api/webchat/v2/skills/install
 pub const WEBUI_V2_PATTERN_SKILL_DETAIL: &str = "/api/webchat/v2/skills/{name}";
 pub const WEBUI_V2_PATTERN_SET_SKILL_AUTO_ACTIVATE: &str =
     "/api/webchat/v2/skills/{name}/auto-activate";
+pub const WEBUI_V2_PATTERN_SET_SKILL_ENABLED: &str = "/api/webchat/v2/skills/{name}/enabled";
 pub const WEBUI_V2_PATTERN_SET_AUTO_ACTIVATE_LEARNED: &str =
     "/api/webchat/v2/skills/auto-activate-learned";
 pub const WEBUI_V2_PATTERN_SETTINGS_TOOLS: &str = "/api/webchat/v2/settings/tools";
@@ -260,6 +262,7 @@ pub fn webui_v2_routes() -> Vec<IngressRouteDescriptor> {
         update_skill_descriptor(),
         remove_skill_descriptor(),
         set_skill_auto_activate_descriptor(),
+        set_skill_enabled_descriptor(),
         set_auto_activate_learned_descriptor(),
         list_settings_tools_descriptor(),
         set_settings_tools_auto_approve_descriptor(),
@@ -1230,6 +1233,20 @@ fn set_skill_auto_activate_descriptor() -> IngressRouteDescriptor {
     )
 }
 
+fn set_skill_enabled_descriptor() -> IngressRouteDescriptor {
+    descriptor(
+        WEBUI_V2_ROUTE_SET_SKILL_ENABLED,
+        NetworkMethod::Post,
+        WEBUI_V2_PATTERN_SET_SKILL_ENABLED,
+        mutation_policy(
+            body_limit_kib(4),
+            mutation_rate_limit(),
+            AuditTraceClass::UserAction,
+            AllowedEffectPath::ProductWorkflow,
+        ),
+    )
+}
+
 fn set_auto_activate_learned_descriptor() -> IngressRouteDescriptor {
     descriptor(
         WEBUI_V2_ROUTE_SET_AUTO_ACTIVATE_LEARNED,
@@ -93,9 +93,9 @@ use ironclaw_product::{
     RebornTraceHoldAuthorizeProductRequest, RebornTraceHoldAuthorizeResponse,
     RebornUpdateMemberRoleRequest, RebornUpdateProjectRequest, RebornViewDescriptor,
     RebornViewPage, RebornViewQuery, SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY,
-    SKILL_AUTO_ACTIVATE_SET_CAPABILITY, SKILL_CONTENT_VIEW, SKILL_INSTALL_CAPABILITY,
-    SKILL_REMOVE_CAPABILITY, SKILL_SEARCH_VIEW, SKILL_UPDATE_CAPABILITY, SKILLS_VIEW,
-    SUBMIT_TURN_COMMAND, SetActiveLlmRequest, SettingsToolPermissionState,
+    SKILL_AUTO_ACTIVATE_SET_CAPABILITY, SKILL_CONTENT_VIEW, SKILL_ENABLED_SET_CAPABILITY,
+    SKILL_INSTALL_CAPABILITY, SKILL_REMOVE_CAPABILITY, SKILL_SEARCH_VIEW, SKILL_UPDATE_CAPABILITY,
+    SKILLS_VIEW, SUBMIT_TURN_COMMAND, SetActiveLlmRequest, SettingsToolPermissionState,
     THREAD_DELETE_CAPABILITY, THREADS_VIEW, TIMELINE_VIEW, TRACE_ACCOUNT_LOGIN_LINK_COMMAND,
     TRACE_ACCOUNT_TRACES_VIEW, TRACE_CREDITS_VIEW, TRACE_HOLD_AUTHORIZE_COMMAND,
     UpsertLlmProviderRequest, install_extension_on_surface, parse_client_action_id,
@@ -2034,6 +2034,35 @@ pub async fn set_skill_auto_activate(
     }))
 }
 
+/// `POST /api/webchat/v2/skills/{name}/enabled`
+pub async fn set_skill_enabled(
+    State(state): State<WebUiV2State>,
+    Extension(caller): Extension<ProductSurfaceCaller>,
+    Path(SkillPath { name }): Path<SkillPath>,
+    Json(body): Json<SetSkillEnabledBody>,
+) -> Result<Json<RebornSkillActionResponse>, WebUiV2HttpError> {
+    let enabled = body.enabled;
+    let resolution = invoke_product_capability(
+        state.services(),
+        caller,
+        SKILL_ENABLED_SET_CAPABILITY,
+        serde_json::json!({
+            "name": name.clone(),
+            "enabled": enabled,
+        }),
+    )
+    .await?;
+    skill_mutation_succeeded(resolution)?;
+    Ok(Json(RebornSkillActionResponse {
+        success: true,
+        message: format!(
+            "Skill '{}' {}",
+            name,
+            if enabled { "enabled" } else { "disabled" }
+        ),
+    }))
+}
+
 /// `POST /api/webchat/v2/skills/auto-activate-learned`
 pub async fn set_auto_activate_learned(
     State(state): State<WebUiV2State>,
@@ -3584,6 +3613,11 @@ pub struct SetSkillAutoActivateBody {
     pub enabled: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SetSkillEnabledBody {
+    pub enabled: bool,
+}
+
 fn extension_package_ref_for_request(
     package_ref: Result<LifecyclePackageRef, ProductWorkflowError>,
     field: &'static str,
@@ -84,9 +84,10 @@ pub use descriptors::{
     WEBUI_V2_ROUTE_SEND_MESSAGE, WEBUI_V2_ROUTE_SET_ACTIVE_LLM,
     WEBUI_V2_ROUTE_SET_AUTO_ACTIVATE_LEARNED, WEBUI_V2_ROUTE_SET_OUTBOUND_PREFERENCES,
     WEBUI_V2_ROUTE_SET_SETTINGS_TOOL_PERMISSION, WEBUI_V2_ROUTE_SET_SETTINGS_TOOLS_AUTO_APPROVE,
-    WEBUI_V2_ROUTE_SET_SKILL_AUTO_ACTIVATE, WEBUI_V2_ROUTE_SETUP_EXTENSION,
-    WEBUI_V2_ROUTE_START_CODEX_LOGIN, WEBUI_V2_ROUTE_START_NEARAI_LOGIN,
-    WEBUI_V2_ROUTE_STAT_FS_PATH, WEBUI_V2_ROUTE_STAT_PROJECT_FILE, WEBUI_V2_ROUTE_STREAM_EVENTS,
+    WEBUI_V2_ROUTE_SET_SKILL_AUTO_ACTIVATE, WEBUI_V2_ROUTE_SET_SKILL_ENABLED,
+    WEBUI_V2_ROUTE_SETUP_EXTENSION, WEBUI_V2_ROUTE_START_CODEX_LOGIN,
+    WEBUI_V2_ROUTE_START_NEARAI_LOGIN, WEBUI_V2_ROUTE_STAT_FS_PATH,
+    WEBUI_V2_ROUTE_STAT_PROJECT_FILE, WEBUI_V2_ROUTE_STREAM_EVENTS,
     WEBUI_V2_ROUTE_STREAM_EVENTS_WS, WEBUI_V2_ROUTE_TEST_LLM_CONNECTION,
     WEBUI_V2_ROUTE_TRACE_ACCOUNT_LOGIN_LINK, WEBUI_V2_ROUTE_TRACE_ACCOUNT_TRACES,
     WEBUI_V2_ROUTE_TRACE_CREDITS, WEBUI_V2_ROUTE_TRACE_HOLD_AUTHORIZE,
@@ -108,9 +109,9 @@ pub use handlers::{
     resolve_gate, resume_automation, retry_run, run_operator_service_lifecycle, run_operator_setup,
     search_skills, send_message, set_active_llm, set_auto_activate_learned,
     set_operator_config_key, set_outbound_preferences, set_settings_tool_permission,
-    set_settings_tools_auto_approve, set_skill_auto_activate, setup_extension, start_codex_login,
-    start_nearai_login, stat_fs_path, stream_events, stream_events_ws, test_llm_connection,
-    trace_account_traces, trace_credits, update_skill, upsert_llm_provider,
+    set_settings_tools_auto_approve, set_skill_auto_activate, set_skill_enabled, setup_extension,
+    start_codex_login, start_nearai_login, stat_fs_path, stream_events, stream_events_ws,
+    test_llm_connection, trace_account_traces, trace_credits, update_skill, upsert_llm_provider,
 };
 pub use router::{
     WebUiV2Capabilities, WebUiV2RouteOptions, WebUiV2State, webui_v2_router,
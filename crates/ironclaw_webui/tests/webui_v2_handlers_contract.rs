@@ -92,10 +92,10 @@ use ironclaw_product::{
     RebornTraceCreditsResponse, RebornTraceHoldAuthorizeProductRequest,
     RebornTraceHoldAuthorizeResponse, RebornViewPage, RebornViewQuery, RunArtifactLogs,
     RunArtifactRedaction, SKILL_AUTO_ACTIVATE_LEARNED_SET_CAPABILITY_ID,
-    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_CONTENT_VIEW, SKILL_INSTALL_CAPABILITY_ID,
-    SKILL_REMOVE_CAPABILITY_ID, SKILL_SEARCH_VIEW, SKILL_UPDATE_CAPABILITY_ID, SKILLS_VIEW,
-    THREAD_DELETE_CAPABILITY_ID, THREADS_VIEW, TIMELINE_VIEW, TRACE_ACCOUNT_TRACES_VIEW,
-    TRACE_CREDITS_VIEW, rejecting_product_surface_error,
+    SKILL_AUTO_ACTIVATE_SET_CAPABILITY_ID, SKILL_CONTENT_VIEW, SKILL_ENABLED_SET_CAPABILITY_ID,
+    SKILL_INSTALL_CAPABILITY_ID, SKILL_REMOVE_CAPABILITY_ID, SKILL_SEARCH_VIEW,
+    SKILL_UPDATE_CAPABILITY_ID, SKILLS_VIEW, THREAD_DELETE_CAPABILITY_ID, THREADS_VIEW,
+    TIMELINE_VIEW, TRACE_ACCOUNT_TRACES_VIEW, TRACE_CREDITS_VIEW, rejecting_product_surface_error,
 };
 use ironclaw_product::{
     AdapterInstallationId, CapabilityActivityStatusView, CapabilityActivityView,
@@ -5153,6 +5153,21 @@ async fn skill_content_and_mutations_use_product_surface() {
         .expect("oneshot");
     assert_eq!(toggle_response.status(), StatusCode::OK);
 
+    services.enqueue_invoke_response(Ok(successful_resolution(ActivityId::new())));
+    let enabled_response = router
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/webchat/v2/skills/demo-skill/enabled")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"enabled":false}"#))
+                .expect("request"),
+        )
+        .await
+        .expect("oneshot");
+    assert_eq!(enabled_response.status(), StatusCode::OK);
+
     services.enqueue_invoke_response(Ok(successful_resolution(ActivityId::new())));
     let remove_response = router
         .oneshot(
@@ -5210,6 +5225,13 @@ async fn skill_content_and_mutations_use_product_surface() {
                     "enabled": false,
                 }),
             ),
+            (
+                SKILL_ENABLED_SET_CAPABILITY_ID,
+                serde_json::json!({
+                    "name": "demo-skill",
+                    "enabled": false,
+                }),
+            ),
             (
                 SKILL_REMOVE_CAPABILITY_ID,
                 serde_json::json!({ "name": "demo-skill" }),
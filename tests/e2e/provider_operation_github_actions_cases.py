@@ -69,6 +69,15 @@ async def _trigger_workflow_outcome(
     assert json.loads(preview["output_preview"]) == {"status": 204}, preview
 
 
+async def _repository_dispatch_outcome(
+    emulate_url: str, preview: dict
+) -> None:
+    await _actions_baseline(emulate_url)
+    output = json.loads(preview["output_preview"])
+    assert output["status"] == 204, output
+    assert output["event_type"] == "provider-contract", output
+
+
 async def _jobs_outcome(emulate_url: str, preview: dict) -> None:
     await _actions_baseline(emulate_url)
     assert JOB_ID in [
@@ -89,6 +98,15 @@ async def _job_logs_outcome(emulate_url: str, preview: dict) -> None:
     assert JOB_LOG in json.dumps(preview), preview
 
 
+async def _run_logs_outcome(emulate_url: str, preview: dict) -> None:
+    await _actions_baseline(emulate_url)
+    output = json.loads(preview["output_preview"])
+    assert output["run_id"] == RUN_ID, output
+    assert [job["id"] for job in output["failed_jobs"]] == [JOB_ID], output
+    assert output["failed_jobs"][0]["log"] == JOB_LOG, output
+    assert output["failed_jobs"][0]["log_truncated"] is False, output
+
+
 async def _artifacts_outcome(emulate_url: str, preview: dict) -> None:
     await _actions_baseline(emulate_url)
     assert ARTIFACT_NAME in json.dumps(preview), preview
@@ -115,6 +133,47 @@ async def _rerun_outcome(emulate_url: str, preview: dict) -> None:
     assert json.loads(preview["output_preview"]) == {"status": 201}, preview
 
 
+async def _full_rerun_outcome(emulate_url: str, preview: dict) -> None:
+    run = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/actions/runs/{RUN_ID}"
+    )
+    assert isinstance(run, dict)
+    assert (run["status"], run["run_attempt"]) == ("queued", 2), run
+    assert json.loads(preview["output_preview"]) == {"status": 201}, preview
+
+
+async def _queued_run_id(emulate_url: str) -> int:
+    runs = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/actions/runs"
+    )
+    assert isinstance(runs, dict)
+    queued = [run for run in runs["workflow_runs"] if run["status"] == "queued"]
+    assert len(queued) == 1, runs
+    return queued[0]["id"]
+
+
+async def _cancel_arguments(emulate_url: str) -> dict:
+    await github_request(
+        emulate_url,
+        "POST",
+        f"{REPO_PATH}/actions/workflows/101/dispatches",
+        payload={"ref": "main"},
+        expected_status=204,
+    )
+    return {**BASE_ARGS, "run_id": await _queued_run_id(emulate_url)}
+
+
+async def _cancel_outcome(emulate_url: str, preview: dict) -> None:
+    runs = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/actions/runs"
+    )
+    assert isinstance(runs, dict)
+    cancelled = [
+        run for run in runs["workflow_runs"] if run["conclusion"] == "cancelled"
+    ]
+    assert len(cancelled) == 1 and cancelled[0]["id"] != RUN_ID, runs
+
+
 GITHUB_ACTIONS_PROVIDER_OPERATION_CASES = (
     ProviderOperationCase(
         case_id="github_get_workflow_runs",
@@ -137,6 +196,18 @@ GITHUB_ACTIONS_PROVIDER_OPERATION_CASES = (
         assert_baseline=_actions_baseline,
         assert_outcome=_trigger_workflow_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_repository_dispatch",
+        provider_service="github",
+        capability_id="github.repository_dispatch",
+        arguments={
+            **BASE_ARGS,
+            "event_type": "provider-contract",
+            "client_payload": {"suite": "provider-contract"},
+        },
+        assert_baseline=_actions_baseline,
+        assert_outcome=_repository_dispatch_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_get_workflow_run_jobs",
         provider_service="github",
@@ -153,6 +224,14 @@ GITHUB_ACTIONS_PROVIDER_OPERATION_CASES = (
         assert_baseline=_actions_baseline,
         assert_outcome=_job_logs_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_get_workflow_run_logs",
+        provider_service="github",
+        capability_id="github.get_workflow_run_logs",
+        arguments={**BASE_ARGS, "run_id": RUN_ID},
+        assert_baseline=_actions_baseline,
+        assert_outcome=_run_logs_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_get_workflow_run_artifacts",
         provider_service="github",
@@ -173,6 +252,22 @@ GITHUB_ACTIONS_PROVIDER_OPERATION_CASES = (
         assert_baseline=_actions_baseline,
         assert_outcome=_rerun_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_rerun_workflow",
+        provider_service="github",
+        capability_id="github.rerun_workflow",
+        arguments={**BASE_ARGS, "run_id": RUN_ID},
+        assert_baseline=_actions_baseline,
+        assert_outcome=_full_rerun_outcome,
+    ),
+    ProviderOperationCase(
+        case_id="github_cancel_workflow_run",
+        provider_service="github",
+        capability_id="github.cancel_workflow_run",
+        arguments=_cancel_arguments,
+        assert_baseline=_actions_baseline,
+        assert_outcome=_cancel_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_rerun_workflow_job",
         provider_service="github",
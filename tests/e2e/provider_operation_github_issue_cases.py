@@ -76,6 +76,17 @@ async def _assignee_remove_baseline(emulate_url: str) -> None:
     await _seeded_assignee_issue(emulate_url, assigned=True)
 
 
+async def _locked_issue_baseline(emulate_url: str) -> None:
+    await seed_issue(emulate_url)
+    await github_request(
+        emulate_url,
+        "PUT",
+        f"{REPO_PATH}/issues/1/lock",
+        payload={"lock_reason": "resolved"},
+        expected_status=204,
+    )
+
+
 async def _seeded_comment_baseline(emulate_url: str) -> None:
     await seed_issue(emulate_url)
     await github_request(
@@ -130,6 +141,14 @@ async def _label_remove_outcome(emulate_url: str, preview: dict) -> None:
     assert LABEL in json.dumps(preview), preview
 
 
+def _lock_outcome(locked: bool):
+    async def assert_outcome(emulate_url: str, preview: dict) -> None:
+        updated = await issue(emulate_url)
+        assert updated["locked"] is locked, updated
+
+    return assert_outcome
+
+
 async def _assignee_add_outcome(emulate_url: str, preview: dict) -> None:
     updated = await issue(emulate_url)
     assert [assignee["login"] for assignee in updated["assignees"]] == [
@@ -266,4 +285,20 @@ GITHUB_ISSUE_PROVIDER_OPERATION_CASES = (
         assert_baseline=_assignee_remove_baseline,
         assert_outcome=_assignee_remove_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_lock_issue",
+        provider_service="github",
+        capability_id="github.lock_issue",
+        arguments={**BASE_ARGS, "issue_number": 1, "lock_reason": "resolved"},
+        assert_baseline=_seeded_issue,
+        assert_outcome=_lock_outcome(True),
+    ),
+    ProviderOperationCase(
+        case_id="github_unlock_issue",
+        provider_service="github",
+        capability_id="github.unlock_issue",
+        arguments={**BASE_ARGS, "issue_number": 1},
+        assert_baseline=_locked_issue_baseline,
+        assert_outcome=_lock_outcome(False),
+    ),
 )
@@ -205,6 +205,16 @@ async def _create_review_outcome(emulate_url: str, preview: dict) -> None:
     assert REVIEW_BODY in json.dumps(preview), preview
 
 
+async def _pull_status_outcome(emulate_url: str, preview: dict) -> None:
+    reviews = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/pulls/1/reviews"
+    )
+    assert [review["body"] for review in reviews] == [REVIEW_BODY], reviews
+    rendered = json.dumps(preview)
+    assert "mergeable" in rendered, preview
+    assert "COMMENTED" in rendered, preview
+
+
 async def _list_comments_outcome(emulate_url: str, preview: dict) -> None:
     comments = await github_request(
         emulate_url, "GET", f"{REPO_PATH}/pulls/1/comments"
@@ -243,6 +253,16 @@ async def _list_threads_outcome(emulate_url: str, preview: dict) -> None:
     assert threads[0]["id"] in json.dumps(preview), preview
 
 
+async def _graphql_outcome(emulate_url: str, preview: dict) -> None:
+    threads = await _review_threads(emulate_url)
+    output = json.loads(preview["output_preview"])
+    assert output["errors"] is None, output
+    assert (
+        output["data"]["repository"]["pullRequest"]["reviewThreads"]["nodes"]
+        == threads
+    ), output
+
+
 def _thread_resolution_outcome(expected: bool):
     async def assert_outcome(emulate_url: str, preview: dict) -> None:
         threads = await _review_threads(emulate_url)
@@ -324,6 +344,14 @@ GITHUB_PULL_PROVIDER_OPERATION_CASES = (
         assert_baseline=_seeded_review,
         assert_outcome=_create_review_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_get_pull_request_status",
+        provider_service="github",
+        capability_id="github.get_pull_request_status",
+        arguments={**BASE_ARGS, "pr_number": 1},
+        assert_baseline=_seeded_review,
+        assert_outcome=_pull_status_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_list_pull_request_comments",
         provider_service="github",
@@ -361,6 +389,22 @@ GITHUB_PULL_PROVIDER_OPERATION_CASES = (
         assert_baseline=_seed_review_thread,
         assert_outcome=_list_threads_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_graphql",
+        provider_service="github",
+        capability_id="github.graphql",
+        arguments={
+            "query": REVIEW_THREADS_QUERY,
+            "variables": {
+                "owner": OWNER,
+                "repo": REPO,
+                "number": 1,
+                "first": 30,
+            },
+        },
+        assert_baseline=_seed_review_thread,
+        assert_outcome=_graphql_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_resolve_review_thread",
         provider_service="github",
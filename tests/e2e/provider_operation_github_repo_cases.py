@@ -139,6 +139,25 @@ async def _list_branches_outcome(emulate_url: str, preview: dict) -> None:
     assert BRANCH in rendered, preview
 
 
+async def _list_commits_outcome(emulate_url: str, preview: dict) -> None:
+    commits = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/commits?sha={BRANCH}"
+    )
+    assert isinstance(commits, list)
+    assert commits, commits
+    assert commits[0]["sha"] in json.dumps(preview), preview
+
+
+async def _get_commit_outcome(emulate_url: str, preview: dict) -> None:
+    commit = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/commits/{BRANCH}"
+    )
+    assert isinstance(commit, dict)
+    rendered = json.dumps(preview)
+    assert commit["sha"] in rendered, preview
+    assert CODE_PATH in rendered, preview
+
+
 async def _create_release_outcome(emulate_url: str, preview: dict) -> None:
     releases = await github_request(emulate_url, "GET", f"{REPO_PATH}/releases")
     assert isinstance(releases, list)
@@ -189,6 +208,12 @@ async def _search_code_outcome(emulate_url: str, preview: dict) -> None:
     assert CODE_PATH in rendered, preview
 
 
+async def _list_tree_outcome(emulate_url: str, preview: dict) -> None:
+    rendered = json.dumps(preview)
+    assert CODE_PATH in rendered, preview
+    assert "truncated" in rendered, preview
+
+
 async def _get_file_outcome(emulate_url: str, preview: dict) -> None:
     resource = await github_request(
         emulate_url,
@@ -249,6 +274,24 @@ async def _get_repo_outcome(emulate_url: str, preview: dict) -> None:
     assert f"{OWNER}/{REPO}" in json.dumps(preview), preview
 
 
+async def _get_repo_languages_outcome(
+    emulate_url: str, preview: dict
+) -> None:
+    languages = await github_request(
+        emulate_url, "GET", f"{REPO_PATH}/languages"
+    )
+    assert isinstance(languages, dict)
+    assert json.loads(preview["output_preview"]) == languages, preview
+
+
+async def _get_contributor_stats_outcome(
+    emulate_url: str, preview: dict
+) -> None:
+    await _repo_baseline(emulate_url)
+    output = json.loads(preview["output_preview"])
+    assert output["status"] in ("ok", "computing"), output
+
+
 async def _seed_listed_release(emulate_url: str) -> None:
     await _repo_baseline(emulate_url)
     release = await github_request(
@@ -292,6 +335,22 @@ GITHUB_REPO_PROVIDER_OPERATION_CASES = (
         assert_baseline=_seed_code,
         assert_outcome=_list_branches_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_list_commits",
+        provider_service="github",
+        capability_id="github.list_commits",
+        arguments={**BASE_ARGS, "sha": BRANCH},
+        assert_baseline=_seed_code,
+        assert_outcome=_list_commits_outcome,
+    ),
+    ProviderOperationCase(
+        case_id="github_get_commit",
+        provider_service="github",
+        capability_id="github.get_commit",
+        arguments={**BASE_ARGS, "ref": BRANCH},
+        assert_baseline=_seed_code,
+        assert_outcome=_get_commit_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_create_release",
         provider_service="github",
@@ -358,6 +417,14 @@ GITHUB_REPO_PROVIDER_OPERATION_CASES = (
         assert_baseline=seed_branch,
         assert_outcome=_get_file_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_list_tree",
+        provider_service="github",
+        capability_id="github.list_tree",
+        arguments={**BASE_ARGS, "ref": BRANCH, "recursive": True},
+        assert_baseline=seed_branch,
+        assert_outcome=_list_tree_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_create_or_update_file",
         provider_service="github",
@@ -408,6 +475,22 @@ GITHUB_REPO_PROVIDER_OPERATION_CASES = (
         assert_baseline=_repo_baseline,
         assert_outcome=_get_repo_outcome,
     ),
+    ProviderOperationCase(
+        case_id="github_get_repo_languages",
+        provider_service="github",
+        capability_id="github.get_repo_languages",
+        arguments=BASE_ARGS,
+        assert_baseline=_repo_baseline,
+        assert_outcome=_get_repo_languages_outcome,
+    ),
+    ProviderOperationCase(
+        case_id="github_get_contributor_stats",
+        provider_service="github",
+        capability_id="github.get_contributor_stats",
+        arguments=BASE_ARGS,
+        assert_baseline=_repo_baseline,
+        assert_outcome=_get_contributor_stats_outcome,
+    ),
     ProviderOperationCase(
         case_id="github_list_releases",
         provider_service="github",
@@ -42,6 +42,8 @@ pub const BUNDLED_EXTENSION_IDS: &[&str] = &[
 
 pub const BUNDLED_EXTENSION_CAPABILITY_IDS: &[&str] = &[
     "github.get_repo",
+    "github.get_repo_languages",
+    "github.get_contributor_stats",
     "github.create_repo",
     "github.list_issues",
     "github.create_issue",
@@ -50,6 +52,8 @@ pub const BUNDLED_EXTENSION_CAPABILITY_IDS: &[&str] = &[
     "github.remove_issue_label",
     "github.add_issue_assignees",
     "github.remove_issue_assignees",
+    "github.lock_issue",
+    "github.unlock_issue",
     "github.get_issue",
     "github.list_issue_comments",
     "github.create_issue_comment",
@@ -63,6 +67,7 @@ pub const BUNDLED_EXTENSION_CAPABILITY_IDS: &[&str] = &[
     "github.list_pull_request_comments",
     "github.reply_pull_request_comment",
     "github.get_pull_request_reviews",
+    "github.get_pull_request_status",
     "github.list_pull_request_review_threads",
     "github.resolve_review_thread",
     "github.unresolve_review_thread",
@@ -76,19 +81,28 @@ pub const BUNDLED_EXTENSION_CAPABILITY_IDS: &[&str] = &[
     "github.search_issues_pull_requests",
     "github.list_branches",
     "github.create_branch",
+    "github.list_commits",
+    "github.get_commit",
     "github.get_file_content",
+    "github.list_tree",
     "github.create_or_update_file",
     "github.delete_file",
     "github.list_releases",
     "github.create_release",
     "github.trigger_workflow",
+    "github.repository_dispatch",
     "github.get_workflow_runs",
     "github.get_workflow_run_jobs",
     "github.get_job_logs",
+    "github.get_workflow_run_logs",
     "github.get_workflow_run_artifacts",
     "github.rerun_failed_workflow_run_jobs",
     "github.rerun_workflow_job",
+    "github.cancel_workflow_run",
+    "github.rerun_workflow",
     "github.fork_repo",
+    "github.create_webhook",
+    "github.graphql",
     "github.handle_webhook",
     "web-access.search",
     "web-access.get_content",
@@ -100,6 +114,7 @@ pub const BUNDLED_EXTENSION_CAPABILITY_IDS: &[&str] = &[
     "slack.get_user_info",
     "slack.whoami",
     "slack.send_message",
+    "slack.delete_scheduled_message",
     "gmail.list_messages",
     "gmail.get_message",
     "gmail.send_message",